@@ -6,6 +6,24 @@ run_example() {
     *_serialize)
       additional_features="--features serialize"
     ;;
+    *_cassette)
+      additional_features="--features cassette"
+    ;;
+    *_fault_injection)
+      additional_features="--features fault-injection"
+    ;;
+    *_test_builders)
+      additional_features="--features test-builders"
+    ;;
+    *_preserve_unknown)
+      additional_features="--features preserve-unknown"
+    ;;
+    *_schemars)
+      additional_features="--features schemars"
+    ;;
+    *_shared_strings)
+      additional_features="--features shared-strings"
+    ;;
     *_static_resources)
       additional_features="--features static-resources"
     ;;
@@ -18,7 +36,7 @@ run_example() {
   fi
 }
 
-for file in ./rustemon/examples/*
+for file in ./rustemon/examples/* ./rustemon-mock/examples/*
   do
     name=$(basename $file)
     base=${name%.rs}
@@ -0,0 +1,219 @@
+//! Comparison of the schemas of the specification with the ones of the models.
+
+use std::{collections::HashSet, fmt};
+
+use serde_json::Value;
+
+/// Difference found between the specification and a model.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Finding {
+    /// The field is described by the specification, but missing from the model.
+    Missing(String),
+    /// The field is held by the model, but not described by the specification.
+    Extra(String),
+    /// The field is optional on one side only, either not required or nullable.
+    Optionality {
+        /// The path of the field, e.g. `abilities[].slot`.
+        path: String,
+        /// Whether the field is optional in the specification.
+        in_spec: bool,
+    },
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Finding::Missing(path) => write!(f, "missing from the model: `{path}`"),
+            Finding::Extra(path) => write!(f, "unknown to the specification: `{path}`"),
+            Finding::Optionality {
+                path,
+                in_spec: true,
+            } => write!(
+                f,
+                "optional in the specification, required in the model: `{path}`"
+            ),
+            Finding::Optionality {
+                path,
+                in_spec: false,
+            } => write!(
+                f,
+                "required in the specification, optional in the model: `{path}`"
+            ),
+        }
+    }
+}
+
+/// Schema within its document, against which its `$ref`s are resolved.
+#[derive(Clone, Copy)]
+struct Located<'a> {
+    document: &'a Value,
+    schema: &'a Value,
+}
+
+/// Schema stripped from its references and nullability.
+struct Resolved<'a> {
+    schema: &'a Value,
+    /// The last reference followed, identifying the schema.
+    reference: Option<&'a str>,
+    nullable: bool,
+}
+
+impl<'a> Located<'a> {
+    /// Follows the references and wrappers of the schema, e.g. `allOf` with a single schema or `anyOf` with `null`.
+    fn resolve(self) -> Resolved<'a> {
+        let mut schema = self.schema;
+        let mut reference = None;
+        let mut nullable = false;
+
+        loop {
+            if let Some(target) = schema["$ref"].as_str() {
+                match target
+                    .strip_prefix('#')
+                    .and_then(|pointer| self.document.pointer(pointer))
+                {
+                    Some(target_schema) => {
+                        reference = Some(target);
+                        schema = target_schema;
+                        continue;
+                    }
+                    None => break,
+                }
+            }
+
+            nullable |= schema["nullable"] == Value::Bool(true)
+                || schema["type"]
+                    .as_array()
+                    .is_some_and(|types| types.iter().any(|t| t == "null"));
+
+            let variants = ["anyOf", "oneOf", "allOf"]
+                .into_iter()
+                .find_map(|key| schema[key].as_array());
+            let Some(variants) = variants else {
+                break;
+            };
+            let (nulls, others): (Vec<_>, Vec<_>) = variants
+                .iter()
+                .partition(|variant| variant["type"] == "null");
+            nullable |= !nulls.is_empty();
+            match others.as_slice() {
+                [single] => schema = single,
+                _ => break,
+            }
+        }
+
+        Resolved {
+            schema,
+            reference,
+            nullable,
+        }
+    }
+
+    fn with(self, schema: &'a Value) -> Self {
+        Self {
+            document: self.document,
+            schema,
+        }
+    }
+}
+
+/// Compares the schema of the specification with the one of the model, both within their document.
+pub fn compare(spec_document: &Value, spec_schema: &Value, model_schema: &Value) -> Vec<Finding> {
+    let mut comparison = Comparison::default();
+    comparison.compare(
+        Located {
+            document: spec_document,
+            schema: spec_schema,
+        },
+        Located {
+            document: model_schema,
+            schema: model_schema,
+        },
+        "",
+    );
+
+    comparison.findings
+}
+
+#[derive(Default)]
+struct Comparison<'a> {
+    findings: Vec<Finding>,
+    /// The pairs of referenced schemas already compared, as the schemas may be recursive. The differences of a schema
+    /// shared by several fields are only reported for the first one.
+    visited: HashSet<(&'a str, &'a str)>,
+}
+
+impl<'a> Comparison<'a> {
+    fn compare(&mut self, spec: Located<'a>, model: Located<'a>, path: &str) {
+        let spec_resolved = spec.resolve();
+        let model_resolved = model.resolve();
+
+        if let (Some(spec_reference), Some(model_reference)) =
+            (spec_resolved.reference, model_resolved.reference)
+            && !self.visited.insert((spec_reference, model_reference))
+        {
+            return;
+        }
+
+        let (spec_schema, model_schema) = (spec_resolved.schema, model_resolved.schema);
+        if let (Some(items), Some(model_items)) =
+            (spec_schema.get("items"), model_schema.get("items"))
+        {
+            self.compare(
+                spec.with(items),
+                model.with(model_items),
+                &format!("{path}[]"),
+            );
+            return;
+        }
+
+        let (Some(properties), Some(model_properties)) = (
+            spec_schema["properties"].as_object(),
+            model_schema["properties"].as_object(),
+        ) else {
+            return;
+        };
+
+        for (name, property) in properties {
+            let field = field_path(path, name);
+            let Some(model_property) = model_properties.get(name) else {
+                self.findings.push(Finding::Missing(field));
+                continue;
+            };
+
+            let optional_in_spec =
+                !is_required(spec_schema, name) || spec.with(property).resolve().nullable;
+            let optional_in_model =
+                !is_required(model_schema, name) || model.with(model_property).resolve().nullable;
+            if optional_in_spec != optional_in_model {
+                self.findings.push(Finding::Optionality {
+                    path: field.clone(),
+                    in_spec: optional_in_spec,
+                });
+            }
+
+            self.compare(spec.with(property), model.with(model_property), &field);
+        }
+
+        for name in model_properties.keys() {
+            if !properties.contains_key(name) {
+                self.findings.push(Finding::Extra(field_path(path, name)));
+            }
+        }
+    }
+}
+
+/// Whether the object described by `schema` requires the property `name`.
+fn is_required(schema: &Value, name: &str) -> bool {
+    schema["required"]
+        .as_array()
+        .is_some_and(|required| required.iter().any(|required| required == name))
+}
+
+/// Returns the path of the field `name` of the object at `path`.
+fn field_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{path}.{name}")
+    }
+}
@@ -0,0 +1,82 @@
+//! Conformance checker of the models of rustemon against the `OpenAPI` document of `PokeApi`.
+//!
+//! For every endpoint, the schema of its resources in the document is compared with the JSON Schema of the model,
+//! reporting the fields missing from or unknown to the model, and the fields optional on one side only. The endpoints
+//! of the document without a module in rustemon are reported as well.
+//!
+//! The document isn't fetched, a local copy must be given, e.g. `openapi.yml` from the `PokeApi` repository.
+
+mod compare;
+mod spec;
+
+use std::{path::PathBuf, process::ExitCode};
+
+use clap::Parser;
+use serde_json::Value;
+
+use crate::spec::Spec;
+
+#[derive(Parser)]
+#[command(about = "Checks the models of rustemon against the OpenAPI document of PokeApi")]
+struct Args {
+    /// Path of the `OpenAPI` document, in YAML or JSON.
+    spec: PathBuf,
+    /// Only check the given endpoints, e.g. `pokemon-species`. Every endpoint is checked when omitted.
+    #[arg(long = "endpoint")]
+    endpoints: Vec<String>,
+    /// Exit with a failure status when anything is reported, e.g. in CI.
+    #[arg(long)]
+    deny: bool,
+}
+
+fn main() -> anyhow::Result<ExitCode> {
+    let args = Args::parse();
+    let spec = Spec::load(&args.spec)?;
+    let spec_schemas = spec.resource_schemas();
+    let model_schemas = rustemon::schema::resource_schemas();
+    let selected =
+        |endpoint: &str| args.endpoints.is_empty() || args.endpoints.iter().any(|e| e == endpoint);
+
+    let mut reported = 0;
+    for (endpoint, model_schema) in &model_schemas {
+        if !selected(endpoint) {
+            continue;
+        }
+        let Some(spec_schema) = spec_schemas.get(endpoint) else {
+            println!("{endpoint}: no resource schema in the specification");
+            reported += 1;
+            continue;
+        };
+
+        let model_schema = Value::from(model_schema.clone());
+        let findings = compare::compare(spec.document(), spec_schema, &model_schema);
+        if findings.is_empty() {
+            println!("{endpoint}: ok");
+            continue;
+        }
+        println!("{endpoint}: {} finding(s)", findings.len());
+        for finding in &findings {
+            println!("  {finding}");
+        }
+        reported += findings.len();
+    }
+
+    let unmodeled: Vec<_> = spec
+        .endpoints()
+        .into_iter()
+        .filter(|endpoint| selected(endpoint) && !model_schemas.contains_key(endpoint))
+        .collect();
+    if !unmodeled.is_empty() {
+        println!("endpoints without a module in rustemon:");
+        for endpoint in &unmodeled {
+            println!("  {endpoint}");
+        }
+        reported += unmodeled.len();
+    }
+
+    Ok(if args.deny && reported > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
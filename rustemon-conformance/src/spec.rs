@@ -0,0 +1,80 @@
+//! Reading of the `OpenAPI` document of `PokeApi`.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::Path,
+};
+
+use anyhow::Context;
+use serde_json::Value;
+
+/// `OpenAPI` document of `PokeApi`, either in YAML or JSON.
+pub struct Spec {
+    document: Value,
+}
+
+impl Spec {
+    /// Reads the document at `path`.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("couldn't read `{}`", path.display()))?;
+        // Going through a YAML value first, as the status codes of the responses may be integer keys.
+        let yaml: serde_yaml::Value = serde_yaml::from_str(&content)
+            .with_context(|| format!("couldn't parse `{}`", path.display()))?;
+
+        Ok(Self {
+            document: serde_json::to_value(yaml)?,
+        })
+    }
+
+    /// The whole document, against which the `$ref`s of its schemas are resolved.
+    pub fn document(&self) -> &Value {
+        &self.document
+    }
+
+    /// Every endpoint having a path in the document, e.g. `pokemon-species`.
+    pub fn endpoints(&self) -> BTreeSet<&str> {
+        self.paths()
+            .filter_map(|(segments, _)| segments.first().copied())
+            .collect()
+    }
+
+    /// The schema of the resources of every endpoint, as returned by `GET /api/v2/<endpoint>/{id}/`.
+    pub fn resource_schemas(&self) -> BTreeMap<&str, &Value> {
+        self.paths()
+            .filter_map(|(segments, item)| match segments.as_slice() {
+                [endpoint, parameter] if parameter.starts_with('{') => {
+                    Some((*endpoint, response_schema(item)?))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The paths of the document, split into segments below `/api/v2/`, along with their item.
+    fn paths(&self) -> impl Iterator<Item = (Vec<&str>, &Value)> {
+        self.document["paths"]
+            .as_object()
+            .into_iter()
+            .flatten()
+            .map(|(path, item)| {
+                let path = path
+                    .split_once("api/v2/")
+                    .map_or(path.as_str(), |(_, path)| path);
+                let segments = path.split('/').filter(|segment| !segment.is_empty());
+
+                (segments.collect(), item)
+            })
+    }
+}
+
+/// Returns the schema of the successful JSON response of the `GET` operation of a path item.
+fn response_schema(item: &Value) -> Option<&Value> {
+    let content = item["get"]["responses"]["200"]["content"].as_object()?;
+
+    content
+        .get("application/json")
+        .or_else(|| content.values().next())?
+        .get("schema")
+}
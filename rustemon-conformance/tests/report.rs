@@ -0,0 +1,71 @@
+//! Checks the models against small `OpenAPI` documents, one matching them and one drifting from them.
+
+use std::process::{Command, Output};
+
+/// Runs the checker on the fixture `document`, restricted to `endpoints`.
+fn check(document: &str, endpoints: &[&str], deny: bool) -> Output {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_rustemon-conformance"));
+    command.arg(format!(
+        "{}/tests/fixtures/{document}",
+        env!("CARGO_MANIFEST_DIR")
+    ));
+    for endpoint in endpoints {
+        command.args(["--endpoint", endpoint]);
+    }
+    if deny {
+        command.arg("--deny");
+    }
+
+    command.output().expect("the checker runs")
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+#[test]
+fn matching_document_reports_nothing() {
+    let output = check("openapi.yml", &["berry-firmness"], true);
+
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(stdout(&output), "berry-firmness: ok\n");
+}
+
+#[test]
+fn drifting_document_reports_every_difference() {
+    let output = check(
+        "openapi_drifted.yml",
+        &["berry-firmness", "berry-smoothness"],
+        true,
+    );
+
+    assert!(!output.status.success(), "{output:?}");
+    assert_eq!(
+        stdout(&output),
+        "berry-firmness: 3 finding(s)\n\
+         \x20 optional in the specification, required in the model: `berries[].url`\n\
+         \x20 missing from the model: `smoothness`\n\
+         \x20 unknown to the specification: `names`\n\
+         endpoints without a module in rustemon:\n\
+         \x20 berry-smoothness\n"
+    );
+}
+
+#[test]
+fn drifting_document_only_fails_when_denied() {
+    let output = check("openapi_drifted.yml", &["berry-firmness"], false);
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(stdout(&output).starts_with("berry-firmness: 3 finding(s)\n"));
+}
+
+#[test]
+fn endpoints_missing_from_the_document_are_reported() {
+    let output = check("openapi.yml", &["berry"], true);
+
+    assert!(!output.status.success(), "{output:?}");
+    assert_eq!(
+        stdout(&output),
+        "berry: no resource schema in the specification\n"
+    );
+}
@@ -0,0 +1,39 @@
+use std::time::{Duration, Instant};
+
+use rustemon::{
+    Follow,
+    client::{MokaManager, RustemonClientBuilder},
+};
+use rustemon_mock::MockServer;
+
+#[tokio::main]
+async fn main() {
+    let server = MockServer::builder()
+        .with_latency(Duration::from_millis(20))
+        .start()
+        .await
+        .unwrap();
+    let rustemon_client = RustemonClientBuilder::<MokaManager>::default()
+        .with_environment(server.environment())
+        .try_build()
+        .unwrap();
+
+    let start = Instant::now();
+    let page = rustemon::pokemon::pokemon::get_page_with_param(0, 1, &rustemon_client)
+        .await
+        .unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(20));
+    println!("{page:?}");
+
+    let pokemon = page.results[0].follow(&rustemon_client).await.unwrap();
+    let species = pokemon.species.follow(&rustemon_client).await.unwrap();
+    println!("{} belongs to {}", pokemon.name, species.name);
+
+    let encounters = rustemon::pokemon::pokemon::encounters::get_by_id(6, &rustemon_client)
+        .await
+        .unwrap();
+    println!("{} encounters", encounters.len());
+
+    let missing = rustemon::pokemon::pokemon::get_by_id(1, &rustemon_client).await;
+    println!("{missing:?}");
+}
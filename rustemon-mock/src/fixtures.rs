@@ -0,0 +1,214 @@
+//! Payloads served by the mock, indexed by endpoint.
+
+use std::collections::BTreeMap;
+
+use rustemon::{
+    model::{
+        berries, contests, encounters, evolution, games, items, locations, machines, moves,
+        pokemon::{self, Pokemon},
+        resource::Endpoint,
+        utility,
+    },
+    static_resources,
+};
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::{Value, json};
+
+/// Base of the URLs found in the static resources.
+const PRODUCTION_BASE: &str = "https://pokeapi.co/api/v2/";
+
+/// Returns the payload of every static resource along with its endpoint.
+pub(crate) fn static_payloads() -> Vec<(&'static str, Value)> {
+    fn payloads<T>() -> Vec<(&'static str, Value)>
+    where
+        T: Endpoint + DeserializeOwned + Serialize,
+    {
+        static_resources::get_all::<T>()
+            .into_iter()
+            .map(|resource| {
+                let payload =
+                    serde_json::to_value(resource).expect("models always serialize to JSON");
+                (T::NAME, payload)
+            })
+            .collect()
+    }
+
+    macro_rules! payloads {
+        ($($model:ty),+ $(,)?) => {
+            [$(payloads::<$model>()),+].concat()
+        };
+    }
+
+    payloads!(
+        // Berries endpoints
+        berries::Berry,
+        berries::BerryFirmness,
+        berries::BerryFlavor,
+        // Contests endpoints
+        contests::ContestType,
+        contests::ContestEffect,
+        contests::SuperContestEffect,
+        // Encounters endpoints
+        encounters::EncounterMethod,
+        encounters::EncounterCondition,
+        encounters::EncounterConditionValue,
+        // Evolution endpoints
+        evolution::EvolutionChain,
+        evolution::EvolutionTrigger,
+        // Games endpoints
+        games::Generation,
+        games::Pokedex,
+        games::Version,
+        games::VersionGroup,
+        // Items endpoints
+        items::Item,
+        items::ItemAttribute,
+        items::ItemCategory,
+        items::ItemFlingEffect,
+        items::ItemPocket,
+        // Locations endpoints
+        locations::Location,
+        locations::LocationArea,
+        locations::PalParkArea,
+        locations::Region,
+        // Machines endpoints
+        machines::Machine,
+        // Moves endpoints
+        moves::Move,
+        moves::MoveAilment,
+        moves::MoveBattleStyle,
+        moves::MoveCategory,
+        moves::MoveDamageClass,
+        moves::MoveLearnMethod,
+        moves::MoveTarget,
+        // Pokemon endpoints
+        pokemon::Ability,
+        pokemon::Characteristic,
+        pokemon::EggGroup,
+        pokemon::Gender,
+        pokemon::GrowthRate,
+        pokemon::Nature,
+        pokemon::PokeathlonStat,
+        pokemon::Pokemon,
+        pokemon::PokemonColor,
+        pokemon::PokemonForm,
+        pokemon::PokemonHabitat,
+        pokemon::PokemonShape,
+        pokemon::PokemonSpecies,
+        pokemon::Stat,
+        pokemon::Type,
+        // Utility endpoints
+        utility::Language,
+    )
+}
+
+/// Returns the encounters of every static Pokémon having some, along with its id.
+pub(crate) fn static_encounters() -> Vec<(i64, Value)> {
+    static_resources::get_all::<Pokemon>()
+        .into_iter()
+        .filter_map(|pokemon| {
+            let encounters = static_resources::get_encounters_by_id(pokemon.id)?;
+            let payload =
+                serde_json::to_value(encounters).expect("models always serialize to JSON");
+            Some((pokemon.id, payload))
+        })
+        .collect()
+}
+
+/// Payloads served for every endpoint, with their URLs pointing at the mock.
+pub(crate) struct Fixtures {
+    base: String,
+    resources: BTreeMap<String, Vec<Value>>,
+    encounters: BTreeMap<i64, Value>,
+}
+
+impl Fixtures {
+    /// Creates an empty set of fixtures served under `base`, which must end with a `/`.
+    pub(crate) fn new(base: String) -> Self {
+        Self {
+            base,
+            resources: BTreeMap::new(),
+            encounters: BTreeMap::new(),
+        }
+    }
+
+    /// Serves `payload` from `endpoint`, replacing any payload with the same id.
+    pub(crate) fn insert(&mut self, endpoint: &str, mut payload: Value) {
+        self.rebase(&mut payload);
+
+        let resources = self.resources.entry(endpoint.to_owned()).or_default();
+        resources.retain(|resource| resource["id"] != payload["id"]);
+        resources.push(payload);
+        resources.sort_by_key(|resource| resource["id"].as_i64());
+    }
+
+    /// Serves `payload` as the encounters of the Pokémon with the given id.
+    pub(crate) fn insert_encounters(&mut self, pokemon_id: i64, mut payload: Value) {
+        self.rebase(&mut payload);
+        self.encounters.insert(pokemon_id, payload);
+    }
+
+    /// Returns the resource of `endpoint` identified by `id_or_name`.
+    pub(crate) fn get(&self, endpoint: &str, id_or_name: &str) -> Option<&Value> {
+        let resources = self.resources.get(endpoint)?;
+        match id_or_name.parse::<i64>() {
+            Ok(id) => resources.iter().find(|r| r["id"].as_i64() == Some(id)),
+            Err(_) => resources
+                .iter()
+                .find(|r| r["name"].as_str() == Some(id_or_name)),
+        }
+    }
+
+    /// Returns the encounters of the Pokémon identified by `id_or_name`.
+    ///
+    /// Known Pokémon without registered encounters can't be encountered anywhere.
+    pub(crate) fn encounters(&self, id_or_name: &str) -> Option<Value> {
+        let id = self.get("pokemon", id_or_name)?["id"].as_i64()?;
+
+        Some(self.encounters.get(&id).cloned().unwrap_or(json!([])))
+    }
+
+    /// Returns the page of `endpoint` starting at `offset`, holding at most `limit` resources.
+    pub(crate) fn page(&self, endpoint: &str, offset: usize, limit: usize) -> Option<Value> {
+        let resources = self.resources.get(endpoint)?;
+        let count = resources.len();
+        let page_url =
+            |offset: usize| format!("{}{endpoint}/?offset={offset}&limit={limit}", self.base);
+
+        let results: Vec<Value> = resources
+            .iter()
+            .skip(offset)
+            .take(limit)
+            .map(|resource| {
+                let url = format!("{}{endpoint}/{}/", self.base, resource["id"]);
+                match resource.get("name") {
+                    Some(name) => json!({ "name": name, "url": url }),
+                    None => json!({ "url": url }),
+                }
+            })
+            .collect();
+        let next = (offset + limit < count).then(|| page_url(offset + limit));
+        let previous = (offset > 0).then(|| page_url(offset.saturating_sub(limit)));
+
+        Some(json!({
+            "count": count,
+            "next": next,
+            "previous": previous,
+            "results": results,
+        }))
+    }
+
+    /// Makes every `PokeApi` URL of `value` point at the mock.
+    fn rebase(&self, value: &mut Value) {
+        match value {
+            Value::String(s) => {
+                if let Some(path) = s.strip_prefix(PRODUCTION_BASE) {
+                    *s = format!("{}{path}", self.base);
+                }
+            }
+            Value::Array(values) => values.iter_mut().for_each(|v| self.rebase(v)),
+            Value::Object(map) => map.values_mut().for_each(|v| self.rebase(v)),
+            Value::Null | Value::Bool(_) | Value::Number(_) => {}
+        }
+    }
+}
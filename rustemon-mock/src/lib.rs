@@ -0,0 +1,144 @@
+//! In-process mock of `PokeApi`, serving the static resources of `rustemon`.
+//!
+//! The [`MockServer`] listens on an ephemeral port of the loopback interface and exposes the same
+//! paths and pagination parameters as `PokeApi`, so that a [`RustemonClient`](rustemon::client::RustemonClient)
+//! targeting [`MockServer::environment`] can be exercised without reaching the internet.
+//!
+//! ```rust
+//! use rustemon::client::{MokaManager, RustemonClientBuilder};
+//! use rustemon_mock::MockServer;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let server = MockServer::start().await.unwrap();
+//!     let rustemon_client = RustemonClientBuilder::<MokaManager>::default()
+//!         .with_environment(server.environment())
+//!         .try_build()
+//!         .unwrap();
+//!
+//!     let charizard = rustemon::pokemon::pokemon::get_by_name("charizard", &rustemon_client)
+//!         .await
+//!         .unwrap();
+//!     assert_eq!(charizard.id, 6);
+//! }
+//! ```
+
+#![deny(missing_docs)]
+
+mod fixtures;
+mod routes;
+
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+use rustemon::client::Environment;
+use serde_json::Value;
+use tokio::{net::TcpListener, task::JoinHandle};
+
+use crate::fixtures::Fixtures;
+
+/// Builder used to ease the configuration of a [`MockServer`].
+#[derive(Default)]
+pub struct MockServerBuilder {
+    latency: Duration,
+    without_static_resources: bool,
+    fixtures: Vec<(String, Value)>,
+    encounters: Vec<(i64, Value)>,
+}
+
+impl MockServerBuilder {
+    /// Delays every response of the server by `latency`.
+    pub const fn with_latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    /// Only serves the fixtures added to the builder, leaving out the static resources of `rustemon`.
+    pub const fn without_static_resources(mut self) -> Self {
+        self.without_static_resources = true;
+        self
+    }
+
+    /// Serves `payload` from `endpoint`, e.g. `"pokemon"`. The payload replaces any resource of the endpoint
+    /// sharing its `id`, and is looked up through its `id` and `name` fields.
+    pub fn with_fixture(mut self, endpoint: &str, payload: Value) -> Self {
+        self.fixtures.push((endpoint.to_owned(), payload));
+        self
+    }
+
+    /// Serves `payload` as the encounters of the Pokémon with the given id.
+    pub fn with_encounters(mut self, pokemon_id: i64, payload: Value) -> Self {
+        self.encounters.push((pokemon_id, payload));
+        self
+    }
+
+    /// Binds the server to an ephemeral port and starts serving in the background.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub async fn start(self) -> std::io::Result<MockServer> {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await?;
+        let address = listener.local_addr()?;
+
+        let mut fixtures = Fixtures::new(format!("http://{address}/api/v2/"));
+        if !self.without_static_resources {
+            for (endpoint, payload) in fixtures::static_payloads() {
+                fixtures.insert(endpoint, payload);
+            }
+            for (pokemon_id, payload) in fixtures::static_encounters() {
+                fixtures.insert_encounters(pokemon_id, payload);
+            }
+        }
+        for (endpoint, payload) in self.fixtures {
+            fixtures.insert(&endpoint, payload);
+        }
+        for (pokemon_id, payload) in self.encounters {
+            fixtures.insert_encounters(pokemon_id, payload);
+        }
+
+        let router = routes::router(Arc::new(fixtures), self.latency);
+        let task = tokio::spawn(async move {
+            // Serving only stops on I/O errors of the listener, nothing to recover from in a mock.
+            let _ = axum::serve(listener, router).await;
+        });
+
+        Ok(MockServer { address, task })
+    }
+}
+
+/// Mock of `PokeApi` running in the background. The server stops when dropped.
+pub struct MockServer {
+    address: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl MockServer {
+    /// Returns a builder to configure the server.
+    pub fn builder() -> MockServerBuilder {
+        MockServerBuilder::default()
+    }
+
+    /// Starts a server serving the static resources of `rustemon`, without latency.
+    pub async fn start() -> std::io::Result<Self> {
+        Self::builder().start().await
+    }
+
+    /// Returns the address the server listens on.
+    pub const fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Returns the base URL of the API served, e.g. `http://127.0.0.1:41234/api/v2/`.
+    pub fn base_url(&self) -> String {
+        format!("http://{}/api/v2/", self.address)
+    }
+
+    /// Returns the [`Environment`] targeting the server.
+    pub fn environment(&self) -> Environment {
+        Environment::Custom(self.base_url())
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
@@ -0,0 +1,96 @@
+//! HTTP layer of the mock.
+
+use std::{sync::Arc, time::Duration};
+
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use serde::Deserialize;
+
+use crate::fixtures::Fixtures;
+
+/// Page size used by `PokeApi` when none is requested.
+const DEFAULT_LIMIT: usize = 20;
+
+#[derive(Clone)]
+struct MockState {
+    fixtures: Arc<Fixtures>,
+    latency: Duration,
+}
+
+impl MockState {
+    async fn delay(&self) {
+        if !self.latency.is_zero() {
+            tokio::time::sleep(self.latency).await;
+        }
+    }
+}
+
+/// Builds the router exposing `fixtures` with the `PokeApi` REST layout.
+pub(crate) fn router(fixtures: Arc<Fixtures>, latency: Duration) -> Router {
+    Router::new()
+        .route("/api/v2/{endpoint}", get(list))
+        .route("/api/v2/{endpoint}/", get(list))
+        .route("/api/v2/{endpoint}/{id}", get(resource))
+        .route("/api/v2/{endpoint}/{id}/", get(resource))
+        .route("/api/v2/{endpoint}/{id}/{sub}", get(sub_resource))
+        .route("/api/v2/{endpoint}/{id}/{sub}/", get(sub_resource))
+        .with_state(MockState { fixtures, latency })
+}
+
+/// Pagination parameters, as accepted by `PokeApi`.
+#[derive(Deserialize)]
+struct PageParams {
+    offset: Option<usize>,
+    limit: Option<usize>,
+}
+
+fn not_found() -> Response {
+    (StatusCode::NOT_FOUND, "Not Found").into_response()
+}
+
+async fn list(
+    State(state): State<MockState>,
+    Path(endpoint): Path<String>,
+    Query(params): Query<PageParams>,
+) -> Response {
+    state.delay().await;
+
+    let offset = params.offset.unwrap_or(0);
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT);
+    match state.fixtures.page(&endpoint, offset, limit) {
+        Some(page) => Json(page).into_response(),
+        None => not_found(),
+    }
+}
+
+async fn resource(
+    State(state): State<MockState>,
+    Path((endpoint, id)): Path<(String, String)>,
+) -> Response {
+    state.delay().await;
+
+    match state.fixtures.get(&endpoint, &id) {
+        Some(resource) => Json(resource).into_response(),
+        None => not_found(),
+    }
+}
+
+async fn sub_resource(
+    State(state): State<MockState>,
+    Path((endpoint, id, sub)): Path<(String, String, String)>,
+) -> Response {
+    state.delay().await;
+
+    if endpoint != "pokemon" || sub != "encounters" {
+        return not_found();
+    }
+    match state.fixtures.encounters(&id) {
+        Some(encounters) => Json(encounters).into_response(),
+        None => not_found(),
+    }
+}
@@ -0,0 +1,191 @@
+//! Exercises the mock through a `RustemonClient`, the way the users of the crate do.
+
+use std::time::{Duration, Instant};
+
+use rustemon::{
+    Follow,
+    client::{CacheMode, MokaManager, RustemonClient, RustemonClientBuilder},
+    error::Error,
+};
+use rustemon_mock::MockServer;
+use serde_json::json;
+
+fn client(server: &MockServer) -> RustemonClient {
+    RustemonClientBuilder::<MokaManager>::default()
+        .with_mode(CacheMode::NoStore)
+        .with_environment(server.environment())
+        .try_build()
+        .unwrap()
+}
+
+fn berry(id: i64) -> serde_json::Value {
+    json!({ "id": id, "name": format!("berry-{id}") })
+}
+
+fn status(error: Error) -> Option<u16> {
+    match error {
+        Error::Reqwest(error) => error.status().map(|status| status.as_u16()),
+        _ => None,
+    }
+}
+
+#[tokio::test]
+async fn serves_static_resources_by_id_and_name() {
+    let server = MockServer::start().await.unwrap();
+    let client = client(&server);
+
+    let by_id = rustemon::pokemon::pokemon::get_by_id(6, &client)
+        .await
+        .unwrap();
+    let by_name = rustemon::pokemon::pokemon::get_by_name("charizard", &client)
+        .await
+        .unwrap();
+
+    assert_eq!(by_id.name, "charizard");
+    assert_eq!(by_name.id, 6);
+}
+
+#[tokio::test]
+async fn rebases_urls_on_the_server() {
+    let server = MockServer::start().await.unwrap();
+    let client = client(&server);
+
+    let charizard = rustemon::pokemon::pokemon::get_by_id(6, &client)
+        .await
+        .unwrap();
+    assert!(charizard.species.url.starts_with(&server.base_url()));
+
+    let species = charizard.species.follow(&client).await.unwrap();
+    assert_eq!(species.id, 6);
+}
+
+#[tokio::test]
+async fn accepts_trailing_slashes() {
+    let server = MockServer::start().await.unwrap();
+    let client = client(&server);
+
+    let payload = client.get_raw("pokemon/6/").await.unwrap();
+    let payload: serde_json::Value = serde_json::from_str(&payload).unwrap();
+
+    assert_eq!(payload["name"], "charizard");
+}
+
+#[tokio::test]
+async fn paginates_like_pokeapi() {
+    let mut builder = MockServer::builder().without_static_resources();
+    for id in 1..=5 {
+        builder = builder.with_fixture("berry", berry(id));
+    }
+    let server = builder.start().await.unwrap();
+    let client = client(&server);
+    let base = server.base_url();
+
+    let first = rustemon::berries::berry::get_page_with_param(0, 2, &client)
+        .await
+        .unwrap();
+    assert_eq!(first.count, 5);
+    assert_eq!(first.previous, None);
+    assert_eq!(
+        first.next.as_deref(),
+        Some(format!("{base}berry/?offset=2&limit=2").as_str())
+    );
+    let names: Vec<_> = first.results.iter().map(|r| r.name.to_string()).collect();
+    assert_eq!(names, ["berry-1", "berry-2"]);
+    assert_eq!(first.results[0].url, format!("{base}berry/1/"));
+
+    let last = rustemon::berries::berry::get_page_with_param(4, 2, &client)
+        .await
+        .unwrap();
+    assert_eq!(last.next, None);
+    assert_eq!(
+        last.previous.as_deref(),
+        Some(format!("{base}berry/?offset=2&limit=2").as_str())
+    );
+    assert_eq!(last.results.len(), 1);
+
+    let default = rustemon::berries::berry::get_page(&client).await.unwrap();
+    assert_eq!(default.results.len(), 5);
+}
+
+#[tokio::test]
+async fn fixtures_replace_static_resources_sharing_their_id() {
+    let server = MockServer::builder()
+        .with_fixture("berry", berry(1))
+        .start()
+        .await
+        .unwrap();
+    let client = client(&server);
+
+    let replaced = client.get_raw("berry/1").await.unwrap();
+    let replaced: serde_json::Value = serde_json::from_str(&replaced).unwrap();
+    assert_eq!(replaced["name"], "berry-1");
+
+    let page = rustemon::berries::berry::get_page(&client).await.unwrap();
+    assert_eq!(page.count, 1);
+}
+
+#[tokio::test]
+async fn serves_encounters() {
+    let encounters = json!([{
+        "location_area": { "name": "area", "url": "https://pokeapi.co/api/v2/location-area/1/" },
+        "version_details": [],
+    }]);
+    let server = MockServer::builder()
+        .with_encounters(6, encounters)
+        .start()
+        .await
+        .unwrap();
+    let client = client(&server);
+
+    let encounters = rustemon::pokemon::pokemon::encounters::get_by_id(6, &client)
+        .await
+        .unwrap();
+    assert_eq!(encounters.len(), 1);
+    assert_eq!(
+        encounters[0].location_area.url,
+        format!("{}location-area/1/", server.base_url())
+    );
+}
+
+#[tokio::test]
+async fn known_pokemon_without_encounters_have_none() {
+    let server = MockServer::start().await.unwrap();
+    let client = client(&server);
+
+    let encounters = rustemon::pokemon::pokemon::encounters::get_by_name("charizard", &client)
+        .await
+        .unwrap();
+
+    assert!(encounters.is_empty());
+}
+
+#[tokio::test]
+async fn unknown_resources_are_not_found() {
+    let server = MockServer::start().await.unwrap();
+    let client = client(&server);
+
+    let pokemon = rustemon::pokemon::pokemon::get_by_id(1, &client).await;
+    let endpoint = client.get_raw("not-an-endpoint").await;
+    let encounters = rustemon::pokemon::pokemon::encounters::get_by_id(1, &client).await;
+
+    assert_eq!(status(pokemon.unwrap_err()), Some(404));
+    assert_eq!(status(endpoint.unwrap_err()), Some(404));
+    assert_eq!(status(encounters.unwrap_err()), Some(404));
+}
+
+#[tokio::test]
+async fn delays_responses() {
+    let server = MockServer::builder()
+        .with_latency(Duration::from_millis(100))
+        .start()
+        .await
+        .unwrap();
+    let client = client(&server);
+
+    let start = Instant::now();
+    rustemon::pokemon::pokemon::get_by_id(6, &client)
+        .await
+        .unwrap();
+
+    assert!(start.elapsed() >= Duration::from_millis(100));
+}
@@ -0,0 +1,137 @@
+//! HTTP layer of the proxy.
+
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    extract::{Path, Query, State},
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use rustemon::{client::RustemonClient, error::Error};
+use serde::Deserialize;
+
+use crate::{
+    coalesce::Coalescer,
+    dispatch::{self, Target},
+    rewrite::UrlRewriter,
+};
+
+/// Reasons for the proxy not to answer with a resource.
+enum ProxyError {
+    /// The endpoint doesn't exist in `PokeApi`.
+    UnknownEndpoint,
+    /// The call made through the client failed.
+    Upstream(Error),
+}
+
+impl ProxyError {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::UnknownEndpoint => StatusCode::NOT_FOUND,
+            Self::Upstream(Error::Reqwest(e)) => e.status().unwrap_or(StatusCode::BAD_GATEWAY),
+            Self::Upstream(_) => StatusCode::BAD_GATEWAY,
+        }
+    }
+}
+
+/// State shared by every request handled by the proxy.
+pub struct AppState {
+    client: RustemonClient,
+    rewriter: UrlRewriter,
+    coalescer: Coalescer<String, ProxyError>,
+}
+
+impl AppState {
+    /// Creates the state of a proxy calling `PokeApi` through `client`, rewriting
+    /// payloads with `rewriter`.
+    pub fn new(client: RustemonClient, rewriter: UrlRewriter) -> Self {
+        Self {
+            client,
+            rewriter,
+            coalescer: Coalescer::default(),
+        }
+    }
+}
+
+/// Builds the router exposing the `PokeApi` REST layout.
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/api/v2/{endpoint}", get(list))
+        .route("/api/v2/{endpoint}/", get(list))
+        .route("/api/v2/{endpoint}/{id}", get(resource))
+        .route("/api/v2/{endpoint}/{id}/", get(resource))
+        .route("/api/v2/{endpoint}/{id}/{sub}", get(sub_resource))
+        .route("/api/v2/{endpoint}/{id}/{sub}/", get(sub_resource))
+        .with_state(Arc::new(state))
+}
+
+/// Pagination parameters, as accepted by `PokeApi`.
+#[derive(Deserialize)]
+struct PageParams {
+    offset: Option<i64>,
+    limit: Option<i64>,
+}
+
+async fn list(
+    State(state): State<Arc<AppState>>,
+    Path(endpoint): Path<String>,
+    Query(params): Query<PageParams>,
+) -> Response {
+    let target = Target::Page {
+        offset: params.offset,
+        limit: params.limit,
+    };
+
+    serve(state, endpoint, target).await
+}
+
+async fn resource(
+    State(state): State<Arc<AppState>>,
+    Path((endpoint, id)): Path<(String, String)>,
+) -> Response {
+    serve(state, endpoint, Target::Resource(id)).await
+}
+
+async fn sub_resource(
+    State(state): State<Arc<AppState>>,
+    Path((endpoint, id, sub)): Path<(String, String, String)>,
+) -> Response {
+    if sub != "encounters" {
+        return ProxyError::UnknownEndpoint.status().into_response();
+    }
+    serve(state, endpoint, Target::Encounters(id)).await
+}
+
+/// Answers with the `target` of `endpoint`, sharing the upstream call with identical requests in flight.
+///
+/// The upstream payload is forwarded as is, only its URLs being rewritten.
+async fn serve(state: Arc<AppState>, endpoint: String, target: Target) -> Response {
+    let Some(path) = dispatch::upstream_path(&endpoint, &target) else {
+        return ProxyError::UnknownEndpoint.status().into_response();
+    };
+    let fetch_state = Arc::clone(&state);
+    let fetch_path = path.clone();
+    let fetch = async move {
+        let payload = fetch_state
+            .client
+            .get_raw(&fetch_path)
+            .await
+            .map_err(ProxyError::Upstream)?;
+
+        Ok(fetch_state.rewriter.rewrite(&payload))
+    };
+
+    match state.coalescer.run(path, fetch).await {
+        Ok(payload) => (
+            [(header::CONTENT_TYPE, "application/json")],
+            payload.to_string(),
+        )
+            .into_response(),
+        Err(e) => {
+            let status = e.status();
+            (status, status.canonical_reason().unwrap_or_default()).into_response()
+        }
+    }
+}
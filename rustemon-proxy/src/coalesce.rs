@@ -0,0 +1,66 @@
+//! Coalescing of identical requests in flight.
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use futures::{
+    FutureExt,
+    future::{BoxFuture, Shared},
+};
+
+type SharedFetch<T, E> = Shared<BoxFuture<'static, Result<Arc<T>, Arc<E>>>>;
+
+/// Runs at most one fetch per key at a time, sharing its outcome with every caller
+/// asking for the same key meanwhile.
+pub struct Coalescer<T, E> {
+    in_flight: Mutex<HashMap<String, SharedFetch<T, E>>>,
+}
+
+impl<T, E> Default for Coalescer<T, E> {
+    fn default() -> Self {
+        Self {
+            in_flight: Mutex::default(),
+        }
+    }
+}
+
+impl<T, E> Coalescer<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+    /// Awaits the fetch in flight for `key`, or starts `fetch` if there is none.
+    pub async fn run<F>(&self, key: String, fetch: F) -> Result<Arc<T>, Arc<E>>
+    where
+        F: Future<Output = Result<T, E>> + Send + 'static,
+    {
+        let shared = self
+            .lock()
+            .entry(key.clone())
+            .or_insert_with(|| {
+                fetch
+                    .map(|r| r.map(Arc::new).map_err(Arc::new))
+                    .boxed()
+                    .shared()
+            })
+            .clone();
+
+        let result = shared.clone().await;
+
+        let mut in_flight = self.lock();
+        if in_flight.get(&key).is_some_and(|s| s.ptr_eq(&shared)) {
+            in_flight.remove(&key);
+        }
+
+        result
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, SharedFetch<T, E>>> {
+        self.in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
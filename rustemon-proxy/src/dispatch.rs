@@ -0,0 +1,107 @@
+//! Maps the `PokeApi` REST layout onto the paths forwarded to the upstream.
+
+/// Endpoints of `PokeApi` served by the proxy.
+const ENDPOINTS: &[&str] = &[
+    // Berries endpoints
+    "berry",
+    "berry-firmness",
+    "berry-flavor",
+    // Contests endpoints
+    "contest-type",
+    "contest-effect",
+    "super-contest-effect",
+    // Encounters endpoints
+    "encounter-method",
+    "encounter-condition",
+    "encounter-condition-value",
+    // Evolution endpoints
+    "evolution-chain",
+    "evolution-trigger",
+    // Games endpoints
+    "generation",
+    "pokedex",
+    "version",
+    "version-group",
+    // Items endpoints
+    "item",
+    "item-attribute",
+    "item-category",
+    "item-fling-effect",
+    "item-pocket",
+    // Locations endpoints
+    "location",
+    "location-area",
+    "pal-park-area",
+    "region",
+    // Machines endpoints
+    "machine",
+    // Moves endpoints
+    "move",
+    "move-ailment",
+    "move-battle-style",
+    "move-category",
+    "move-damage-class",
+    "move-learn-method",
+    "move-target",
+    // Pokemon endpoints
+    "ability",
+    "characteristic",
+    "egg-group",
+    "gender",
+    "growth-rate",
+    "nature",
+    "pokeathlon-stat",
+    "pokemon",
+    "pokemon-color",
+    "pokemon-form",
+    "pokemon-habitat",
+    "pokemon-shape",
+    "pokemon-species",
+    "stat",
+    "type",
+    // Utility endpoints
+    "language",
+];
+
+/// What an incoming request targets within an endpoint.
+pub enum Target {
+    /// A page of the resource list, the parameters being passed through when given.
+    Page {
+        /// The offset of the page.
+        offset: Option<i64>,
+        /// The size of the page.
+        limit: Option<i64>,
+    },
+    /// A single resource, by id or name.
+    Resource(String),
+    /// The encounters of a single Pokémon, by id or name.
+    Encounters(String),
+}
+
+/// Returns the path of the `target` of `endpoint`, relative to the upstream base.
+///
+/// Returns `None` when the endpoint doesn't exist in `PokeApi`.
+pub fn upstream_path(endpoint: &str, target: &Target) -> Option<String> {
+    if !ENDPOINTS.contains(&endpoint) {
+        return None;
+    }
+
+    match target {
+        Target::Page { offset, limit } => {
+            let params: Vec<String> = [("limit", limit), ("offset", offset)]
+                .into_iter()
+                .filter_map(|(name, value)| Some(format!("{name}={}", (*value)?)))
+                .collect();
+            if params.is_empty() {
+                Some(endpoint.to_owned())
+            } else {
+                Some(format!("{endpoint}?{}", params.join("&")))
+            }
+        }
+        Target::Resource(id) => Some(format!("{endpoint}/{id}")),
+        Target::Encounters(id) if endpoint == "pokemon" => {
+            Some(format!("{endpoint}/{id}/encounters"))
+        }
+        Target::Encounters(_) => None,
+    }
+}
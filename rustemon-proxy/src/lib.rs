@@ -0,0 +1,7 @@
+//! Building blocks of the `rustemon-proxy` binary, exposed to run the proxy in-process, against a local upstream
+//! stand-in in the tests for example.
+
+pub mod app;
+pub mod coalesce;
+pub mod dispatch;
+pub mod rewrite;
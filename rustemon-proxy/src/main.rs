@@ -0,0 +1,91 @@
+//! Caching reverse-proxy speaking the `PokeApi` REST layout.
+//!
+//! Every `/api/v2/<endpoint>/`, `/api/v2/<endpoint>/<id-or-name>/` and
+//! `/api/v2/pokemon/<id-or-name>/encounters` request is answered through a [`RustemonClient`],
+//! benefiting from its cache and rate limiting. Identical requests in flight share a single upstream
+//! call, and the URLs found in payloads are rewritten to point at the proxy.
+//!
+//! Pointing `--upstream` at a local stand-in allows running the proxy without reaching the internet.
+
+use std::{net::SocketAddr, path::PathBuf, time::Duration};
+
+use clap::Parser;
+use rustemon::client::{
+    CACacheManager, Environment, MokaManager, RustemonClient, RustemonClientBuilder,
+};
+
+use rustemon_proxy::{
+    app::{self, AppState},
+    rewrite::UrlRewriter,
+};
+
+/// Base URL of the production `PokeApi`, always rewritten in payloads.
+const PRODUCTION_BASE: &str = "https://pokeapi.co/api/v2/";
+
+#[derive(Parser)]
+#[command(about = "Caching reverse-proxy speaking the PokeApi REST layout")]
+struct Args {
+    /// Address the proxy listens on.
+    #[arg(long, default_value = "127.0.0.1:8000")]
+    listen: SocketAddr,
+    /// Base URL of the upstream `PokeApi`.
+    #[arg(long, default_value = PRODUCTION_BASE)]
+    upstream: String,
+    /// Base URL the proxy is reachable at, used when rewriting payloads.
+    /// Defaults to `http://<listen>/api/v2/`.
+    #[arg(long)]
+    public_url: Option<String>,
+    /// Directory holding the cache. The cache is kept in memory when omitted.
+    #[arg(long)]
+    cache_dir: Option<PathBuf>,
+    /// Maximum number of calls reaching the upstream every second.
+    #[arg(long, default_value_t = 10)]
+    max_requests_per_second: u32,
+}
+
+fn with_trailing_slash(mut url: String) -> String {
+    if !url.ends_with('/') {
+        url.push('/');
+    }
+    url
+}
+
+fn build_client(args: &Args) -> Result<RustemonClient, rustemon::error::Error> {
+    let environment = Environment::Custom(args.upstream.clone());
+    let period = Duration::from_secs(1);
+
+    match &args.cache_dir {
+        Some(cache_dir) => RustemonClientBuilder::default()
+            .with_manager(CACacheManager::new(cache_dir.clone(), false))
+            .with_environment(environment)
+            .with_rate_limit(args.max_requests_per_second, period)
+            .try_build(),
+        None => RustemonClientBuilder::<MokaManager>::default()
+            .with_environment(environment)
+            .with_rate_limit(args.max_requests_per_second, period)
+            .try_build(),
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    let client = build_client(&args)?;
+
+    let public_base = with_trailing_slash(
+        args.public_url
+            .clone()
+            .unwrap_or_else(|| format!("http://{}/api/v2/", args.listen)),
+    );
+    let mut upstream_bases = vec![with_trailing_slash(args.upstream.clone())];
+    if !upstream_bases.iter().any(|base| base == PRODUCTION_BASE) {
+        upstream_bases.push(PRODUCTION_BASE.to_owned());
+    }
+    let rewriter = UrlRewriter::new(upstream_bases, public_base);
+
+    let listener = tokio::net::TcpListener::bind(args.listen).await?;
+    axum::serve(listener, app::router(AppState::new(client, rewriter))).await?;
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+//! Rewrites the URLs found in payloads so they point at the proxy.
+
+/// Replaces the base of the upstream URLs with the public base of the proxy.
+pub struct UrlRewriter {
+    upstream_bases: Vec<String>,
+    public_base: String,
+}
+
+impl UrlRewriter {
+    /// Creates a rewriter replacing any of `upstream_bases` by `public_base`.
+    ///
+    /// Every base is expected to end with a `/`.
+    pub fn new(upstream_bases: Vec<String>, public_base: String) -> Self {
+        Self {
+            upstream_bases,
+            public_base,
+        }
+    }
+
+    /// Rewrites every JSON string of `payload` starting with one of the upstream bases, leaving the rest of the
+    /// payload untouched.
+    pub fn rewrite(&self, payload: &str) -> String {
+        let mut rewritten = String::with_capacity(payload.len());
+        let mut rest = payload;
+        while let Some(index) = rest.find('"') {
+            rewritten.push_str(&rest[..=index]);
+            rest = &rest[index + 1..];
+            if let Some(base) = self
+                .upstream_bases
+                .iter()
+                .find(|base| rest.starts_with(base.as_str()))
+            {
+                rewritten.push_str(&self.public_base);
+                rest = &rest[base.len()..];
+            }
+        }
+        rewritten.push_str(rest);
+
+        rewritten
+    }
+}
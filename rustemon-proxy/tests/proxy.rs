@@ -0,0 +1,214 @@
+//! Runs the proxy in-process against a local stand-in of `PokeApi`.
+
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use axum::{
+    Router,
+    extract::State,
+    http::{StatusCode, Uri},
+    response::{IntoResponse, Response},
+};
+use rustemon::client::{CacheMode, Environment, MokaManager, RustemonClientBuilder};
+use rustemon_proxy::{
+    app::{self, AppState},
+    rewrite::UrlRewriter,
+};
+use serde_json::{Value, json};
+use tokio::net::TcpListener;
+
+/// Base URL of the production `PokeApi`, which the stand-in also uses in its payloads.
+const PRODUCTION_BASE: &str = "https://pokeapi.co/api/v2/";
+
+/// Stand-in of `PokeApi`, recording the path and query of every request it receives.
+#[derive(Clone)]
+struct Upstream {
+    base: String,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl Upstream {
+    fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+async fn upstream(State(upstream): State<Upstream>, uri: Uri) -> Response {
+    let path_and_query = uri.path_and_query().map_or(uri.path(), |pq| pq.as_str());
+    upstream
+        .requests
+        .lock()
+        .unwrap()
+        .push(path_and_query.to_owned());
+    // Leaves time for identical requests to pile up in the proxy.
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let base = &upstream.base;
+    let payload = match path_and_query {
+        "/api/v2/pokemon/charizard" | "/api/v2/pokemon/slowpoke" => json!({
+            "id": 6,
+            "name": "charizard",
+            "species": { "name": "charizard", "url": format!("{base}pokemon-species/6/") },
+            "types": [{ "slot": 1, "type": { "name": "fire", "url": format!("{PRODUCTION_BASE}type/10/") } }],
+            "field_unknown_to_rustemon": { "nested": [1, 2, 3] },
+        }),
+        "/api/v2/berry?limit=2&offset=4" => json!({
+            "count": 64,
+            "next": format!("{base}berry?offset=6&limit=2"),
+            "previous": format!("{base}berry?offset=2&limit=2"),
+            "results": [
+                { "name": "pecha", "url": format!("{base}berry/5/") },
+                { "name": "rawst", "url": format!("{base}berry/6/") },
+            ],
+        }),
+        "/api/v2/pokemon/6/encounters" => json!([{
+            "location_area": { "name": "kanto-route-1-area", "url": format!("{base}location-area/1/") },
+            "version_details": [],
+        }]),
+        _ => return StatusCode::NOT_FOUND.into_response(),
+    };
+
+    payload.to_string().into_response()
+}
+
+/// Proxy running in front of a stand-in of `PokeApi`.
+struct Harness {
+    upstream: Upstream,
+    proxy_base: String,
+}
+
+impl Harness {
+    async fn start() -> Self {
+        let upstream_listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let upstream = Upstream {
+            base: format!("http://{}/api/v2/", upstream_listener.local_addr().unwrap()),
+            requests: Arc::default(),
+        };
+        let router = Router::new()
+            .fallback(self::upstream)
+            .with_state(upstream.clone());
+        tokio::spawn(async move { axum::serve(upstream_listener, router).await });
+
+        let proxy_listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let proxy_address: SocketAddr = proxy_listener.local_addr().unwrap();
+        let proxy_base = format!("http://{proxy_address}/api/v2/");
+        let client = RustemonClientBuilder::<MokaManager>::default()
+            .with_mode(CacheMode::NoStore)
+            .with_environment(Environment::Custom(upstream.base.clone()))
+            .try_build()
+            .unwrap();
+        let rewriter = UrlRewriter::new(
+            vec![upstream.base.clone(), PRODUCTION_BASE.to_owned()],
+            proxy_base.clone(),
+        );
+        let router = app::router(AppState::new(client, rewriter));
+        tokio::spawn(async move { axum::serve(proxy_listener, router).await });
+
+        Self {
+            upstream,
+            proxy_base,
+        }
+    }
+
+    async fn get(&self, path: &str) -> reqwest::Response {
+        reqwest::get(format!("{}{path}", self.proxy_base))
+            .await
+            .unwrap()
+    }
+
+    async fn get_json(&self, path: &str) -> Value {
+        let response = self.get(path).await;
+        assert_eq!(response.status(), StatusCode::OK, "GET {path}");
+
+        serde_json::from_str(&response.text().await.unwrap()).unwrap()
+    }
+}
+
+#[tokio::test]
+async fn serves_the_pokeapi_path_layout() {
+    let harness = Harness::start().await;
+
+    for path in ["pokemon/charizard", "pokemon/charizard/"] {
+        assert_eq!(harness.get_json(path).await["name"], "charizard");
+    }
+    let encounters = harness.get_json("pokemon/6/encounters/").await;
+    assert_eq!(encounters[0]["location_area"]["name"], "kanto-route-1-area");
+
+    assert_eq!(
+        harness.get("pokemon/missingno").await.status(),
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(
+        harness.get("not-an-endpoint/1").await.status(),
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(
+        harness.get("berry/1/encounters").await.status(),
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(
+        harness.upstream.requests(),
+        [
+            "/api/v2/pokemon/charizard",
+            "/api/v2/pokemon/charizard",
+            "/api/v2/pokemon/6/encounters",
+            "/api/v2/pokemon/missingno",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn passes_pagination_through() {
+    let harness = Harness::start().await;
+
+    let page = harness.get_json("berry/?offset=4&limit=2").await;
+
+    assert_eq!(
+        harness.upstream.requests(),
+        ["/api/v2/berry?limit=2&offset=4"]
+    );
+    assert_eq!(page["count"], 64);
+    assert_eq!(page["results"][1]["name"], "rawst");
+    assert_eq!(
+        page["next"],
+        format!("{}berry?offset=6&limit=2", harness.proxy_base)
+    );
+    assert_eq!(
+        page["previous"],
+        format!("{}berry?offset=2&limit=2", harness.proxy_base)
+    );
+}
+
+#[tokio::test]
+async fn rewrites_urls_and_keeps_unknown_fields() {
+    let harness = Harness::start().await;
+
+    let charizard = harness.get_json("pokemon/charizard/").await;
+
+    assert_eq!(
+        charizard["species"]["url"],
+        format!("{}pokemon-species/6/", harness.proxy_base)
+    );
+    assert_eq!(
+        charizard["types"][0]["type"]["url"],
+        format!("{}type/10/", harness.proxy_base)
+    );
+    assert_eq!(
+        charizard["field_unknown_to_rustemon"],
+        json!({ "nested": [1, 2, 3] })
+    );
+}
+
+#[tokio::test]
+async fn coalesces_identical_requests_in_flight() {
+    let harness = Harness::start().await;
+
+    let responses =
+        futures::future::join_all((0..8).map(|_| harness.get_json("pokemon/slowpoke/"))).await;
+
+    assert!(responses.iter().all(|slowpoke| slowpoke["id"] == 6));
+    assert_eq!(harness.upstream.requests(), ["/api/v2/pokemon/slowpoke"]);
+}
@@ -1,19 +1,97 @@
-use std::{any, fs::OpenOptions, io::BufWriter};
+use std::{
+    any,
+    collections::BTreeMap,
+    fs::{File, OpenOptions},
+    io::BufWriter,
+};
 
 use anyhow::anyhow;
+use flate2::{Compression, write::GzEncoder};
 use rustemon::{
     berries, client::RustemonClient, contests, encounters, evolution, games, items, locations,
-    machines, moves, pokemon, utility,
+    machines, model::resource::Endpoint, moves, pokemon, registry::Registry, utility,
 };
 use serde::Serialize;
+use serde_json::Value;
 use tera::{Context, Tera};
 
-async fn get_json_body<T: Serialize, Fut: Future<Output = Result<T, rustemon::error::Error>>>(
-    supplier: impl FnOnce() -> Fut,
-) -> anyhow::Result<(String, String, String)> {
-    let value = supplier().await?;
-    let json_body = serde_json::to_string(&value)?;
+/// Pokémon whose encounters are stored.
+const ENCOUNTERS: [i64; 3] = [1, 6, 133];
+
+/// Number of resources stored at least for every endpoint.
+const MIN_RESOURCES: usize = 2;
+
+/// Payloads stored compressed alongside `static_resources.rs`.
+#[derive(Default, Serialize)]
+struct Fixtures {
+    resources: BTreeMap<&'static str, Vec<Value>>,
+    pages: BTreeMap<&'static str, Value>,
+    encounters: BTreeMap<i64, Value>,
+}
+
+/// Getter generated for a model: its snake case name, its path in the crate and the id it returns.
+type Getter = (String, String, i64);
+
+impl Fixtures {
+    /// Stores the resources of an endpoint along with its first page.
+    /// The first resource is the one returned by the getter of the model.
+    fn add<T>(&mut self, resources: Vec<T>, page: T::List) -> anyhow::Result<Getter>
+    where
+        T: Endpoint + Serialize,
+        T::List: Serialize,
+    {
+        let values = resources
+            .iter()
+            .map(serde_json::to_value)
+            .collect::<Result<Vec<_>, _>>()?;
+        let id = values
+            .first()
+            .and_then(|value| value["id"].as_i64())
+            .ok_or_else(|| anyhow!("No resource with an id for {}", T::NAME))?;
 
+        self.resources.insert(T::NAME, values);
+        self.pages.insert(T::NAME, serde_json::to_value(page)?);
+
+        getter::<T>(id)
+    }
+
+    /// Checks that every endpoint has several resources and a non-empty first page, and that encounters were
+    /// stored, so that a partial pull is never written over the fixtures.
+    fn check(&self) -> anyhow::Result<()> {
+        let mut problems = Vec::new();
+        for (endpoint, resources) in &self.resources {
+            if resources.len() < MIN_RESOURCES {
+                problems.push(format!(
+                    "{endpoint} has {} resources, at least {MIN_RESOURCES} expected",
+                    resources.len()
+                ));
+            }
+            let page_is_empty = self.pages.get(endpoint).is_none_or(|page| {
+                page["results"]
+                    .as_array()
+                    .is_none_or(|results| results.is_empty())
+            });
+            if page_is_empty {
+                problems.push(format!("{endpoint} has no first page"));
+            }
+        }
+        if !self
+            .encounters
+            .values()
+            .any(|encounters| encounters.as_array().is_some_and(|e| !e.is_empty()))
+        {
+            problems.push("no encounters were stored".to_owned());
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!("Incomplete fixtures: {}", problems.join(", ")))
+        }
+    }
+}
+
+fn getter<T>(id: i64) -> anyhow::Result<Getter> {
     let full_path = any::type_name::<T>();
     let type_name = full_path
         .rsplit_once("::")
@@ -31,73 +109,95 @@ async fn get_json_body<T: Serialize, Fut: Future<Output = Result<T, rustemon::er
 
     let crate_path = full_path.replace("rustemon", "crate");
 
-    Ok((snake_case_name, crate_path, json_body))
+    Ok((snake_case_name, crate_path, id))
 }
 
-async fn get_data(rc: &RustemonClient) -> anyhow::Result<Vec<(String, String, String)>> {
-    let payloads = vec![
+/// Fetches the given resources, by id or name, and the first page of every endpoint.
+macro_rules! fixtures {
+    ($rc:ident; $($group:ident::$endpoint:ident => [$($id:literal),+]),+ $(,)?) => {{
+        let mut fixtures = Fixtures::default();
+        let mut getters = Vec::new();
+        $(
+            let resources = vec![$($group::$endpoint::get_by_name(&$id.to_string(), $rc).await?),+];
+            let page = $group::$endpoint::get_page($rc).await?;
+            getters.push(fixtures.add(resources, page)?);
+        )+
+
+        (fixtures, getters)
+    }};
+}
+
+async fn get_data(rc: &RustemonClient) -> anyhow::Result<(Fixtures, Vec<Getter>)> {
+    let (mut fixtures, getters) = fixtures!(rc;
         // Berries endpoints
-        get_json_body(|| berries::berry::get_by_id(1, rc)).await?,
-        get_json_body(|| berries::berry_firmness::get_by_id(1, rc)).await?,
-        get_json_body(|| berries::berry_flavor::get_by_id(1, rc)).await?,
+        berries::berry => [1, 2],
+        berries::berry_firmness => [1, 2],
+        berries::berry_flavor => [1, 2],
         // Contests endpoints
-        get_json_body(|| contests::contest_type::get_by_id(1, rc)).await?,
-        get_json_body(|| contests::contest_effect::get_by_id(1, rc)).await?,
-        get_json_body(|| contests::super_contest_effect::get_by_id(1, rc)).await?,
+        contests::contest_type => [1, 2],
+        contests::contest_effect => [1, 2],
+        contests::super_contest_effect => [1, 2],
         // Encounters endpoints
-        get_json_body(|| encounters::encounter_method::get_by_id(1, rc)).await?,
-        get_json_body(|| encounters::encounter_condition::get_by_id(1, rc)).await?,
-        get_json_body(|| encounters::encounter_condition_value::get_by_id(1, rc)).await?,
-        // Evolution endpoints
-        get_json_body(|| evolution::evolution_chain::get_by_id(2, rc)).await?,
-        get_json_body(|| evolution::evolution_trigger::get_by_id(1, rc)).await?,
+        encounters::encounter_method => [1, 2],
+        encounters::encounter_condition => [1, 2],
+        encounters::encounter_condition_value => [1, 2],
+        // Evolution endpoints, with the branched chain of Eevee
+        evolution::evolution_chain => [2, 67],
+        evolution::evolution_trigger => [1, 2],
         // Games endpoints
-        get_json_body(|| games::generation::get_by_id(1, rc)).await?,
-        get_json_body(|| games::pokedex::get_by_id(2, rc)).await?,
-        get_json_body(|| games::version::get_by_id(1, rc)).await?,
-        get_json_body(|| games::version_group::get_by_id(1, rc)).await?,
+        games::generation => [1, 2],
+        games::pokedex => [2, 1],
+        games::version => [1, 2],
+        games::version_group => [1, 2],
         // Items endpoints
-        get_json_body(|| items::item::get_by_id(1, rc)).await?,
-        get_json_body(|| items::item_attribute::get_by_id(1, rc)).await?,
-        get_json_body(|| items::item_category::get_by_id(3, rc)).await?,
-        get_json_body(|| items::item_fling_effect::get_by_id(3, rc)).await?,
-        get_json_body(|| items::item_pocket::get_by_id(3, rc)).await?,
+        items::item => [1, 2],
+        items::item_attribute => [1, 2],
+        items::item_category => [3, 1],
+        items::item_fling_effect => [3, 1],
+        items::item_pocket => [3, 1],
         // Locations endpoints
-        get_json_body(|| locations::location::get_by_id(5, rc)).await?,
-        get_json_body(|| locations::location_area::get_by_id(5, rc)).await?,
-        get_json_body(|| locations::pal_park_area::get_by_id(1, rc)).await?,
-        get_json_body(|| locations::region::get_by_id(1, rc)).await?,
+        locations::location => [5, 1],
+        locations::location_area => [5, 1],
+        locations::pal_park_area => [1, 2],
+        locations::region => [1, 2],
         // Machines endpoints
-        get_json_body(|| machines::machine::get_by_id(1, rc)).await?,
-        // Moves endpoints
-        get_json_body(|| moves::move_::get_by_id(126, rc)).await?,
-        get_json_body(|| moves::move_ailment::get_by_id(2, rc)).await?,
-        get_json_body(|| moves::move_battle_style::get_by_id(1, rc)).await?,
-        get_json_body(|| moves::move_category::get_by_id(0, rc)).await?,
-        get_json_body(|| moves::move_damage_class::get_by_id(2, rc)).await?,
-        get_json_body(|| moves::move_learn_method::get_by_id(1, rc)).await?,
-        get_json_body(|| moves::move_target::get_by_id(1, rc)).await?,
-        // Pokemon endpoints
-        get_json_body(|| pokemon::ability::get_by_id(9, rc)).await?,
-        get_json_body(|| pokemon::characteristic::get_by_id(1, rc)).await?,
-        get_json_body(|| pokemon::egg_group::get_by_id(14, rc)).await?,
-        get_json_body(|| pokemon::gender::get_by_id(3, rc)).await?,
-        get_json_body(|| pokemon::growth_rate::get_by_id(1, rc)).await?,
-        get_json_body(|| pokemon::nature::get_by_id(2, rc)).await?,
-        get_json_body(|| pokemon::pokeathlon_stat::get_by_id(2, rc)).await?,
-        get_json_body(|| pokemon::pokemon::get_by_id(6, rc)).await?,
-        get_json_body(|| pokemon::pokemon_color::get_by_id(5, rc)).await?,
-        get_json_body(|| pokemon::pokemon_form::get_by_id(6, rc)).await?,
-        get_json_body(|| pokemon::pokemon_habitat::get_by_id(4, rc)).await?,
-        get_json_body(|| pokemon::pokemon_shape::get_by_id(9, rc)).await?,
-        get_json_body(|| pokemon::pokemon_species::get_by_id(6, rc)).await?,
-        get_json_body(|| pokemon::stat::get_by_id(4, rc)).await?,
-        get_json_body(|| pokemon::type_::get_by_id(10, rc)).await?,
+        machines::machine => [1, 2],
+        // Moves endpoints, with moves having past values or no power
+        moves::move_ => [126, 33, 237],
+        moves::move_ailment => [2, 1],
+        moves::move_battle_style => [1, 2],
+        moves::move_category => [0, 1],
+        moves::move_damage_class => [2, 1],
+        moves::move_learn_method => [1, 2],
+        moves::move_target => [1, 2],
+        // Pokemon endpoints, with dual types, past types and alternate forms
+        pokemon::ability => [9, 1],
+        pokemon::characteristic => [1, 2],
+        pokemon::egg_group => [14, 1],
+        pokemon::gender => [3, 1],
+        pokemon::growth_rate => [1, 2],
+        pokemon::nature => [2, 1],
+        pokemon::pokeathlon_stat => [2, 1],
+        pokemon::pokemon => [6, 1, 35, 133, "charizard-mega-x"],
+        pokemon::pokemon_color => [5, 1],
+        pokemon::pokemon_form => [6, "charizard-mega-x"],
+        pokemon::pokemon_habitat => [4, 1],
+        pokemon::pokemon_shape => [9, 1],
+        pokemon::pokemon_species => [6, 133, 201],
+        pokemon::stat => [4, 1],
+        pokemon::type_ => [10, 3, 18],
         // Utility endpoints
-        get_json_body(|| utility::language::get_by_id(2, rc)).await?,
-    ];
+        utility::language => [2, 9],
+    );
+
+    for id in ENCOUNTERS {
+        let encounters = pokemon::pokemon::encounters::get_by_id(id, rc).await?;
+        fixtures
+            .encounters
+            .insert(id, serde_json::to_value(encounters)?);
+    }
 
-    Ok(payloads)
+    Ok((fixtures, getters))
 }
 
 #[tokio::main]
@@ -109,8 +209,20 @@ async fn main() -> anyhow::Result<()> {
         "./rustemon-static-test/src/template.tera",
         Some("template.tera"),
     )?;
+    template.add_template_file(
+        "./rustemon-static-test/src/registry.tera",
+        Some("registry.tera"),
+    )?;
 
-    let payloads = get_data(&rc).await?;
+    let (fixtures, getters) = get_data(&rc).await?;
+    fixtures.check()?;
+
+    let mut encoder = GzEncoder::new(
+        File::create("./rustemon/fixtures/static_resources.json.gz")?,
+        Compression::best(),
+    );
+    serde_json::to_writer(&mut encoder, &fixtures)?;
+    encoder.finish()?;
 
     let file = OpenOptions::new()
         .read(true)
@@ -121,9 +233,18 @@ async fn main() -> anyhow::Result<()> {
     let writer = BufWriter::new(file);
 
     let mut context = Context::new();
-    context.insert("payloads", &payloads);
+    context.insert("getters", &getters);
 
     template.render_to("template.tera", &context, writer)?;
 
+    // The games known to the built-in registry, looked up without network.
+    let registry = Registry::fetch(&rc).await?;
+    let writer = BufWriter::new(File::create("./rustemon/src/registry/data.rs")?);
+
+    let mut context = Context::new();
+    context.insert("registry", &registry);
+
+    template.render_to("registry.tera", &context, writer)?;
+
     Ok(())
 }
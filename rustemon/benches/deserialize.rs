@@ -0,0 +1,59 @@
+//! Compares the decoding of representative payloads with `serde_json` and with `simd-json`, as done by the client
+//! with the `simd-json` feature, and the decoding of the summary models with the one of the full models.
+
+use std::hint::black_box;
+
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+use rustemon::{
+    model::pokemon::{Pokemon, PokemonSummary},
+    static_resources,
+};
+use serde::{Serialize, de::DeserializeOwned};
+
+fn bench_model<T: Serialize + DeserializeOwned>(c: &mut Criterion, name: &str, model: T) {
+    let payload = serde_json::to_vec(&model).unwrap();
+    let mut group = c.benchmark_group(name);
+
+    group.bench_function("serde_json", |b| {
+        b.iter(|| serde_json::from_slice::<T>(black_box(&payload)).unwrap())
+    });
+    // simd-json decodes in place, the payload is copied before every iteration, outside of the measurement.
+    group.bench_function("simd-json", |b| {
+        b.iter_batched_ref(
+            || payload.clone(),
+            |payload| simd_json::serde::from_slice::<T>(black_box(payload)).unwrap(),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+/// Decodes the same `pokemon` payload, as done by `get_by_id` and by `get_summary_by_id`.
+fn bench_summary(c: &mut Criterion) {
+    let payload = serde_json::to_vec(&static_resources::get_pokemon()).unwrap();
+    let mut group = c.benchmark_group("pokemon_summary");
+
+    group.bench_function("Pokemon", |b| {
+        b.iter(|| serde_json::from_slice::<Pokemon>(black_box(&payload)).unwrap())
+    });
+    group.bench_function("PokemonSummary", |b| {
+        b.iter(|| serde_json::from_slice::<PokemonSummary>(black_box(&payload)).unwrap())
+    });
+
+    group.finish();
+}
+
+fn deserialize(c: &mut Criterion) {
+    bench_model(c, "pokemon", static_resources::get_pokemon());
+    bench_model(c, "type", static_resources::get_type());
+    bench_model(c, "move", static_resources::get_move());
+    bench_model(
+        c,
+        "evolution_chain",
+        static_resources::get_evolution_chain(),
+    );
+}
+
+criterion_group!(benches, deserialize, bench_summary);
+criterion_main!(benches);
@@ -1,4 +1,7 @@
-use rustemon::static_resources;
+use rustemon::{
+    model::{evolution::EvolutionChain, moves::Move, pokemon::Pokemon},
+    static_resources,
+};
 
 #[tokio::main]
 async fn main() {
@@ -50,4 +53,23 @@ async fn main() {
     static_resources::get_stat();
     static_resources::get_type();
     println!("{:?}", static_resources::get_language());
+
+    // Resources are also looked up by id or name, along with the pages and encounters pulled.
+    println!(
+        "{:?}",
+        static_resources::get_by_name::<Pokemon>("charizard").map(|p| p.id)
+    );
+    println!(
+        "{:?}",
+        static_resources::get_by_id::<EvolutionChain>(67).map(|c| c.id)
+    );
+    println!("{}", static_resources::get_all::<Move>().len());
+    println!(
+        "{:?}",
+        static_resources::get_page::<Pokemon>().map(|p| p.count)
+    );
+    println!(
+        "{:?}",
+        static_resources::get_encounters_by_name("charizard").map(|e| e.len())
+    );
 }
@@ -0,0 +1,15 @@
+//! Exports the JSON Schema of every resource, one file per endpoint, into the given directory.
+
+use std::env;
+
+fn main() -> std::io::Result<()> {
+    let directory = env::args().nth(1).unwrap_or_else(|| "schemas".to_owned());
+
+    rustemon::schema::export(&directory)?;
+
+    for endpoint in rustemon::schema::resource_schemas().keys() {
+        println!("{directory}/{endpoint}.json");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,167 @@
+//! Measures the memory held by the models decoded from a full snapshot of `PokeApi`, laid out like the `PokeAPI/api-data`
+//! repository: `<snapshot>/<endpoint>/<id>/index.json`, and by compact models of the same resources only keeping some of
+//! their fields, held through the interned views of `rustemon::model::shared`, e.g.
+//! `cargo run --release --example memory_shared_strings --features shared-strings -- api-data/data/api/v2`.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    env, fs,
+    path::Path,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use rustemon::model::{
+    games::VersionGroup,
+    items::Item,
+    locations::LocationArea,
+    moves::{Move, MoveLearnMethod},
+    pokemon::{Ability, Pokemon, PokemonForm, PokemonSpecies, Type},
+    resource::Endpoint,
+    shared::{SharedFlavorText, SharedName, SharedNamedApiResource, SharedStr},
+};
+use serde::{Deserialize, de::DeserializeOwned};
+
+/// Allocator keeping track of the bytes currently allocated.
+struct Counting;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATED.fetch_add(new_size, Ordering::Relaxed);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// Pokémon only keeping its types and the moves it learns.
+#[derive(Deserialize)]
+#[allow(dead_code)]
+struct CompactPokemon {
+    id: i64,
+    name: SharedStr,
+    types: Vec<CompactType>,
+    moves: Vec<CompactMove>,
+}
+
+#[derive(Deserialize)]
+#[allow(dead_code)]
+struct CompactType {
+    slot: i64,
+    #[serde(rename = "type")]
+    type_: SharedNamedApiResource<Type>,
+}
+
+#[derive(Deserialize)]
+#[allow(dead_code)]
+struct CompactMove {
+    #[serde(rename = "move")]
+    move_: SharedNamedApiResource<Move>,
+    version_group_details: Vec<CompactMoveVersion>,
+}
+
+#[derive(Deserialize)]
+#[allow(dead_code)]
+struct CompactMoveVersion {
+    move_learn_method: SharedNamedApiResource<MoveLearnMethod>,
+    version_group: SharedNamedApiResource<VersionGroup>,
+    level_learned_at: i64,
+}
+
+/// Pokémon species only keeping its localized texts.
+#[derive(Deserialize)]
+#[allow(dead_code)]
+struct CompactSpecies {
+    id: i64,
+    name: SharedStr,
+    names: Vec<SharedName>,
+    flavor_text_entries: Vec<SharedFlavorText>,
+}
+
+/// Decodes into `T`s every resource of the endpoint found in the snapshot.
+fn load<T: DeserializeOwned>(snapshot: &Path, endpoint: &str) -> Vec<T> {
+    let Ok(entries) = fs::read_dir(snapshot.join(endpoint)) else {
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(|entry| fs::read(entry.ok()?.path().join("index.json")).ok())
+        .map(|json| serde_json::from_slice(&json).unwrap())
+        .collect()
+}
+
+/// Decodes the resources of the endpoint of `T`, printing how many there are and the memory they hold.
+fn measure<T: Endpoint + DeserializeOwned>(snapshot: &Path) -> Vec<T> {
+    measure_as(snapshot, T::NAME, T::NAME)
+}
+
+/// Decodes into `T`s the resources of the endpoint, printing how many there are and the memory they hold under `label`.
+fn measure_as<T: DeserializeOwned>(snapshot: &Path, endpoint: &str, label: &str) -> Vec<T> {
+    let before = ALLOCATED.load(Ordering::Relaxed);
+    let resources = load::<T>(snapshot, endpoint);
+    let held = ALLOCATED.load(Ordering::Relaxed) - before;
+    println!(
+        "{:>16}: {:>6} resources, {:>8.2} MiB",
+        label,
+        resources.len(),
+        held as f64 / (1024.0 * 1024.0)
+    );
+
+    resources
+}
+
+fn main() {
+    let Some(snapshot) = env::args().nth(1) else {
+        println!("usage: memory_shared_strings <snapshot directory>");
+        return;
+    };
+    let snapshot = Path::new(&snapshot);
+
+    println!("models:");
+    let before = ALLOCATED.load(Ordering::Relaxed);
+    let dataset = (
+        measure::<Pokemon>(snapshot),
+        measure::<PokemonSpecies>(snapshot),
+        measure::<PokemonForm>(snapshot),
+        measure::<Move>(snapshot),
+        measure::<Ability>(snapshot),
+        measure::<Item>(snapshot),
+        measure::<Type>(snapshot),
+        measure::<LocationArea>(snapshot),
+    );
+    let held = ALLOCATED.load(Ordering::Relaxed) - before;
+
+    println!(
+        "{:>16}: {:>8.2} MiB",
+        "total",
+        held as f64 / (1024.0 * 1024.0)
+    );
+    drop(dataset);
+
+    println!("compact models, holding interned strings:");
+    let dataset = (
+        measure_as::<CompactPokemon>(snapshot, Pokemon::NAME, "compact pokemon"),
+        measure_as::<CompactSpecies>(snapshot, PokemonSpecies::NAME, "compact species"),
+    );
+    drop(dataset);
+
+    // The interned strings outlive the compact models until released.
+    println!(
+        "{:>16}: {:>6} distinct strings",
+        "released",
+        rustemon::model::shared::release_unused()
+    );
+}
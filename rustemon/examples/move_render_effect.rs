@@ -0,0 +1,40 @@
+use rustemon::model::localized::Localized;
+
+#[tokio::main]
+async fn main() {
+    // Renders the effect and the flavor texts of Thunder, in English.
+    let rustemon_client = rustemon::client::RustemonClient::default();
+    let thunder = rustemon::moves::move_::get_by_name("thunder", &rustemon_client)
+        .await
+        .unwrap();
+
+    if let Some(effect) = thunder.effect_entries.entry_in("en") {
+        let effect = effect.render_effect(&thunder);
+        println!("{}", effect.to_plain());
+        println!("{}", effect.to_markdown());
+        println!("{}", effect.to_html());
+
+        for link in effect.links() {
+            println!("{} -> {:?}", link.text(), link.target.url());
+        }
+    }
+
+    // The chance of the effect before the given version group.
+    for past in &thunder.past_values {
+        for effect in past.effect_entries.entries_in("en") {
+            println!(
+                "{}: {}",
+                past.version_group.name,
+                effect.render_effect(&(past, &thunder))
+            );
+        }
+    }
+
+    for flavor_text in thunder.flavor_text_entries.entries_in("en") {
+        println!(
+            "{}: {}",
+            flavor_text.version_group.name,
+            flavor_text.normalized()
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use rustemon::client::RustemonClient;
+
+#[tokio::main]
+async fn main() {
+    let rustemon_client = RustemonClient::default();
+    let page = rustemon::pokemon::pokemon::get_page(&rustemon_client)
+        .await
+        .unwrap();
+
+    // Only the name, types and stats of the Pokémon are needed, leaving their moves and sprites undecoded.
+    for entry in page.results {
+        let pokemon =
+            rustemon::pokemon::pokemon::get_summary_by_name(&entry.name, &rustemon_client)
+                .await
+                .unwrap();
+        let types: Vec<_> = pokemon
+            .types
+            .iter()
+            .map(|t| t.type_.name.as_str())
+            .collect();
+        let total: i64 = pokemon.stats.iter().map(|s| s.base_stat).sum();
+        println!("{} {types:?}, base stat total {total}", pokemon.name);
+    }
+
+    // The moves are decoded on first access only.
+    let charizard = rustemon::pokemon::pokemon::get_summary_by_name("charizard", &rustemon_client)
+        .await
+        .unwrap();
+    let moves = charizard.moves.get().unwrap();
+    println!("{} can learn {} moves", charizard.name, moves.len());
+}
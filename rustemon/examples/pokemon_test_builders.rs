@@ -0,0 +1,37 @@
+use rustemon::{
+    client::Environment,
+    model::{
+        pokemon::{Pokemon, PokemonStat, PokemonType, Stat, Type},
+        resource::NamedApiResource,
+    },
+};
+
+fn main() {
+    let environment = Environment::Production;
+
+    // Only the fields relevant to the test are set, the others keep their default value.
+    let pokemon = Pokemon::builder()
+        .name("charizard")
+        .types(vec![
+            PokemonType::builder()
+                .slot(1)
+                .type_(NamedApiResource::<Type>::from_id(&environment, 10, "fire").unwrap())
+                .build(),
+            PokemonType::builder()
+                .slot(2)
+                .type_(NamedApiResource::<Type>::from_id(&environment, 3, "flying").unwrap())
+                .build(),
+        ])
+        .stats(vec![
+            PokemonStat::builder()
+                .stat(NamedApiResource::<Stat>::new(
+                    "speed",
+                    "https://pokeapi.co/api/v2/stat/6/",
+                ))
+                .base_stat(100)
+                .build(),
+        ])
+        .build();
+
+    println!("{pokemon:#?}");
+}
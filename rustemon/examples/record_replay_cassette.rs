@@ -0,0 +1,58 @@
+use axum::{Json, Router, routing::get};
+use rustemon::{
+    cassette::{Cassette, UrlMatching},
+    client::{CacheMode, Environment, MokaManager, RustemonClientBuilder},
+};
+use serde_json::json;
+
+#[tokio::main]
+async fn main() {
+    // A local stand-in of PokeApi, to record from without network.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let environment =
+        Environment::Custom(format!("http://{}/api/v2/", listener.local_addr().unwrap()));
+    let upstream = tokio::spawn(async move {
+        let router = Router::new().route(
+            "/api/v2/berry-firmness/soft",
+            get(|| async { Json(json!({ "id": 1, "name": "soft", "berries": [], "names": [] })) }),
+        );
+        axum::serve(listener, router).await.unwrap();
+    });
+    let path = std::env::temp_dir().join("rustemon-cassette.json");
+
+    // Records the interactions with PokeApi once...
+    let recording_client = RustemonClientBuilder::<MokaManager>::default()
+        .with_mode(CacheMode::NoStore)
+        .with_environment(environment)
+        .with_cassette(Cassette::record(&path).with_redacted_header("Set-Cookie"))
+        .try_build()
+        .unwrap();
+    let recorded = rustemon::berries::berry_firmness::get_by_name("soft", &recording_client)
+        .await
+        .unwrap();
+    // ... the cassette being written once the client is dropped...
+    drop(recording_client);
+    upstream.abort();
+
+    // ... then replays them without network, from any environment.
+    let replaying_client = RustemonClientBuilder::<MokaManager>::default()
+        .with_cassette(
+            Cassette::replay(&path)
+                .unwrap()
+                .with_url_matching(UrlMatching::Normalized),
+        )
+        .try_build()
+        .unwrap();
+    let replayed = rustemon::berries::berry_firmness::get_by_name("soft", &replaying_client)
+        .await
+        .unwrap();
+    assert_eq!(recorded, replayed);
+
+    // Unrecorded requests fail loudly.
+    if let Err(e) = rustemon::berries::berry_firmness::get_by_name("hard", &replaying_client).await
+    {
+        println!("{e}");
+    }
+
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,13 @@
+use rustemon::model::extra::unknown_keys;
+
+#[tokio::main]
+async fn main() {
+    let rustemon_client = rustemon::client::RustemonClient::default();
+    let pokemon = rustemon::pokemon::pokemon::get_by_name("charizard", &rustemon_client)
+        .await
+        .unwrap();
+
+    // Fields added by PokeApi since the models were written are kept when re-exporting.
+    println!("unknown fields: {:?}", unknown_keys(&pokemon));
+    println!("{}", serde_json::to_string(&pokemon).unwrap());
+}
@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+use http::StatusCode;
+use rustemon::{
+    client::{MokaManager, RustemonClientBuilder},
+    error::Error,
+    fault::{Fault, FaultInjector},
+};
+
+#[tokio::main]
+async fn main() {
+    // PokeApi first times out, then is overloaded, then sends garbage, before answering.
+    let rustemon_client = RustemonClientBuilder::<MokaManager>::default()
+        .with_fault_injector(FaultInjector::new(42).with_endpoint_script(
+            "pokemon",
+            [
+                Some(Fault::Timeout(Duration::from_millis(100))),
+                Some(Fault::Status(StatusCode::SERVICE_UNAVAILABLE)),
+                Some(Fault::MalformedJson),
+            ],
+        ))
+        .try_build()
+        .unwrap();
+
+    for attempt in 1.. {
+        match rustemon::pokemon::pokemon::get_by_name("charizard", &rustemon_client).await {
+            Ok(charizard) => {
+                println!("attempt {attempt}: got {}", charizard.name);
+                break;
+            }
+            Err(Error::Reqwest(e)) if e.status().is_some() => {
+                println!("attempt {attempt}: status {:?}", e.status())
+            }
+            Err(e) => println!("attempt {attempt}: {e}"),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use rustemon::{
+    client::{MokaManager, RustemonClientBuilder},
+    decode::Strictness,
+    error::Error,
+};
+
+#[tokio::main]
+async fn main() {
+    // In CI, fails as soon as PokeApi sends fields unknown to the models.
+    let strict_client = RustemonClientBuilder::<MokaManager>::default()
+        .with_strictness(Strictness::Strict)
+        .try_build()
+        .unwrap();
+    match rustemon::evolution::evolution_chain::get_by_id(67, &strict_client).await {
+        Ok(chain) => println!("evolution chain {} matches its model", chain.id),
+        Err(Error::UnknownFields { model, fields, .. }) => {
+            println!("{model} needs updating, unknown fields: {fields:?}")
+        }
+        Err(e) => println!("{e}"),
+    }
+
+    // In production, keeps working on upstream changes and reports them.
+    let lenient_client = RustemonClientBuilder::<MokaManager>::default()
+        .with_strictness(Strictness::Lenient)
+        .try_build()
+        .unwrap();
+    let eevee = rustemon::pokemon::pokemon::get_by_name("eevee", &lenient_client)
+        .await
+        .unwrap();
+    println!("got {}", eevee.name);
+    for warning in lenient_client.take_warnings() {
+        println!("{warning}");
+    }
+}
@@ -0,0 +1,47 @@
+use rustemon::mechanics::stats::{BaseStats, StatCalculator, StatSet};
+
+#[tokio::main]
+async fn main() {
+    let rustemon_client = rustemon::client::RustemonClient::default();
+
+    let garchomp = rustemon::pokemon::pokemon::get_by_name("garchomp", &rustemon_client)
+        .await
+        .unwrap();
+    let adamant = rustemon::pokemon::nature::get_by_name("adamant", &rustemon_client)
+        .await
+        .unwrap();
+
+    let base_stats = BaseStats::from(&garchomp);
+    let evs = StatSet {
+        hp: 74,
+        attack: 190,
+        defense: 91,
+        special_attack: 48,
+        special_defense: 84,
+        speed: 23,
+    };
+    evs.validate_evs().unwrap();
+
+    let stats = StatCalculator::new(&base_stats, 78)
+        .with_ivs(StatSet {
+            hp: 24,
+            attack: 12,
+            defense: 30,
+            special_attack: 16,
+            special_defense: 23,
+            speed: 5,
+        })
+        .with_evs(evs)
+        .with_nature(&adamant)
+        .stats()
+        .unwrap();
+    println!("{stats:?}");
+
+    // The same Garchomp with the DVs and stat experience of generations 1 and 2.
+    let stats = StatCalculator::new(&base_stats, 78)
+        .with_ivs(StatSet::splat(15))
+        .with_evs(StatSet::splat(65535))
+        .classic_stats()
+        .unwrap();
+    println!("{stats:?}");
+}
@@ -0,0 +1,44 @@
+use rustemon::mechanics::{
+    team::{Team, TeamMember},
+    type_chart::TypeChart,
+};
+
+#[tokio::main]
+async fn main() {
+    let rustemon_client = rustemon::client::RustemonClient::default();
+    let chart = TypeChart::fetch(&rustemon_client).await.unwrap();
+
+    let mut members = Vec::new();
+    for (pokemon, moves) in [
+        (
+            "charizard",
+            vec!["flamethrower", "air-slash", "dragon-pulse"],
+        ),
+        ("gyarados", vec!["waterfall", "dragon-dance"]),
+        ("venusaur", vec![]),
+    ] {
+        let pokemon = rustemon::pokemon::pokemon::get_by_name(pokemon, &rustemon_client)
+            .await
+            .unwrap();
+        let mut member = TeamMember::from_pokemon(&pokemon);
+        for move_ in moves {
+            let move_ = rustemon::moves::move_::get_by_name(move_, &rustemon_client)
+                .await
+                .unwrap();
+            member = member.with_move(&move_);
+        }
+        members.push(member);
+    }
+
+    let analysis = Team::new(members).unwrap().analyze(&chart);
+    println!("{}", analysis.defensive_markdown());
+    println!("{}", analysis.offensive_markdown());
+    for threat in analysis.threats() {
+        println!(
+            "{} threatens the team ({} weak)",
+            threat.attacker, threat.weak
+        );
+    }
+    println!("uncovered: {:?}", analysis.uncovered());
+    println!("suggestions: {:?}", analysis.suggestions);
+}
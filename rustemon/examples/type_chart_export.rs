@@ -0,0 +1,22 @@
+use rustemon::{mechanics::type_chart::TypeChart, registry::Registry};
+
+#[tokio::main]
+async fn main() {
+    let rustemon_client = rustemon::client::RustemonClient::default();
+
+    // The current chart, and the weaknesses of Gyarados.
+    let chart = TypeChart::fetch(&rustemon_client).await.unwrap();
+    println!("{}", chart.to_markdown());
+
+    let gyarados = rustemon::pokemon::pokemon::get_by_name("gyarados", &rustemon_client)
+        .await
+        .unwrap();
+    println!("{:?}", chart.matchups_of(&gyarados));
+
+    // The chart of the first generation, in which Psychic was immune to Ghost.
+    let chronology = Registry::builtin().chronology();
+    let first_generation = TypeChart::fetch_as_of(&rustemon_client, chronology.at_generation(1))
+        .await
+        .unwrap();
+    println!("{}", first_generation.to_csv());
+}
@@ -0,0 +1,322 @@
+//! Record and replay of the interactions with `PokeApi`, for deterministic tests.
+//!
+//! A [`Cassette`] attached to a [`RustemonClientBuilder`](crate::client::RustemonClientBuilder) through
+//! `with_cassette` either records every call made by the client into a file, or serves the calls from
+//! that file without ever reaching the network.
+//!
+//! The cassette sits behind the cache of the client, so the calls answered by the cache are neither
+//! recorded nor replayed. A recording cassette writes its file when flushed, and when the last of its
+//! clones, e.g. the one held by the client, is dropped.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use http::{Extensions, HeaderName, HeaderValue, StatusCode};
+use reqwest::{Request, Response, ResponseBuilderExt, Url};
+use reqwest_middleware::{Middleware, Next};
+use thiserror::Error;
+
+/// Value replacing the redacted headers in the cassette.
+const REDACTED: &str = "[REDACTED]";
+
+/// Errors raised while recording or replaying a [`Cassette`].
+#[derive(Debug, Error)]
+pub enum CassetteError {
+    /// Error raised when the cassette file can't be read or written.
+    #[error("couldn't access cassette `{0}`")]
+    Io(PathBuf, #[source] io::Error),
+    /// Error raised when the cassette file isn't a valid cassette.
+    #[error("couldn't parse cassette `{0}`")]
+    Parse(PathBuf, #[source] serde_json::Error),
+    /// Error raised in replay mode, when the cassette doesn't hold the requested interaction.
+    #[error("no interaction recorded for `{method} {url}` in cassette `{path}`")]
+    Unrecorded {
+        /// The method of the request.
+        method: String,
+        /// The URL of the request.
+        url: String,
+        /// The path of the cassette.
+        path: PathBuf,
+    },
+    /// Error raised in replay mode, when the recorded interaction doesn't hold a valid HTTP status.
+    #[error("invalid status `{status}` recorded for `{url}` in cassette `{path}`")]
+    InvalidStatus {
+        /// The recorded status.
+        status: u16,
+        /// The URL of the request.
+        url: String,
+        /// The path of the cassette.
+        path: PathBuf,
+    },
+}
+
+/// How requests are matched against the recorded interactions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UrlMatching {
+    /// The URLs must be identical.
+    #[default]
+    Exact,
+    /// Only the paths, ignoring trailing slashes, and the query parameters, in any order, must be identical.
+    /// Allows replaying a cassette recorded against another [`Environment`](crate::client::Environment).
+    Normalized,
+}
+
+/// Whether the cassette records or replays the interactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Record,
+    Replay,
+}
+
+/// A call made to `PokeApi` and its response.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct Interaction {
+    method: String,
+    url: String,
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+/// Content of a cassette file.
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+struct Tape {
+    interactions: Vec<Interaction>,
+    /// Whether interactions were recorded since the file was last written.
+    #[serde(skip)]
+    unsaved: bool,
+}
+
+/// Interactions of a cassette, shared by its clones.
+#[derive(Debug)]
+struct Reel {
+    path: PathBuf,
+    tape: Mutex<Tape>,
+}
+
+impl Reel {
+    /// Writes the whole tape to the file, if interactions were recorded since it was last written.
+    fn save(&self) -> Result<(), CassetteError> {
+        let mut tape = self.tape.lock().unwrap_or_else(PoisonError::into_inner);
+        if !tape.unsaved {
+            return Ok(());
+        }
+
+        let content = serde_json::to_vec_pretty(&*tape)
+            .map_err(|e| CassetteError::Parse(self.path.clone(), e))?;
+        fs::write(&self.path, content).map_err(|e| CassetteError::Io(self.path.clone(), e))?;
+        tape.unsaved = false;
+
+        Ok(())
+    }
+}
+
+impl Drop for Reel {
+    fn drop(&mut self) {
+        // Nothing to report the error to while dropping, `Cassette::flush` surfaces it.
+        let _ = self.save();
+    }
+}
+
+/// Middleware recording the interactions of a client into a file, or replaying them from it.
+///
+/// Clones share the same interactions, so a clone kept aside can [`flush`](Cassette::flush) the
+/// cassette attached to a client.
+#[derive(Debug, Clone)]
+pub struct Cassette {
+    mode: Mode,
+    url_matching: UrlMatching,
+    redacted_headers: Vec<String>,
+    reel: Arc<Reel>,
+}
+
+impl Cassette {
+    /// Creates a cassette recording every interaction into the file at `path`,
+    /// written when the cassette is flushed or dropped.
+    pub fn record(path: impl Into<PathBuf>) -> Self {
+        Self::new(path.into(), Mode::Record, Tape::default())
+    }
+
+    /// Creates a cassette serving every interaction from the file at `path`.
+    /// Requests that weren't recorded fail with [`CassetteError::Unrecorded`].
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self, CassetteError> {
+        let path = path.into();
+        let content = fs::read(&path).map_err(|e| CassetteError::Io(path.clone(), e))?;
+        let tape =
+            serde_json::from_slice(&content).map_err(|e| CassetteError::Parse(path.clone(), e))?;
+
+        Ok(Self::new(path, Mode::Replay, tape))
+    }
+
+    fn new(path: PathBuf, mode: Mode, tape: Tape) -> Self {
+        Self {
+            mode,
+            url_matching: UrlMatching::default(),
+            redacted_headers: Vec::new(),
+            reel: Arc::new(Reel {
+                path,
+                tape: Mutex::new(tape),
+            }),
+        }
+    }
+
+    /// Configure how requests are matched against the recorded interactions. See [`UrlMatching`].
+    pub const fn with_url_matching(mut self, url_matching: UrlMatching) -> Self {
+        self.url_matching = url_matching;
+        self
+    }
+
+    /// Replaces the value of the response header `name` in the recorded interactions.
+    pub fn with_redacted_header(mut self, name: &str) -> Self {
+        self.redacted_headers.push(name.to_ascii_lowercase());
+        self
+    }
+
+    /// Returns the path of the cassette file.
+    pub fn path(&self) -> &Path {
+        &self.reel.path
+    }
+
+    /// Writes the interactions recorded so far to the file of the cassette.
+    /// Does nothing in replay mode, or if nothing was recorded since the last flush.
+    pub fn flush(&self) -> Result<(), CassetteError> {
+        self.reel.save()
+    }
+
+    fn matches(&self, interaction: &Interaction, method: &str, url: &Url) -> bool {
+        interaction.method == method
+            && match self.url_matching {
+                UrlMatching::Exact => interaction.url == url.as_str(),
+                UrlMatching::Normalized => Url::parse(&interaction.url)
+                    .is_ok_and(|recorded| normalize(&recorded) == normalize(url)),
+            }
+    }
+
+    fn replay_interaction(&self, req: &Request) -> Result<Response, CassetteError> {
+        let method = req.method().as_str();
+        let tape = self
+            .reel
+            .tape
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let interaction = tape
+            .interactions
+            .iter()
+            .find(|interaction| self.matches(interaction, method, req.url()))
+            .ok_or_else(|| CassetteError::Unrecorded {
+                method: method.to_owned(),
+                url: req.url().to_string(),
+                path: self.reel.path.clone(),
+            })?;
+        let status =
+            StatusCode::from_u16(interaction.status).map_err(|_| CassetteError::InvalidStatus {
+                status: interaction.status,
+                url: req.url().to_string(),
+                path: self.reel.path.clone(),
+            })?;
+
+        let mut response = http::Response::builder()
+            .status(status)
+            .url(req.url().clone())
+            .body(interaction.body.clone())
+            .expect("status and url are already validated");
+        for (name, value) in &interaction.headers {
+            if let (Ok(name), Ok(value)) = (
+                HeaderName::try_from(name.as_str()),
+                HeaderValue::try_from(value.as_str()),
+            ) {
+                response.headers_mut().append(name, value);
+            }
+        }
+
+        Ok(Response::from(response))
+    }
+
+    async fn record_interaction(
+        &self,
+        method: String,
+        url: String,
+        response: Response,
+    ) -> reqwest_middleware::Result<Response> {
+        let response_url = response.url().clone();
+        let status = response.status();
+        let version = response.version();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+
+        let interaction = Interaction {
+            method,
+            url,
+            status: status.as_u16(),
+            headers: headers
+                .iter()
+                .map(|(name, value)| {
+                    let value = if self.redacted_headers.iter().any(|h| h == name.as_str()) {
+                        REDACTED.to_owned()
+                    } else {
+                        String::from_utf8_lossy(value.as_bytes()).into_owned()
+                    };
+                    (name.to_string(), value)
+                })
+                .collect(),
+            body: String::from_utf8_lossy(&body).into_owned(),
+        };
+        let mut tape = self
+            .reel
+            .tape
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        tape.interactions.push(interaction);
+        tape.unsaved = true;
+        drop(tape);
+
+        let mut replayed = http::Response::builder()
+            .status(status)
+            .version(version)
+            .url(response_url)
+            .body(body)
+            .expect("status and url are already validated");
+        *replayed.headers_mut() = headers;
+
+        Ok(Response::from(replayed))
+    }
+}
+
+/// Returns the path, without trailing slash, and the sorted query parameters of `url`.
+fn normalize(url: &Url) -> String {
+    let mut query: Vec<_> = url.query_pairs().into_owned().collect();
+    query.sort();
+    let query = query
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join("&");
+
+    format!("{}?{query}", url.path().trim_end_matches('/'))
+}
+
+#[async_trait::async_trait]
+impl Middleware for Cassette {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        match self.mode {
+            Mode::Replay => self
+                .replay_interaction(&req)
+                .map_err(reqwest_middleware::Error::middleware),
+            Mode::Record => {
+                let method = req.method().to_string();
+                let url = req.url().to_string();
+                let response = next.run(req, extensions).await?;
+
+                self.record_interaction(method, url, response).await
+            }
+        }
+    }
+}
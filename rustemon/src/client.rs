@@ -1,11 +1,25 @@
 //! Defines the client used to access Pokeapi.
 
+use std::{
+    mem,
+    sync::{Mutex, PoisonError},
+    time::Duration,
+};
+
 use http_cache_reqwest::{Cache, CacheManager, HttpCache, HttpCacheOptions};
 use reqwest::{Client, IntoUrl, Url};
 use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
 use serde::de::DeserializeOwned;
 
-use crate::error::Error;
+#[cfg(feature = "cassette")]
+use crate::cassette::Cassette;
+#[cfg(feature = "fault-injection")]
+use crate::fault::FaultInjector;
+use crate::{
+    decode::{self, DecodeWarning, Strictness},
+    error::Error,
+    rate_limit::RateLimiter,
+};
 
 // Reexport to ease overloading.
 pub use http_cache_reqwest::{CacheMode, CacheOptions};
@@ -46,6 +60,12 @@ impl TryFrom<Environment> for Url {
 pub struct RustemonClientBuilder<T: CacheManager> {
     cache: HttpCache<T>,
     environment: Environment,
+    strictness: Strictness,
+    rate_limiter: Option<RateLimiter>,
+    #[cfg(feature = "cassette")]
+    cassette: Option<Cassette>,
+    #[cfg(feature = "fault-injection")]
+    fault_injector: Option<FaultInjector>,
 }
 
 impl Default for RustemonClientBuilder<CACacheManager> {
@@ -58,6 +78,12 @@ impl Default for RustemonClientBuilder<CACacheManager> {
                 options: HttpCacheOptions::default(),
             },
             environment: Environment::default(),
+            strictness: Strictness::default(),
+            rate_limiter: None,
+            #[cfg(feature = "cassette")]
+            cassette: None,
+            #[cfg(feature = "fault-injection")]
+            fault_injector: None,
         }
     }
 }
@@ -71,6 +97,12 @@ impl Default for RustemonClientBuilder<MokaManager> {
                 options: HttpCacheOptions::default(),
             },
             environment: Environment::default(),
+            strictness: Strictness::default(),
+            rate_limiter: None,
+            #[cfg(feature = "cassette")]
+            cassette: None,
+            #[cfg(feature = "fault-injection")]
+            fault_injector: None,
         }
     }
 }
@@ -100,13 +132,59 @@ impl<T: CacheManager> RustemonClientBuilder<T> {
         self
     }
 
+    /// Configure how strictly the payloads must match the models. See [`Strictness`].
+    pub const fn with_strictness(mut self, strictness: Strictness) -> Self {
+        self.strictness = strictness;
+        self
+    }
+
+    /// Limits the calls reaching `PokeApi` to `max_requests` per `period`.
+    /// Responses served from the cache are not limited.
+    pub fn with_rate_limit(mut self, max_requests: u32, period: Duration) -> Self {
+        self.rate_limiter = Some(RateLimiter::new(max_requests, period));
+        self
+    }
+
+    /// Records or replays the calls made by the client through the given [`Cassette`].
+    /// The cassette sits behind the cache, so the calls answered by the cache aren't recorded twice.
+    #[cfg(feature = "cassette")]
+    pub fn with_cassette(mut self, cassette: Cassette) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
+    /// Injects faults in the calls made by the client through the given [`FaultInjector`].
+    /// Faults are injected before the cache, which never stores the broken responses.
+    #[cfg(feature = "fault-injection")]
+    pub fn with_fault_injector(mut self, fault_injector: FaultInjector) -> Self {
+        self.fault_injector = Some(fault_injector);
+        self
+    }
+
     /// Consumes the builder in order to create a [`RustemonClient`].
     pub fn try_build(self) -> Result<RustemonClient, Error> {
+        let base = Url::try_from(self.environment)?;
+
+        let mut client_builder = ClientBuilder::new(Client::new());
+        #[cfg(feature = "fault-injection")]
+        if let Some(mut fault_injector) = self.fault_injector {
+            fault_injector.bind(base.clone());
+            client_builder = client_builder.with(fault_injector);
+        }
+        client_builder = client_builder.with(Cache(self.cache));
+        #[cfg(feature = "cassette")]
+        if let Some(cassette) = self.cassette {
+            client_builder = client_builder.with(cassette);
+        }
+        if let Some(rate_limiter) = self.rate_limiter {
+            client_builder = client_builder.with(rate_limiter);
+        }
+
         Ok(RustemonClient {
-            client: ClientBuilder::new(Client::new())
-                .with(Cache(self.cache))
-                .build(),
-            base: Url::try_from(self.environment)?,
+            client: client_builder.build(),
+            base,
+            strictness: self.strictness,
+            warnings: Mutex::default(),
         })
     }
 }
@@ -116,6 +194,8 @@ impl<T: CacheManager> RustemonClientBuilder<T> {
 pub struct RustemonClient {
     client: ClientWithMiddleware,
     base: Url,
+    strictness: Strictness,
+    warnings: Mutex<Vec<DecodeWarning>>,
 }
 
 /// Inner representation of an endpoint's id. Used to ease the api calls.
@@ -125,12 +205,44 @@ pub(crate) enum Id<'a> {
 }
 
 impl RustemonClient {
+    /// Returns the drifts found between the payloads and the models since the last call,
+    /// in [`Strictness::Lenient`] mode.
+    pub fn take_warnings(&self) -> Vec<DecodeWarning> {
+        let mut warnings = self.warnings.lock().unwrap_or_else(PoisonError::into_inner);
+        mem::take(&mut *warnings)
+    }
+
     /// Calls the api through the given [Url].
+    ///
+    /// Responses with an error status are returned as [`Error::Reqwest`], carrying the status. This is a breaking
+    /// change for all the getters, which used to fail decoding the error page instead.
     async fn inner_get<T>(&self, url: Url) -> Result<T, Error>
     where
         T: DeserializeOwned,
     {
-        Ok(self.client.get(url).send().await?.json().await?)
+        let response = self.client.get(url).send().await?.error_for_status()?;
+        if self.strictness == Strictness::Default {
+            #[cfg(feature = "simd-json")]
+            {
+                let url = response.url().to_string();
+                return decode::decode_simd(response.bytes().await?.into(), &url);
+            }
+            #[cfg(not(feature = "simd-json"))]
+            return Ok(response.json().await?);
+        }
+
+        let url = response.url().to_string();
+        let body = response.bytes().await?;
+        let mut warnings = Vec::new();
+        let decoded = decode::decode(&body, &url, self.strictness, &mut warnings)?;
+        if !warnings.is_empty() {
+            self.warnings
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .append(&mut warnings);
+        }
+
+        Ok(decoded)
     }
 
     /// Make a call through the client to the given `endpoint`.
@@ -172,15 +284,52 @@ impl RustemonClient {
     where
         T: DeserializeOwned,
     {
+        let url = self.resource_url(endpoint, id)?;
+        self.inner_get(url).await
+    }
+
+    /// Make a call though the client to the given `endpoint`, targetting a specific resource described by [Id], and
+    /// only decoding the fields of the summary `T`. The other fields being left out on purpose, the payload is decoded
+    /// regardless of the [`Strictness`] of the client, and always with `serde_json` reading the body, the lazy fields
+    /// holding the raw JSON as found in the body.
+    pub(crate) async fn get_summary_by_endpoint_and_id<T>(
+        &self,
+        endpoint: &str,
+        id: Id<'_>,
+    ) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let url = self.resource_url(endpoint, id)?;
+        let response = self.client.get(url).send().await?.error_for_status()?;
+        Ok(response.json().await?)
+    }
+
+    /// Returns the [Url] of the resource of the given `endpoint` described by [Id].
+    fn resource_url(&self, endpoint: &str, id: Id<'_>) -> Result<Url, Error> {
         let endpoint_id = match id {
             Id::Int(i) => format!("{endpoint}/{i}"),
             Id::Str(s) => format!("{endpoint}/{s}"),
         };
+        self.base
+            .join(&endpoint_id)
+            .map_err(|_| Error::UrlParse(format!("{}/{endpoint_id}", self.base)))
+    }
+
+    /// Calls the api at the given `path`, relative to the base of the environment, e.g. `pokemon/6` or
+    /// `berry?limit=20&offset=0`, and returns the payload as is.
+    ///
+    /// No model is involved, so the fields unknown to `rustemon` are kept: this is meant to forward payloads
+    /// untouched, through a proxy for example. Responses with an error status are returned as [`Error::Reqwest`],
+    /// carrying the status.
+    pub async fn get_raw(&self, path: &str) -> Result<String, Error> {
         let url = self
             .base
-            .join(&endpoint_id)
-            .map_err(|_| Error::UrlParse(format!("{}/{endpoint_id}", self.base)))?;
-        self.inner_get(url).await
+            .join(path)
+            .map_err(|_| Error::UrlParse(format!("{}{path}", self.base)))?;
+        let response = self.client.get(url).send().await?.error_for_status()?;
+
+        Ok(response.text().await?)
     }
 
     /// Make a call through the client from a given [`IntoUrl`].
@@ -206,6 +355,8 @@ impl Default for RustemonClient {
                 }))
                 .build(),
             base: Url::try_from(Environment::default()).unwrap(),
+            strictness: Strictness::default(),
+            warnings: Mutex::default(),
         }
     }
 }
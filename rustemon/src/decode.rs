@@ -0,0 +1,613 @@
+//! Decoding of the payloads of `PokeApi`, detecting the drift between the payloads and the models.
+//!
+//! By default, the fields of the payloads unknown to the models are silently ignored, and a missing or `null`
+//! required field fails the call. The [`Strictness`] of a client, configured through
+//! [`RustemonClientBuilder::with_strictness`](crate::client::RustemonClientBuilder::with_strictness), changes this
+//! behaviour, either to fail on unknown fields or to recover from missing ones, reporting which model needs updating.
+
+use std::{any, cell::RefCell, collections::HashSet, fmt};
+
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor,
+    value::BorrowedStrDeserializer,
+};
+use serde_json::{Map, Value};
+use serde_path_to_error::{Path, Segment};
+
+use crate::error::Error;
+
+/// Maximum number of fields repaired in a single payload once decoded, in [`Strictness::Lenient`] mode.
+const MAX_REPAIRS: usize = 1024;
+
+/// `null`, standing for the missing values when the default value of their type is unknown.
+static NULL: Value = Value::Null;
+
+/// How strictly the payloads must match the models.
+///
+/// With the `preserve-unknown` feature, the unknown fields are kept by the models rather than reported,
+/// see [`unknown_keys`](crate::model::extra::unknown_keys).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Strictness {
+    /// Unknown fields are ignored, missing or `null` required fields fail with [`Error::Decode`].
+    #[default]
+    Default,
+    /// Unknown fields fail with [`Error::UnknownFields`], listing all of them.
+    Strict,
+    /// Missing or `null` required fields fall back to their default value. Those fields, as well as unknown ones,
+    /// are reported as [`DecodeWarning`]s, available through
+    /// [`RustemonClient::take_warnings`](crate::client::RustemonClient::take_warnings).
+    ///
+    /// The default values are given while decoding, in a single pass. The models buffering their fields, i.e. all of
+    /// them with the `preserve-unknown` feature, are repaired one field at a time instead, the payload being decoded
+    /// again after each repair.
+    Lenient,
+}
+
+/// Kind of drift found between a payload and its model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriftKind {
+    /// The field isn't known to the model, and was ignored.
+    Unknown,
+    /// The field is required by the model but was missing, and fell back to its default value.
+    Missing,
+    /// The field is required by the model but was `null`, and fell back to its default value.
+    Null,
+}
+
+/// Drift found between a payload and its model, in [`Strictness::Lenient`] mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeWarning {
+    /// The model the payload was decoded into, e.g. `rustemon::model::pokemon::Pokemon`.
+    pub model: &'static str,
+    /// The URL the payload was retrieved from.
+    pub url: String,
+    /// The path of the field within the payload, e.g. `chain.evolves_to[0].species`.
+    pub path: String,
+    /// The kind of drift.
+    pub kind: DriftKind,
+}
+
+impl fmt::Display for DecodeWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let drift = match self.kind {
+            DriftKind::Unknown => "unknown field",
+            DriftKind::Missing => "missing field",
+            DriftKind::Null => "null field",
+        };
+        write!(
+            f,
+            "{drift} `{}` of `{}` in `{}`",
+            self.path, self.model, self.url
+        )
+    }
+}
+
+/// Decodes `body`, retrieved from `url`, as a `T` with the given strictness.
+pub(crate) fn decode<T: DeserializeOwned>(
+    body: &[u8],
+    url: &str,
+    strictness: Strictness,
+    warnings: &mut Vec<DecodeWarning>,
+) -> Result<T, Error> {
+    let model = any::type_name::<T>();
+    let decode_error = |path: String, source| Error::Decode {
+        model,
+        url: url.to_owned(),
+        path,
+        source,
+    };
+
+    let mut value: Value =
+        serde_json::from_slice(body).map_err(|e| decode_error(String::new(), e))?;
+    let mut repaired = HashSet::new();
+    let mut repairs = Vec::new();
+    let mut repair_count = 0;
+
+    loop {
+        let mut unknown = Vec::new();
+        let drifts = RefCell::new(Vec::new());
+        let mut on_ignored = |path: serde_ignored::Path<'_>| unknown.push(ignored_path(&path));
+        let result = if strictness == Strictness::Lenient {
+            let lenient = Lenient::root(&value, &drifts);
+            serde_path_to_error::deserialize::<_, T>(serde_ignored::Deserializer::new(
+                lenient,
+                &mut on_ignored,
+            ))
+        } else {
+            serde_path_to_error::deserialize::<_, T>(serde_ignored::Deserializer::new(
+                &value,
+                &mut on_ignored,
+            ))
+        };
+
+        let e = match result {
+            Ok(decoded) if strictness == Strictness::Default => return Ok(decoded),
+            Ok(decoded) if strictness == Strictness::Strict && unknown.is_empty() => {
+                return Ok(decoded);
+            }
+            Ok(_) if strictness == Strictness::Strict => {
+                return Err(Error::UnknownFields {
+                    model,
+                    url: url.to_owned(),
+                    fields: unknown,
+                });
+            }
+            Ok(decoded) => {
+                let unknown = unknown.into_iter().map(|path| (path, DriftKind::Unknown));
+                // The repaired fields are `null` once decoded again, and must not be reported twice.
+                let drifts = drifts
+                    .into_inner()
+                    .into_iter()
+                    .filter(|(path, _)| !is_repaired(path, &repaired));
+                let drifts = repairs.into_iter().chain(drifts).chain(unknown);
+                warnings.extend(drifts.map(|(path, kind)| DecodeWarning {
+                    model,
+                    url: url.to_owned(),
+                    path,
+                    kind,
+                }));
+                return Ok(decoded);
+            }
+            Err(e) => e,
+        };
+
+        let path = e.path().clone();
+        let source = e.into_inner();
+        let repair = (strictness == Strictness::Lenient && repair_count < MAX_REPAIRS)
+            .then(|| repair(&mut value, &path, &source.to_string(), &mut repaired))
+            .flatten();
+        match repair {
+            Some(drift) => {
+                repair_count += 1;
+                repairs.extend(drift);
+            }
+            None => return Err(decode_error(path.to_string(), source)),
+        }
+    }
+}
+
+/// Decodes `body`, retrieved from `url`, as a `T` with simd-json, in [`Strictness::Default`] mode.
+#[cfg(feature = "simd-json")]
+pub(crate) fn decode_simd<T: DeserializeOwned>(mut body: Vec<u8>, url: &str) -> Result<T, Error> {
+    simd_json::serde::from_slice(&mut body).map_err(|source| Error::SimdDecode {
+        model: any::type_name::<T>(),
+        url: url.to_owned(),
+        source: Box::new(source),
+    })
+}
+
+/// Path of a value within a payload, e.g. `chain.evolves_to[0].species`, linked to the path of its parent so that
+/// nothing is allocated unless a drift is reported.
+#[derive(Clone, Copy)]
+enum FieldPath<'p> {
+    Root,
+    Key(&'p FieldPath<'p>, &'p str),
+    Index(&'p FieldPath<'p>, usize),
+}
+
+impl fmt::Display for FieldPath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Root => Ok(()),
+            Self::Key(FieldPath::Root, key) => f.write_str(key),
+            Self::Key(parent, key) => write!(f, "{parent}.{key}"),
+            Self::Index(parent, index) => write!(f, "{parent}[{index}]"),
+        }
+    }
+}
+
+/// Drifts found while decoding a payload, along with their path.
+type Drifts = RefCell<Vec<(String, DriftKind)>>;
+
+/// Deserializer of a payload giving their default value to the missing and `null` fields, according to the type the
+/// model expects, and reporting them.
+struct Lenient<'de, 'p> {
+    /// The value to decode, `None` if missing.
+    value: Option<&'de Value>,
+    path: FieldPath<'p>,
+    drifts: &'p Drifts,
+    /// Whether the value belongs to a value already reported, e.g. the fields of a missing struct.
+    reported: bool,
+}
+
+impl<'de, 'p> Lenient<'de, 'p> {
+    fn root(value: &'de Value, drifts: &'p Drifts) -> Self {
+        Self {
+            value: Some(value),
+            path: FieldPath::Root,
+            drifts,
+            reported: false,
+        }
+    }
+
+    /// Returns the value, unless it is missing or `null`.
+    fn present(&self) -> Option<&'de Value> {
+        self.value.filter(|value| !value.is_null())
+    }
+
+    /// Reports the value as falling back to its default value.
+    fn report(&self) {
+        if !self.reported {
+            let kind = match self.value {
+                None => DriftKind::Missing,
+                Some(_) => DriftKind::Null,
+            };
+            self.drifts.borrow_mut().push((self.path.to_string(), kind));
+        }
+    }
+
+    /// Decodes the value as is, `null` if missing, failing if the model can't handle it.
+    fn strict(&self) -> &'de Value {
+        self.value.unwrap_or(&NULL)
+    }
+}
+
+/// Implements the methods of [`Deserializer`] either decoding the value as is, or giving its default value through
+/// the visitor.
+macro_rules! lenient {
+    ($($method:ident($visitor:ident) => $default:expr;)+) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, $visitor: V) -> Result<V::Value, Self::Error> {
+                match self.present() {
+                    Some(value) => value.$method($visitor),
+                    None => {
+                        self.report();
+                        $default
+                    }
+                }
+            }
+        )+
+    };
+}
+
+/// Implements the methods of [`Deserializer`] decoding the value as is.
+macro_rules! strict {
+    ($($method:ident),+ $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                self.strict().$method(visitor)
+            }
+        )+
+    };
+}
+
+impl<'de> Deserializer<'de> for Lenient<'de, '_> {
+    type Error = serde_json::Error;
+
+    lenient! {
+        deserialize_bool(visitor) => visitor.visit_bool(false);
+        deserialize_i8(visitor) => visitor.visit_i64(0);
+        deserialize_i16(visitor) => visitor.visit_i64(0);
+        deserialize_i32(visitor) => visitor.visit_i64(0);
+        deserialize_i64(visitor) => visitor.visit_i64(0);
+        deserialize_u8(visitor) => visitor.visit_u64(0);
+        deserialize_u16(visitor) => visitor.visit_u64(0);
+        deserialize_u32(visitor) => visitor.visit_u64(0);
+        deserialize_u64(visitor) => visitor.visit_u64(0);
+        deserialize_f32(visitor) => visitor.visit_f64(0.0);
+        deserialize_f64(visitor) => visitor.visit_f64(0.0);
+        deserialize_str(visitor) => visitor.visit_borrowed_str("");
+        deserialize_string(visitor) => visitor.visit_borrowed_str("");
+        deserialize_bytes(visitor) => visitor.visit_borrowed_bytes(&[]);
+        deserialize_byte_buf(visitor) => visitor.visit_borrowed_bytes(&[]);
+    }
+
+    strict!(deserialize_char, deserialize_identifier);
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.present() {
+            Some(Value::Object(map)) => visitor.visit_map(LenientMap::new(Some(map), &[], &self)),
+            Some(Value::Array(values)) => visitor.visit_seq(LenientSeq::new(values, &self)),
+            Some(value) => value.deserialize_any(visitor),
+            None => self.strict().deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.present() {
+            Some(_) => visitor.visit_some(self),
+            None => visitor.visit_none(),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.present() {
+            Some(value) => value.deserialize_unit(visitor),
+            None => visitor.visit_unit(),
+        }
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.present() {
+            Some(Value::Array(values)) => visitor.visit_seq(LenientSeq::new(values, &self)),
+            Some(value) => value.deserialize_seq(visitor),
+            None => {
+                self.report();
+                visitor.visit_seq(LenientSeq::new(&[], &self))
+            }
+        }
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    /// Also used by the models buffering their fields, whose fields are unknown: a missing or `null` map fails so
+    /// that the error tells which model is expected, see [`repair`].
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.present() {
+            Some(Value::Object(map)) => visitor.visit_map(LenientMap::new(Some(map), &[], &self)),
+            _ => self.strict().deserialize_map(visitor),
+        }
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.present() {
+            Some(Value::Object(map)) => {
+                visitor.visit_map(LenientMap::new(Some(map), fields, &self))
+            }
+            Some(value) => value.deserialize_map(visitor),
+            None => {
+                self.report();
+                let reported = Lenient {
+                    reported: true,
+                    ..self
+                };
+                visitor.visit_map(LenientMap::new(None, fields, &reported))
+            }
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.strict().deserialize_enum(name, variants, visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+}
+
+/// Fields of an object, followed by the fields expected by the model but missing from the object.
+struct LenientMap<'de, 'p> {
+    map: Option<&'de Map<String, Value>>,
+    entries: Option<serde_json::map::Iter<'de>>,
+    fields: std::slice::Iter<'static, &'static str>,
+    next: Option<(&'de str, Option<&'de Value>)>,
+    path: FieldPath<'p>,
+    drifts: &'p Drifts,
+    reported: bool,
+}
+
+impl<'de, 'p> LenientMap<'de, 'p> {
+    fn new(
+        map: Option<&'de Map<String, Value>>,
+        fields: &'static [&'static str],
+        parent: &Lenient<'de, 'p>,
+    ) -> Self {
+        Self {
+            map,
+            entries: map.map(Map::iter),
+            fields: fields.iter(),
+            next: None,
+            path: parent.path,
+            drifts: parent.drifts,
+            reported: parent.reported,
+        }
+    }
+}
+
+impl<'de> MapAccess<'de> for LenientMap<'de, '_> {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        let map = self.map;
+        self.next = match self.entries.as_mut().and_then(Iterator::next) {
+            Some((key, value)) => Some((key.as_str(), Some(value))),
+            None => self
+                .fields
+                .find(|field| map.is_none_or(|map| !map.contains_key(**field)))
+                .map(|field| (*field, None)),
+        };
+
+        match self.next {
+            Some((key, _)) => seed
+                .deserialize(BorrowedStrDeserializer::new(key))
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let (key, value) = self
+            .next
+            .take()
+            .ok_or_else(|| de::Error::custom("value requested before its key"))?;
+
+        seed.deserialize(Lenient {
+            value,
+            path: FieldPath::Key(&self.path, key),
+            drifts: self.drifts,
+            reported: self.reported,
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.entries.as_ref().map(ExactSizeIterator::len)
+    }
+}
+
+/// Values of an array.
+struct LenientSeq<'de, 'p> {
+    values: std::iter::Enumerate<std::slice::Iter<'de, Value>>,
+    path: FieldPath<'p>,
+    drifts: &'p Drifts,
+    reported: bool,
+}
+
+impl<'de, 'p> LenientSeq<'de, 'p> {
+    fn new(values: &'de [Value], parent: &Lenient<'de, 'p>) -> Self {
+        Self {
+            values: values.iter().enumerate(),
+            path: parent.path,
+            drifts: parent.drifts,
+            reported: parent.reported,
+        }
+    }
+}
+
+impl<'de> SeqAccess<'de> for LenientSeq<'de, '_> {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        let Some((index, value)) = self.values.next() else {
+            return Ok(None);
+        };
+
+        seed.deserialize(Lenient {
+            value: Some(value),
+            path: FieldPath::Index(&self.path, index),
+            drifts: self.drifts,
+            reported: self.reported,
+        })
+        .map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.values.len())
+    }
+}
+
+/// Repairs the field of `value` at `path` described by the error `message`. Returns `None` when the error can't be
+/// repaired, otherwise the path of the field and the kind of drift to report, if not already reported.
+///
+/// A missing field is first inserted as `null`, its type being unknown, then given the default value of the type
+/// expected once deserialized again. The fields repaired are remembered in `repaired`, so that neither them nor the
+/// fields they hold are reported twice.
+fn repair(
+    value: &mut Value,
+    path: &Path,
+    message: &str,
+    repaired: &mut HashSet<String>,
+) -> Option<Option<(String, DriftKind)>> {
+    let target = navigate(value, path)?;
+
+    let (field_path, kind) = if let Some(field) = message
+        .strip_prefix("missing field `")
+        .and_then(|rest| rest.strip_suffix('`'))
+    {
+        target
+            .as_object_mut()?
+            .insert(field.to_owned(), Value::Null);
+        let field_path = if path.iter().next().is_none() {
+            field.to_owned()
+        } else {
+            format!("{path}.{field}")
+        };
+        (field_path, DriftKind::Missing)
+    } else {
+        let expected = message.strip_prefix("invalid type: null, expected ")?;
+        if !target.is_null() {
+            return None;
+        }
+        *target = default_value(expected)?;
+        (path.to_string(), DriftKind::Null)
+    };
+
+    let already_reported = is_repaired(&field_path, repaired);
+    repaired.insert(field_path.clone());
+
+    Some((!already_reported).then_some((field_path, kind)))
+}
+
+/// Whether the field at `path` is one of the `repaired` fields, or is held by one of them.
+fn is_repaired(path: &str, repaired: &HashSet<String>) -> bool {
+    repaired.iter().any(|repaired| {
+        path.strip_prefix(repaired.as_str())
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with(['.', '[']))
+    })
+}
+
+/// Formats the path of an ignored field like the paths of [`serde_path_to_error`], e.g. `chain.evolves_to[0].species`.
+fn ignored_path(path: &serde_ignored::Path<'_>) -> String {
+    match path {
+        serde_ignored::Path::Root => String::new(),
+        serde_ignored::Path::Seq { parent, index } => format!("{}[{index}]", ignored_path(parent)),
+        serde_ignored::Path::Map { parent, key } => match ignored_path(parent) {
+            parent if parent.is_empty() => key.clone(),
+            parent => format!("{parent}.{key}"),
+        },
+        serde_ignored::Path::Some { parent }
+        | serde_ignored::Path::NewtypeStruct { parent }
+        | serde_ignored::Path::NewtypeVariant { parent } => ignored_path(parent),
+    }
+}
+
+/// Returns the value at `path` within `value`.
+fn navigate<'a>(value: &'a mut Value, path: &Path) -> Option<&'a mut Value> {
+    path.iter().try_fold(value, |value, segment| match segment {
+        Segment::Seq { index } => value.get_mut(*index),
+        Segment::Map { key } => value.get_mut(key.as_str()),
+        Segment::Enum { .. } | Segment::Unknown => None,
+    })
+}
+
+/// Returns the default value of the type described by `expected`, as found in the messages of serde.
+fn default_value(expected: &str) -> Option<Value> {
+    match expected {
+        "a string" => Some(Value::String(String::new())),
+        "a boolean" => Some(Value::Bool(false)),
+        "a sequence" => Some(Value::Array(Vec::new())),
+        "a map" => Some(Value::Object(Default::default())),
+        "i64" | "u64" | "i32" | "u32" => Some(Value::from(0)),
+        _ if expected.starts_with("struct ") => Some(Value::Object(Default::default())),
+        _ => None,
+    }
+}
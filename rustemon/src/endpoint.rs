@@ -19,6 +19,11 @@ macro_rules! endpoint {
         use crate::client::{RustemonClient, Id};
         use crate::error::Error;
 
+        impl crate::model::resource::Endpoint for $type {
+            const NAME: &'static str = $name;
+            type List = ResourceList<$type>;
+        }
+
         /// Returns the default page regarding the resource.
         ///
         /// # Arguments
@@ -115,7 +120,7 @@ macro_rules! endpoint {
                 ///
                 /// `name` - The name of the resource to get.
                 /// `rustemon_client` - The [RustemonClient] to use to access the resource.
-                pub async fn get_by_name(name: &'static str, rustemon_client: &RustemonClient) -> Result<$sub_type, Error> {
+                pub async fn get_by_name(name: &str, rustemon_client: &RustemonClient) -> Result<$sub_type, Error> {
                     let sub_path = format!("{}/{}/{}", $name, name, SUB_STR);
                     rustemon_client.get_by_endpoint::<$sub_type>(&sub_path).await
                 }
@@ -125,3 +130,34 @@ macro_rules! endpoint {
 }
 
 pub(crate) use endpoint;
+
+/// Generates the getters of a summary model, decoded from the payloads of the endpoint declared by [`endpoint`].
+macro_rules! summary {
+    ($summary:ty; for $name:literal) => {
+        /// Returns the summary of the resource, using its id.
+        /// Only the fields of the summary are decoded from the payload, regardless of the strictness of the client:
+        /// the unknown fields are never rejected, and the drifts are never reported as warnings.
+        ///
+        /// # Arguments
+        ///
+        /// `id` - The unique ID of the resource to get.
+        /// `rustemon_client` - The [RustemonClient] to use to access the resource.
+        pub async fn get_summary_by_id(id: i64, rustemon_client: &RustemonClient) -> Result<$summary, Error> {
+            rustemon_client.get_summary_by_endpoint_and_id::<$summary>($name, Id::Int(id)).await
+        }
+
+        /// Returns the summary of the resource, using its name.
+        /// Only the fields of the summary are decoded from the payload, regardless of the strictness of the client:
+        /// the unknown fields are never rejected, and the drifts are never reported as warnings.
+        ///
+        /// # Arguments
+        ///
+        /// `name` - The name of the resource to get.
+        /// `rustemon_client` - The [RustemonClient] to use to access the resource.
+        pub async fn get_summary_by_name(name: &str, rustemon_client: &RustemonClient) -> Result<$summary, Error> {
+            rustemon_client.get_summary_by_endpoint_and_id::<$summary>($name, Id::Str(name)).await
+        }
+    };
+}
+
+pub(crate) use summary;
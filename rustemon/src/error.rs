@@ -5,7 +5,7 @@ use thiserror::Error;
 /// Custom error from the project.
 #[derive(Debug, Error)]
 pub enum Error {
-    /// Error coming from reqwest calls.
+    /// Error coming from reqwest calls, including the responses with an error status.
     #[error(transparent)]
     Reqwest(#[from] reqwest::Error),
     /// Error coming from `reqwest_middleware`.
@@ -21,4 +21,79 @@ pub enum Error {
     /// Error coming from trying to follow an instance with no URL.
     #[error("error while following empty url")]
     FollowEmptyURL,
+    /// Error raised when a payload doesn't match its model.
+    #[error("couldn't decode `{model}` from `{url}` at `{path}`")]
+    Decode {
+        /// The model the payload was decoded into.
+        model: &'static str,
+        /// The URL the payload was retrieved from.
+        url: String,
+        /// The path of the faulty field within the payload.
+        path: String,
+        /// The underlying error.
+        #[source]
+        source: serde_json::Error,
+    },
+    /// Error raised in [`Strictness::Strict`](crate::decode::Strictness::Strict) mode, when a payload holds fields
+    /// unknown to its model.
+    #[error("`{model}` doesn't know the fields {fields:?} found in `{url}`")]
+    UnknownFields {
+        /// The model the payload was decoded into.
+        model: &'static str,
+        /// The URL the payload was retrieved from.
+        url: String,
+        /// The paths of the unknown fields within the payload.
+        fields: Vec<String>,
+    },
+    /// Error raised when a team of Pokémon is built with more members than allowed.
+    #[error("a team holds at most {max} Pokémon, got {size}")]
+    TeamTooLarge {
+        /// The number of members given.
+        size: usize,
+        /// The maximum number of members.
+        max: usize,
+    },
+    /// Error raised when a level, IV, EV or other value given to a computation is out of its range.
+    #[error("{what} `{value}` is out of range `{min}..={max}`")]
+    OutOfRange {
+        /// What the value is, e.g. `attack IV`.
+        what: String,
+        /// The value given.
+        value: i64,
+        /// The lowest value allowed.
+        min: i64,
+        /// The highest value allowed.
+        max: i64,
+    },
+    /// Error raised when the EVs of a Pokémon add up to more than allowed.
+    #[error("EVs add up to {total}, more than {max}")]
+    EffortTotalExceeded {
+        /// The total of the EVs given.
+        total: i64,
+        /// The highest total allowed.
+        max: i64,
+    },
+    /// Error raised when a growth rate formula can't be parsed.
+    #[error("couldn't parse the formula `{formula}` at {position}: {reason}")]
+    FormulaParse {
+        /// The LaTeX formula.
+        formula: String,
+        /// The byte position of the faulty input within the formula.
+        position: usize,
+        /// What was wrong.
+        reason: String,
+    },
+    /// Error raised with the `simd-json` feature, when a payload doesn't match its model.
+    ///
+    /// The variant exists regardless of the feature, so that matching on [`Error`] doesn't depend on it.
+    #[error("couldn't decode `{model}` from `{url}`")]
+    SimdDecode {
+        /// The model the payload was decoded into.
+        model: &'static str,
+        /// The URL the payload was retrieved from.
+        url: String,
+        /// The underlying error, a `simd_json::Error`.
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
 }
@@ -0,0 +1,255 @@
+//! Injection of faults in the calls made to `PokeApi`, for resilience testing.
+//!
+//! A [`FaultInjector`] attached to a [`RustemonClientBuilder`](crate::client::RustemonClientBuilder) through
+//! `with_fault_injector` makes the client experience timeouts, error statuses, broken payloads or slow responses,
+//! either randomly or following scripted sequences, so that the handling of every [`Error`](crate::error::Error)
+//! can be exercised without a flaky network.
+//!
+//! | Fault                    | Surfaced as                                                                    |
+//! |--------------------------|--------------------------------------------------------------------------------|
+//! | [`Fault::Timeout`]       | `Error::ReqwestMiddleware` wrapping a [`FaultError::Timeout`]                  |
+//! | [`Fault::Status`]        | `Error::Reqwest`, carrying the status                                          |
+//! | [`Fault::TruncatedBody`] | `Error::Decode` in `Strict` and `Lenient` modes, failing to decode the payload |
+//! | [`Fault::MalformedJson`] | `Error::Decode` in `Strict` and `Lenient` modes, failing to decode the payload |
+//! | [`Fault::Slow`]          | The regular response, once the delay elapsed                                   |
+//!
+//! In the [`Strictness::Default`](crate::decode::Strictness::Default) mode, the broken payloads surface as
+//! `Error::Reqwest` instead, or as `Error::SimdDecode` with the `simd-json` feature.
+
+use std::{
+    collections::VecDeque,
+    sync::{Mutex, PoisonError},
+    time::Duration,
+};
+
+use http::{Extensions, StatusCode};
+use reqwest::{Request, Response, ResponseBuilderExt, Url};
+use reqwest_middleware::{Middleware, Next};
+use thiserror::Error;
+
+/// Payload served by [`Fault::MalformedJson`].
+const MALFORMED_JSON: &str = r#"{"id": 1, "name": "#;
+
+/// Errors raised by the [`FaultInjector`].
+#[derive(Debug, Error)]
+pub enum FaultError {
+    /// Error raised by [`Fault::Timeout`], once the delay elapsed.
+    #[error("injected timeout after {0:?} for `{1}`")]
+    Timeout(Duration, Url),
+}
+
+/// Misbehaviour of `PokeApi` simulated by the [`FaultInjector`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fault {
+    /// Waits for the given duration, then fails without response.
+    Timeout(Duration),
+    /// Answers with the given status and an empty body, without calling `PokeApi`.
+    Status(StatusCode),
+    /// Calls `PokeApi`, then only forwards the first half of the body.
+    TruncatedBody,
+    /// Answers with a successful status and a payload that isn't valid JSON, without calling `PokeApi`.
+    MalformedJson,
+    /// Waits for the given duration, then calls `PokeApi`.
+    Slow(Duration),
+}
+
+/// Fault injected randomly in the calls to an endpoint, or to every endpoint.
+#[derive(Debug)]
+struct Rule {
+    endpoint: Option<String>,
+    fault: Fault,
+    probability: f64,
+}
+
+/// Faults injected in turn in the calls to an endpoint, or to every endpoint.
+#[derive(Debug)]
+struct Script {
+    endpoint: Option<String>,
+    faults: VecDeque<Option<Fault>>,
+}
+
+/// Middleware injecting [`Fault`]s in the calls of a client.
+///
+/// Scripted sequences take precedence over probabilities: each call consumes the next step of the first script
+/// scoped to its endpoint, or of the first unscoped script. Once the scripts are exhausted, the rules are drawn
+/// in the order they were added, the first one drawn being injected.
+///
+/// Endpoints are named as in `PokeApi`, e.g. `"pokemon"` or `"move"`.
+#[derive(Debug)]
+pub struct FaultInjector {
+    rules: Vec<Rule>,
+    scripts: Mutex<Vec<Script>>,
+    rng: Mutex<SplitMix64>,
+    base: Option<Url>,
+}
+
+impl FaultInjector {
+    /// Creates an injector without faults, drawing its probabilities from `seed`.
+    /// The same seed and calls always produce the same faults.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rules: Vec::new(),
+            scripts: Mutex::new(Vec::new()),
+            rng: Mutex::new(SplitMix64(seed)),
+            base: None,
+        }
+    }
+
+    /// Injects `fault` in the calls to every endpoint with the given `probability`, between 0 and 1.
+    pub fn with_fault(mut self, fault: Fault, probability: f64) -> Self {
+        self.rules.push(Rule {
+            endpoint: None,
+            fault,
+            probability,
+        });
+        self
+    }
+
+    /// Injects `fault` in the calls to `endpoint` with the given `probability`, between 0 and 1.
+    pub fn with_endpoint_fault(mut self, endpoint: &str, fault: Fault, probability: f64) -> Self {
+        self.rules.push(Rule {
+            endpoint: Some(endpoint.to_owned()),
+            fault,
+            probability,
+        });
+        self
+    }
+
+    /// Injects `faults` in turn in the calls to every endpoint, `None` letting the call through.
+    pub fn with_script(self, faults: impl IntoIterator<Item = Option<Fault>>) -> Self {
+        self.push_script(None, faults)
+    }
+
+    /// Injects `faults` in turn in the calls to `endpoint`, `None` letting the call through.
+    pub fn with_endpoint_script(
+        self,
+        endpoint: &str,
+        faults: impl IntoIterator<Item = Option<Fault>>,
+    ) -> Self {
+        self.push_script(Some(endpoint.to_owned()), faults)
+    }
+
+    fn push_script(
+        mut self,
+        endpoint: Option<String>,
+        faults: impl IntoIterator<Item = Option<Fault>>,
+    ) -> Self {
+        self.scripts
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(Script {
+                endpoint,
+                faults: faults.into_iter().collect(),
+            });
+        self
+    }
+
+    /// Sets the base URL the endpoints are resolved against.
+    pub(crate) fn bind(&mut self, base: Url) {
+        self.base = Some(base);
+    }
+
+    /// Returns the endpoint targeted by `url`, i.e. its first path segment below the base URL.
+    fn endpoint<'a>(&self, url: &'a Url) -> Option<&'a str> {
+        let base_path = self.base.as_ref().map_or("/", Url::path);
+        url.path()
+            .strip_prefix(base_path)?
+            .split('/')
+            .next()
+            .filter(|endpoint| !endpoint.is_empty())
+    }
+
+    /// Picks the fault to inject in the call to `endpoint`, if any.
+    fn pick(&self, endpoint: Option<&str>) -> Option<Fault> {
+        let scoped = |scope: &Option<String>| scope.is_none() || scope.as_deref() == endpoint;
+
+        let mut scripts = self.scripts.lock().unwrap_or_else(PoisonError::into_inner);
+        let script = scripts
+            .iter_mut()
+            .filter(|script| !script.faults.is_empty() && scoped(&script.endpoint))
+            .min_by_key(|script| script.endpoint.is_none());
+        if let Some(script) = script {
+            return script.faults.pop_front().flatten();
+        }
+        drop(scripts);
+
+        let mut rng = self.rng.lock().unwrap_or_else(PoisonError::into_inner);
+        self.rules
+            .iter()
+            .filter(|rule| scoped(&rule.endpoint))
+            .find(|rule| rng.next_f64() < rule.probability)
+            .map(|rule| rule.fault.clone())
+    }
+}
+
+/// Builds a response to `url` from its parts, as if it came from `PokeApi`.
+fn response(url: &Url, status: StatusCode, body: impl Into<reqwest::Body>) -> Response {
+    let response = http::Response::builder()
+        .status(status)
+        .url(url.clone())
+        .body(body.into())
+        .expect("status and url are already validated");
+
+    Response::from(response)
+}
+
+#[async_trait::async_trait]
+impl Middleware for FaultInjector {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let fault = self.pick(self.endpoint(req.url()));
+
+        match fault {
+            None => next.run(req, extensions).await,
+            Some(Fault::Timeout(delay)) => {
+                futures_timer::Delay::new(delay).await;
+                Err(reqwest_middleware::Error::middleware(FaultError::Timeout(
+                    delay,
+                    req.url().clone(),
+                )))
+            }
+            Some(Fault::Status(status)) => Ok(response(req.url(), status, "")),
+            Some(Fault::TruncatedBody) => {
+                let upstream = next.run(req, extensions).await?;
+                let url = upstream.url().clone();
+                let status = upstream.status();
+                let mut headers = upstream.headers().clone();
+                headers.remove(http::header::CONTENT_LENGTH);
+                let body = upstream.bytes().await?;
+
+                let mut truncated = response(&url, status, body.slice(..body.len() / 2));
+                *truncated.headers_mut() = headers;
+
+                Ok(truncated)
+            }
+            Some(Fault::MalformedJson) => Ok(response(req.url(), StatusCode::OK, MALFORMED_JSON)),
+            Some(Fault::Slow(delay)) => {
+                futures_timer::Delay::new(delay).await;
+                next.run(req, extensions).await
+            }
+        }
+    }
+}
+
+/// Small seedable generator, enough to draw probabilities reproducibly.
+#[derive(Debug)]
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number uniformly drawn in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
@@ -14,15 +14,26 @@ pub mod moves;
 pub mod pokemon;
 pub mod utility;
 
+#[cfg(feature = "cassette")]
+pub mod cassette;
 pub mod client;
+pub mod decode;
 pub mod error;
+#[cfg(feature = "fault-injection")]
+pub mod fault;
 
 mod endpoint;
-pub(crate) use endpoint::endpoint;
+pub(crate) use endpoint::{endpoint, summary};
+
+mod rate_limit;
 
 mod follow;
 pub use follow::Follow;
 
+pub mod mechanics;
 pub mod model;
+pub mod registry;
+#[cfg(feature = "schemars")]
+pub mod schema;
 #[cfg(feature = "static-resources")]
 pub mod static_resources;
@@ -0,0 +1,842 @@
+//! Experience of the Pokémon: the levels of their [`GrowthRate`], its LaTeX formula, and the experience gained by
+//! defeating other Pokémon.
+//!
+//! ```rust
+//! use rustemon::mechanics::experience::{ExperienceGain, Formula};
+//! # fn experience(medium_slow: rustemon::model::pokemon::GrowthRate) -> Result<(), rustemon::error::Error> {
+//!
+//! assert_eq!(medium_slow.level_for_experience(1_000), 12);
+//! assert_eq!(medium_slow.experience_to_next_level(1_000), Some(261));
+//!
+//! let erratic = Formula::parse(r"\begin{cases}
+//!     \frac{ x^3 \left( 100 - x \right) }{50}, & \text{if } x \leq 50 \\
+//!     \frac{ x^3 \left( 150 - x \right) }{100}, & \text{if } 50 < x \leq 68 \\
+//!     \frac{ x^3 \left( 1274 + (x \bmod 3)^2 - 9 (x \bmod 3) - 20 \left \lfloor \frac{x}{3} \right \rfloor \right) }{1000}, & \text{if } 68 < x \leq 98 \\
+//!     \frac{ x^3 \left( 160 - x \right) }{100}, & \text{if } x > 98 \\
+//! \end{cases}")?;
+//! assert_eq!(erratic.experience_at(100), Some(600_000));
+//!
+//! let gained = ExperienceGain::new(64, 5).with_trainer_battle(true).experience(4)?;
+//! assert_eq!(gained, 67);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt, str::FromStr};
+
+use super::stats::{MAX_LEVEL, check_range};
+use crate::{
+    error::Error,
+    model::pokemon::{GrowthRate, Pokemon},
+};
+
+impl GrowthRate {
+    /// The highest level of the growth rate, from its levels.
+    pub fn max_level(&self) -> Option<i64> {
+        self.levels.iter().map(|level| level.level).max()
+    }
+
+    /// The total experience needed to reach the given level, from the levels of the growth rate.
+    pub fn experience_at_level(&self, level: i64) -> Option<i64> {
+        self.levels
+            .iter()
+            .find(|experience_level| experience_level.level == level)
+            .map(|experience_level| experience_level.experience)
+    }
+
+    /// The level of a Pokémon having the given total experience, from the levels of the growth rate, at least 1.
+    pub fn level_for_experience(&self, experience: i64) -> i64 {
+        self.levels
+            .iter()
+            .filter(|experience_level| experience_level.experience <= experience)
+            .map(|experience_level| experience_level.level)
+            .max()
+            .unwrap_or(1)
+    }
+
+    /// The experience a Pokémon having the given total experience still needs to reach the next level, if not at the
+    /// highest level already.
+    pub fn experience_to_next_level(&self, experience: i64) -> Option<i64> {
+        self.experience_to_level(experience, self.level_for_experience(experience) + 1)
+    }
+
+    /// The experience a Pokémon having the given total experience still needs to reach the given level, if known to
+    /// the growth rate, `0` if already reached.
+    pub fn experience_to_level(&self, experience: i64, level: i64) -> Option<i64> {
+        Some((self.experience_at_level(level)? - experience).max(0))
+    }
+
+    /// Parses the formula of the growth rate.
+    pub fn parse_formula(&self) -> Result<Formula, Error> {
+        Formula::parse(&self.formula)
+    }
+}
+
+/// Growth rate formula, giving the total experience needed to reach a level `x`, parsed from the LaTeX of `PokeApi`.
+///
+/// The formulas are made of numbers, the level `x`, the `+`, `-`, `\cdot`, `\times`, `\bmod` and `^` operators, the
+/// implicit multiplication, parentheses, `\frac`, `\lfloor`/`\rfloor`, and `\begin{cases}` for the piecewise ones,
+/// whose conditions compare expressions with `<`, `>`, `\leq`, `\geq` and the like, or are `\text{otherwise}`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Formula {
+    source: String,
+    expression: Expression,
+}
+
+impl Formula {
+    /// Parses the given LaTeX formula, failing with [`Error::FormulaParse`] if it isn't supported.
+    pub fn parse(latex: &str) -> Result<Self, Error> {
+        let tokens = tokenize(latex)?;
+        let mut parser = Parser {
+            source: latex,
+            tokens: &tokens,
+            index: 0,
+        };
+        let expression = parser.formula()?;
+        if let Some(token) = parser.peek() {
+            return Err(parser.error_at(token.position, "unexpected trailing input"));
+        }
+
+        Ok(Self {
+            source: latex.to_string(),
+            expression,
+        })
+    }
+
+    /// The LaTeX the formula was parsed from.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Evaluates the formula at the given level, if one of its cases applies.
+    pub fn evaluate(&self, level: i64) -> Option<f64> {
+        self.expression.evaluate(level as f64)
+    }
+
+    /// The total experience needed to reach the given level, rounded down, if one of the cases of the formula applies.
+    ///
+    /// The formulas aren't meant for the level 1, which needs no experience: see [`GrowthRate::experience_at_level`].
+    pub fn experience_at(&self, level: i64) -> Option<i64> {
+        Some(self.evaluate(level)?.floor() as i64)
+    }
+}
+
+impl FromStr for Formula {
+    type Err = Error;
+
+    fn from_str(latex: &str) -> Result<Self, Self::Err> {
+        Self::parse(latex)
+    }
+}
+
+impl fmt::Display for Formula {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+/// Comparison of a condition of a piecewise formula.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+}
+
+impl Comparison {
+    /// Compares the given values.
+    fn holds(self, left: f64, right: f64) -> bool {
+        match self {
+            Self::Lt => left < right,
+            Self::Le => left <= right,
+            Self::Gt => left > right,
+            Self::Ge => left >= right,
+            Self::Eq => left == right,
+        }
+    }
+}
+
+/// Condition of a case of a piecewise formula, e.g. `50 < x \leq 68`, always holding without comparisons.
+#[derive(Debug, Clone, PartialEq)]
+struct Condition {
+    first: Expression,
+    comparisons: Vec<(Comparison, Expression)>,
+}
+
+impl Condition {
+    /// Whether the condition holds at the given level.
+    fn holds(&self, x: f64) -> Option<bool> {
+        let mut left = self.first.evaluate(x)?;
+        for (comparison, right) in &self.comparisons {
+            let right = right.evaluate(x)?;
+            if !comparison.holds(left, right) {
+                return Some(false);
+            }
+            left = right;
+        }
+
+        Some(true)
+    }
+}
+
+/// Expression of a formula.
+#[derive(Debug, Clone, PartialEq)]
+enum Expression {
+    Number(f64),
+    Level,
+    Negate(Box<Expression>),
+    Add(Box<Expression>, Box<Expression>),
+    Subtract(Box<Expression>, Box<Expression>),
+    Multiply(Box<Expression>, Box<Expression>),
+    Divide(Box<Expression>, Box<Expression>),
+    Modulo(Box<Expression>, Box<Expression>),
+    Power(Box<Expression>, Box<Expression>),
+    Floor(Box<Expression>),
+    Cases(Vec<(Expression, Condition)>),
+}
+
+impl Expression {
+    /// Evaluates the expression at the given level, if one of its cases applies.
+    fn evaluate(&self, x: f64) -> Option<f64> {
+        Some(match self {
+            Self::Number(number) => *number,
+            Self::Level => x,
+            Self::Negate(operand) => -operand.evaluate(x)?,
+            Self::Add(left, right) => left.evaluate(x)? + right.evaluate(x)?,
+            Self::Subtract(left, right) => left.evaluate(x)? - right.evaluate(x)?,
+            Self::Multiply(left, right) => left.evaluate(x)? * right.evaluate(x)?,
+            Self::Divide(left, right) => left.evaluate(x)? / right.evaluate(x)?,
+            Self::Modulo(left, right) => left.evaluate(x)?.rem_euclid(right.evaluate(x)?),
+            Self::Power(base, exponent) => base.evaluate(x)?.powf(exponent.evaluate(x)?),
+            Self::Floor(operand) => operand.evaluate(x)?.floor(),
+            Self::Cases(cases) => {
+                for (expression, condition) in cases {
+                    if condition.holds(x)? {
+                        return expression.evaluate(x);
+                    }
+                }
+                return None;
+            }
+        })
+    }
+}
+
+/// Kind of a token of a formula.
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    Number(f64),
+    Level,
+    Plus,
+    Minus,
+    Times,
+    Slash,
+    Modulo,
+    Caret,
+    OpenParen,
+    CloseParen,
+    OpenBrace,
+    CloseBrace,
+    OpenFloor,
+    CloseFloor,
+    Frac,
+    Compare(Comparison),
+    Comma,
+    Ampersand,
+    RowSeparator,
+    BeginCases,
+    EndCases,
+    Text(String),
+}
+
+/// Token of a formula, along with its byte position.
+#[derive(Debug, Clone, PartialEq)]
+struct Token {
+    kind: TokenKind,
+    position: usize,
+}
+
+/// Splits the given LaTeX formula into tokens, the sizing and spacing commands being skipped.
+fn tokenize(latex: &str) -> Result<Vec<Token>, Error> {
+    let error_at = |position: usize, reason: &str| Error::FormulaParse {
+        formula: latex.to_string(),
+        position,
+        reason: reason.to_string(),
+    };
+    let bytes = latex.as_bytes();
+    let mut tokens = Vec::new();
+    let mut position = 0;
+
+    while position < bytes.len() {
+        let start = position;
+        let byte = bytes[position];
+        position += 1;
+        let kind = match byte {
+            byte if byte.is_ascii_whitespace() => continue,
+            b'0'..=b'9' | b'.' => {
+                // A digit right after `^` is the whole exponent, e.g. `x^32` being `x^3 \cdot 2`.
+                let is_exponent = matches!(
+                    tokens.last(),
+                    Some(Token {
+                        kind: TokenKind::Caret,
+                        ..
+                    })
+                );
+                if !is_exponent {
+                    while position < bytes.len()
+                        && (bytes[position].is_ascii_digit() || bytes[position] == b'.')
+                    {
+                        position += 1;
+                    }
+                }
+                let number = latex[start..position]
+                    .parse()
+                    .map_err(|_| error_at(start, "invalid number"))?;
+                TokenKind::Number(number)
+            }
+            b'x' => TokenKind::Level,
+            b'+' => TokenKind::Plus,
+            b'-' => TokenKind::Minus,
+            b'*' => TokenKind::Times,
+            b'/' => TokenKind::Slash,
+            b'^' => TokenKind::Caret,
+            b'(' | b'[' => TokenKind::OpenParen,
+            b')' | b']' => TokenKind::CloseParen,
+            b'{' => TokenKind::OpenBrace,
+            b'}' => TokenKind::CloseBrace,
+            b',' => TokenKind::Comma,
+            b'&' => TokenKind::Ampersand,
+            b'=' => TokenKind::Compare(Comparison::Eq),
+            b'<' if bytes.get(position) == Some(&b'=') => {
+                position += 1;
+                TokenKind::Compare(Comparison::Le)
+            }
+            b'>' if bytes.get(position) == Some(&b'=') => {
+                position += 1;
+                TokenKind::Compare(Comparison::Ge)
+            }
+            b'<' => TokenKind::Compare(Comparison::Lt),
+            b'>' => TokenKind::Compare(Comparison::Gt),
+            b'\\' if bytes.get(position) == Some(&b'\\') => {
+                position += 1;
+                TokenKind::RowSeparator
+            }
+            b'\\'
+                if bytes
+                    .get(position)
+                    .is_some_and(|byte| !byte.is_ascii_alphabetic()) =>
+            {
+                // Spacing commands, e.g. `\,` or `\ `.
+                position += 1;
+                continue;
+            }
+            b'\\' => {
+                while position < bytes.len() && bytes[position].is_ascii_alphabetic() {
+                    position += 1;
+                }
+                match &latex[start + 1..position] {
+                    "left" | "right" | "big" | "Big" | "bigg" | "Bigg" | "quad" | "qquad" => {
+                        continue;
+                    }
+                    "cdot" | "times" => TokenKind::Times,
+                    "div" => TokenKind::Slash,
+                    "bmod" | "mod" => TokenKind::Modulo,
+                    "frac" | "dfrac" | "tfrac" => TokenKind::Frac,
+                    "lfloor" => TokenKind::OpenFloor,
+                    "rfloor" => TokenKind::CloseFloor,
+                    "lt" => TokenKind::Compare(Comparison::Lt),
+                    "gt" => TokenKind::Compare(Comparison::Gt),
+                    "le" | "leq" | "leqslant" => TokenKind::Compare(Comparison::Le),
+                    "ge" | "geq" | "geqslant" => TokenKind::Compare(Comparison::Ge),
+                    "text" | "mbox" | "textrm" | "mathrm" => {
+                        let Some(end) = bytes[position..]
+                            .starts_with(b"{")
+                            .then(|| latex[position..].find('}'))
+                            .flatten()
+                        else {
+                            return Err(error_at(start, "expected a braced text"));
+                        };
+                        let text = latex[position + 1..position + end].trim().to_string();
+                        position += end + 1;
+                        TokenKind::Text(text)
+                    }
+                    "begin" | "end" => {
+                        let environment = if latex[position..].starts_with("{cases}") {
+                            position += "{cases}".len();
+                            "cases"
+                        } else {
+                            ""
+                        };
+                        match (&latex[start + 1..start + 4], environment) {
+                            ("beg", "cases") => TokenKind::BeginCases,
+                            ("end", "cases") => TokenKind::EndCases,
+                            _ => return Err(error_at(start, "unsupported environment")),
+                        }
+                    }
+                    _ => return Err(error_at(start, "unsupported command")),
+                }
+            }
+            _ => return Err(error_at(start, "unexpected character")),
+        };
+        tokens.push(Token {
+            kind,
+            position: start,
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// Recursive descent parser of the tokens of a formula.
+struct Parser<'a> {
+    source: &'a str,
+    tokens: &'a [Token],
+    index: usize,
+}
+
+impl Parser<'_> {
+    /// Parses a whole formula, either piecewise or not.
+    fn formula(&mut self) -> Result<Expression, Error> {
+        if self.eat(&TokenKind::BeginCases) {
+            self.cases()
+        } else {
+            self.expression()
+        }
+    }
+
+    /// Parses the cases of a piecewise formula, after `\begin{cases}`.
+    fn cases(&mut self) -> Result<Expression, Error> {
+        let mut cases = Vec::new();
+        loop {
+            if self.eat(&TokenKind::EndCases) {
+                break;
+            }
+            let expression = self.expression()?;
+            self.eat(&TokenKind::Comma);
+            self.expect(&TokenKind::Ampersand, "expected `&` before the condition")?;
+            while matches!(self.peek_kind(), Some(TokenKind::Text(_))) {
+                self.index += 1;
+            }
+            let condition = match self.peek_kind() {
+                Some(TokenKind::RowSeparator | TokenKind::EndCases) => Condition {
+                    first: Expression::Number(0.0),
+                    comparisons: Vec::new(),
+                },
+                _ => self.condition()?,
+            };
+            cases.push((expression, condition));
+            if !self.eat(&TokenKind::RowSeparator) {
+                self.expect(&TokenKind::EndCases, "expected `\\\\` or `\\end{cases}`")?;
+                break;
+            }
+        }
+
+        Ok(Expression::Cases(cases))
+    }
+
+    /// Parses a condition, e.g. `50 < x \leq 68`.
+    fn condition(&mut self) -> Result<Condition, Error> {
+        let first = self.expression()?;
+        let mut comparisons = Vec::new();
+        while let Some(TokenKind::Compare(comparison)) = self.peek_kind() {
+            let comparison = *comparison;
+            self.index += 1;
+            comparisons.push((comparison, self.expression()?));
+        }
+        if comparisons.is_empty() {
+            return Err(self.error_here("expected a comparison"));
+        }
+
+        Ok(Condition { first, comparisons })
+    }
+
+    /// Parses a sum or a difference.
+    fn expression(&mut self) -> Result<Expression, Error> {
+        let mut expression = self.term()?;
+        loop {
+            if self.eat(&TokenKind::Plus) {
+                expression = Expression::Add(Box::new(expression), Box::new(self.term()?));
+            } else if self.eat(&TokenKind::Minus) {
+                expression = Expression::Subtract(Box::new(expression), Box::new(self.term()?));
+            } else {
+                return Ok(expression);
+            }
+        }
+    }
+
+    /// Parses a product, a quotient or a modulo, including the implicit multiplication, e.g. `9 (x \bmod 3)`.
+    fn term(&mut self) -> Result<Expression, Error> {
+        let mut term = self.unary()?;
+        loop {
+            if self.eat(&TokenKind::Times) {
+                term = Expression::Multiply(Box::new(term), Box::new(self.unary()?));
+            } else if self.eat(&TokenKind::Slash) {
+                term = Expression::Divide(Box::new(term), Box::new(self.unary()?));
+            } else if self.eat(&TokenKind::Modulo) {
+                term = Expression::Modulo(Box::new(term), Box::new(self.unary()?));
+            } else if matches!(
+                self.peek_kind(),
+                Some(
+                    TokenKind::Number(_)
+                        | TokenKind::Level
+                        | TokenKind::OpenParen
+                        | TokenKind::OpenBrace
+                        | TokenKind::OpenFloor
+                        | TokenKind::Frac
+                )
+            ) {
+                term = Expression::Multiply(Box::new(term), Box::new(self.power()?));
+            } else {
+                return Ok(term);
+            }
+        }
+    }
+
+    /// Parses a negation.
+    fn unary(&mut self) -> Result<Expression, Error> {
+        if self.eat(&TokenKind::Minus) {
+            Ok(Expression::Negate(Box::new(self.unary()?)))
+        } else {
+            self.power()
+        }
+    }
+
+    /// Parses a power, e.g. `x^3`.
+    fn power(&mut self) -> Result<Expression, Error> {
+        let base = self.atom()?;
+        if self.eat(&TokenKind::Caret) {
+            let exponent = self.atom()?;
+            return Ok(Expression::Power(Box::new(base), Box::new(exponent)));
+        }
+
+        Ok(base)
+    }
+
+    /// Parses a number, the level, a group, a floor or a fraction.
+    fn atom(&mut self) -> Result<Expression, Error> {
+        let Some(token) = self.tokens.get(self.index) else {
+            return Err(self.error_here("unexpected end of formula"));
+        };
+        self.index += 1;
+
+        match &token.kind {
+            TokenKind::Number(number) => Ok(Expression::Number(*number)),
+            TokenKind::Level => Ok(Expression::Level),
+            TokenKind::OpenParen => {
+                let expression = self.expression()?;
+                self.expect(&TokenKind::CloseParen, "expected `)`")?;
+                Ok(expression)
+            }
+            TokenKind::OpenBrace => self.braced_rest(),
+            TokenKind::OpenFloor => {
+                let expression = self.expression()?;
+                self.expect(&TokenKind::CloseFloor, "expected `\\rfloor`")?;
+                Ok(Expression::Floor(Box::new(expression)))
+            }
+            TokenKind::Frac => {
+                self.expect(&TokenKind::OpenBrace, "expected `{` after `\\frac`")?;
+                let numerator = self.braced_rest()?;
+                self.expect(&TokenKind::OpenBrace, "expected `{` after the numerator")?;
+                let denominator = self.braced_rest()?;
+                Ok(Expression::Divide(
+                    Box::new(numerator),
+                    Box::new(denominator),
+                ))
+            }
+            _ => Err(self.error_at(token.position, "expected a number, `x` or a group")),
+        }
+    }
+
+    /// Parses the rest of a braced group, after `{`.
+    fn braced_rest(&mut self) -> Result<Expression, Error> {
+        let expression = self.expression()?;
+        self.expect(&TokenKind::CloseBrace, "expected `}`")?;
+
+        Ok(expression)
+    }
+
+    /// The current token, if any.
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.index)
+    }
+
+    /// The kind of the current token, if any.
+    fn peek_kind(&self) -> Option<&TokenKind> {
+        self.peek().map(|token| &token.kind)
+    }
+
+    /// Consumes the current token if of the given kind.
+    fn eat(&mut self, kind: &TokenKind) -> bool {
+        let is_kind = self.peek_kind() == Some(kind);
+        if is_kind {
+            self.index += 1;
+        }
+
+        is_kind
+    }
+
+    /// Consumes the current token, failing with the given reason if not of the given kind.
+    fn expect(&mut self, kind: &TokenKind, reason: &str) -> Result<(), Error> {
+        if self.eat(kind) {
+            Ok(())
+        } else {
+            Err(self.error_here(reason))
+        }
+    }
+
+    /// The error raised at the current token.
+    fn error_here(&self, reason: &str) -> Error {
+        let position = self
+            .peek()
+            .map_or(self.source.len(), |token| token.position);
+
+        self.error_at(position, reason)
+    }
+
+    /// The error raised at the given byte position.
+    fn error_at(&self, position: usize, reason: &str) -> Error {
+        Error::FormulaParse {
+            formula: self.source.to_string(),
+            position,
+            reason: reason.to_string(),
+        }
+    }
+}
+
+/// Experience gained by a Pokémon defeating another one, under the formulas of the different generations.
+///
+/// The flat formulas of generations 1 to 4 and 6 only depend on the defeated Pokémon, while the scaled formulas of
+/// generations 5 and 7 onwards also depend on the level of the victorious one, the defeated Pokémon's level unless set.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExperienceGain {
+    base_experience: i64,
+    level: i64,
+    victor_level: Option<i64>,
+    participants: i64,
+    trainer_battle: bool,
+    traded: bool,
+    international: bool,
+    lucky_egg: bool,
+}
+
+impl ExperienceGain {
+    /// Creates the gain of defeating a Pokémon having the given base experience, at the given level, in a wild battle
+    /// by a single original Pokémon.
+    pub fn new(base_experience: i64, level: i64) -> Self {
+        Self {
+            base_experience,
+            level,
+            victor_level: None,
+            participants: 1,
+            trainer_battle: false,
+            traded: false,
+            international: false,
+            lucky_egg: false,
+        }
+    }
+
+    /// Creates the gain of defeating the given Pokémon at the given level, if its base experience is known.
+    pub fn of(defeated: &Pokemon, level: i64) -> Option<Self> {
+        Some(Self::new(defeated.base_experience?, level))
+    }
+
+    /// Sets the level of the victorious Pokémon, used by the scaled formulas.
+    pub fn with_victor_level(mut self, level: i64) -> Self {
+        self.victor_level = Some(level);
+        self
+    }
+
+    /// Sets the number of Pokémon sharing the experience, by taking part in the battle or through Exp. Share.
+    pub fn with_participants(mut self, participants: i64) -> Self {
+        self.participants = participants;
+        self
+    }
+
+    /// Sets whether the defeated Pokémon belonged to a trainer, boosting the experience outside of generations 7 on.
+    pub fn with_trainer_battle(mut self, trainer_battle: bool) -> Self {
+        self.trainer_battle = trainer_battle;
+        self
+    }
+
+    /// Sets whether the victorious Pokémon was traded, boosting the experience.
+    pub fn with_traded(mut self, traded: bool) -> Self {
+        self.traded = traded;
+        self
+    }
+
+    /// Sets whether the victorious Pokémon was traded from a game of another language, boosting the experience further
+    /// from generation 4 on.
+    pub fn with_international(mut self, international: bool) -> Self {
+        self.international = international;
+        self.traded |= international;
+        self
+    }
+
+    /// Sets whether the victorious Pokémon holds a Lucky Egg, boosting the experience from generation 2 on.
+    pub fn with_lucky_egg(mut self, lucky_egg: bool) -> Self {
+        self.lucky_egg = lucky_egg;
+        self
+    }
+
+    /// Computes the experience gained in the given generation, rounded down at each step like the games do.
+    ///
+    /// Fails if the generation isn't within `1..=9`, a level within `1..=100`, or if there is no participant.
+    pub fn experience(&self, generation: i64) -> Result<i64, Error> {
+        check_range("generation", generation, 1, 9)?;
+        check_range("level", self.level, 1, MAX_LEVEL)?;
+        let victor_level = self.victor_level.unwrap_or(self.level);
+        check_range("victor level", victor_level, 1, MAX_LEVEL)?;
+        check_range("participants", self.participants, 1, i64::MAX)?;
+
+        let boost = |experience: i64, applies: bool, percent: i64| {
+            if applies {
+                experience * percent / 100
+            } else {
+                experience
+            }
+        };
+        let trade_percent = if self.international && generation >= 4 {
+            170
+        } else {
+            150
+        };
+
+        let experience = if generation == 5 || generation >= 7 {
+            let level = self.level as f64;
+            let scale = ((2.0 * level + 10.0) / (level + victor_level as f64 + 10.0)).powf(2.5);
+            let trainer = generation == 5 && self.trainer_battle;
+            let experience =
+                boost(self.base_experience * self.level, trainer, 150) / 5 / self.participants;
+
+            (experience as f64 * scale).floor() as i64 + 1
+        } else {
+            let experience = self.base_experience * self.level / 7 / self.participants;
+
+            boost(experience, self.trainer_battle, 150)
+        };
+        let experience = boost(experience, self.traded, trade_percent);
+
+        Ok(boost(experience, self.lucky_egg && generation >= 2, 150))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const ERRATIC: &str = r"\begin{cases}
+\frac{ x^3 \left( 100 - x \right) }{50}, & \text{if } x \leq 50 \\
+\frac{ x^3 \left( 150 - x \right) }{100}, & \text{if } 50 < x \leq 68 \\
+\frac{ x^3 \left( 1274 + (x \bmod 3)^2 - 9 (x \bmod 3) - 20 \left \lfloor \frac{x}{3} \right \rfloor \right) }{1000}, & \text{if } 68 < x \leq 98 \\
+\frac{ x^3 \left( 160 - x \right) }{100}, & \text{if } x > 98 \\
+\end{cases}";
+
+    const FLUCTUATING: &str = r"\begin{cases}
+\frac{ x^3 \left( \left \lfloor \frac{x + 1}{3} \right \rfloor + 24 \right) }{50}, & \text{if } x \leq 15 \\
+\frac{ x^3 \left( x + 14 \right) }{50}, & \text{if } 15 < x \leq 36 \\
+\frac{ x^3 \left( \left \lfloor \frac{x}{2} \right \rfloor + 32 \right) }{50}, & \text{if } x > 36 \\
+\end{cases}";
+
+    const MEDIUM_SLOW: &str = r"\frac{6x^3}{5} - 15x^2 + 100x - 140";
+
+    /// A growth rate following the given formula, its levels computed from it.
+    fn growth_rate(formula: &str) -> GrowthRate {
+        let parsed = Formula::parse(formula).unwrap();
+        let levels: Vec<_> = (1..=MAX_LEVEL)
+            .map(|level| {
+                let experience = match level {
+                    1 => 0,
+                    _ => parsed.experience_at(level).unwrap(),
+                };
+                json!({ "level": level, "experience": experience })
+            })
+            .collect();
+
+        serde_json::from_value(json!({
+            "id": 4,
+            "name": "medium-slow",
+            "formula": formula,
+            "descriptions": [],
+            "levels": levels,
+            "pokemon_species": [],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn erratic_formula_follows_every_case() {
+        let erratic = Formula::parse(ERRATIC).unwrap();
+
+        assert_eq!(erratic.experience_at(50), Some(125_000));
+        assert_eq!(erratic.experience_at(68), Some(257_834));
+        assert_eq!(erratic.experience_at(70), Some(276_458));
+        assert_eq!(erratic.experience_at(98), Some(583_539));
+        assert_eq!(erratic.experience_at(100), Some(600_000));
+    }
+
+    #[test]
+    fn fluctuating_formula_follows_every_case() {
+        let fluctuating = Formula::parse(FLUCTUATING).unwrap();
+
+        assert_eq!(fluctuating.experience_at(15), Some(1_957));
+        assert_eq!(fluctuating.experience_at(36), Some(46_656));
+        assert_eq!(fluctuating.experience_at(100), Some(1_640_000));
+    }
+
+    #[test]
+    fn medium_slow_formula_is_rounded_down() {
+        let medium_slow = Formula::parse(MEDIUM_SLOW).unwrap();
+
+        assert_eq!(medium_slow.experience_at(12), Some(973));
+        assert_eq!(medium_slow.experience_at(13), Some(1_261));
+        assert_eq!(medium_slow.to_string(), MEDIUM_SLOW);
+    }
+
+    #[test]
+    fn levels_are_found_from_the_experience() {
+        let medium_slow = growth_rate(MEDIUM_SLOW);
+
+        assert_eq!(medium_slow.max_level(), Some(100));
+        assert_eq!(medium_slow.level_for_experience(0), 1);
+        assert_eq!(medium_slow.level_for_experience(1_000), 12);
+        assert_eq!(medium_slow.experience_to_next_level(1_000), Some(261));
+        assert_eq!(medium_slow.experience_to_level(1_000, 12), Some(0));
+        assert_eq!(medium_slow.experience_to_next_level(1_059_860), None);
+    }
+
+    #[test]
+    fn unsupported_formulas_are_rejected() {
+        assert!(matches!(
+            Formula::parse(r"\sqrt{x}"),
+            Err(Error::FormulaParse { .. })
+        ));
+        assert!(matches!(
+            Formula::parse(r"\frac{x^3}{"),
+            Err(Error::FormulaParse { .. })
+        ));
+    }
+
+    #[test]
+    fn trainer_battles_boost_the_flat_and_scaled_formulas() {
+        let gain = ExperienceGain::new(64, 5).with_trainer_battle(true);
+
+        assert_eq!(gain.experience(4).unwrap(), 67);
+        assert_eq!(gain.with_victor_level(10).experience(5).unwrap(), 55);
+    }
+
+    #[test]
+    fn experience_is_shared_and_boosted() {
+        let gain = ExperienceGain::new(64, 5);
+
+        assert_eq!(gain.experience(4).unwrap(), 45);
+        assert_eq!(gain.with_participants(2).experience(4).unwrap(), 22);
+        assert_eq!(gain.with_lucky_egg(true).experience(4).unwrap(), 67);
+        assert_eq!(gain.with_traded(true).experience(4).unwrap(), 67);
+        assert_eq!(gain.with_international(true).experience(4).unwrap(), 76);
+        assert!(gain.with_participants(0).experience(4).is_err());
+        assert!(gain.experience(10).is_err());
+    }
+}
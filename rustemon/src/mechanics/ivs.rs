@@ -0,0 +1,330 @@
+//! IVs of the Pokémon: judged back from their stats, hinted by their [`Characteristic`], and deciding their Hidden Power.
+//!
+//! ```rust
+//! use rustemon::mechanics::{
+//!     ivs::{HiddenPower, IvCandidates},
+//!     stats::{BaseStats, StatSet},
+//! };
+//! # fn judge(garchomp: rustemon::model::pokemon::Pokemon, adamant: rustemon::model::pokemon::Nature, takes_plenty_of_siestas: rustemon::model::pokemon::Characteristic) -> Result<(), rustemon::error::Error> {
+//!
+//! let stats = StatSet {
+//!     hp: 289,
+//!     attack: 278,
+//!     defense: 193,
+//!     special_attack: 135,
+//!     special_defense: 171,
+//!     speed: 171,
+//! };
+//! let evs = StatSet {
+//!     hp: 74,
+//!     attack: 190,
+//!     defense: 91,
+//!     special_attack: 48,
+//!     special_defense: 84,
+//!     speed: 23,
+//! };
+//! let candidates = IvCandidates::judge(&BaseStats::from(&garchomp), 78, &stats, &evs, Some(&adamant))?
+//!     .with_characteristic(&takes_plenty_of_siestas);
+//! println!("{:?}", candidates.range(&rustemon::model::kinds::StatKind::Defense));
+//!
+//! let hidden_power = HiddenPower::of(&StatSet::splat(31), 5)?;
+//! println!("Hidden Power {} {}", hidden_power.type_name, hidden_power.power);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::ops::RangeInclusive;
+
+use super::stats::{BATTLE_STATS, BaseStats, MAX_DV, MAX_IV, StatCalculator, StatSet, check_range};
+use crate::{
+    error::Error,
+    model::{
+        kinds::{StatKind, TypeName},
+        pokemon::{Characteristic, Nature},
+    },
+};
+
+/// Order of the stats used by the characteristics, the Hidden Power and the personality value.
+const GENE_ORDER: [StatKind; 6] = [
+    StatKind::Hp,
+    StatKind::Attack,
+    StatKind::Defense,
+    StatKind::Speed,
+    StatKind::SpecialAttack,
+    StatKind::SpecialDefense,
+];
+
+/// Types Hidden Power can have, by index.
+const HIDDEN_POWER_TYPES: [TypeName; 16] = [
+    TypeName::Fighting,
+    TypeName::Flying,
+    TypeName::Poison,
+    TypeName::Ground,
+    TypeName::Rock,
+    TypeName::Bug,
+    TypeName::Ghost,
+    TypeName::Steel,
+    TypeName::Fire,
+    TypeName::Water,
+    TypeName::Grass,
+    TypeName::Electric,
+    TypeName::Psychic,
+    TypeName::Ice,
+    TypeName::Dragon,
+    TypeName::Dark,
+];
+
+/// IVs a Pokémon may have in each stat, e.g. judged from its stats.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IvCandidates {
+    /// The candidates, in the order of [`BATTLE_STATS`].
+    candidates: [Vec<i64>; 6],
+}
+
+impl Default for IvCandidates {
+    /// Every IV is possible in every stat.
+    fn default() -> Self {
+        Self {
+            candidates: std::array::from_fn(|_| (0..=MAX_IV).collect()),
+        }
+    }
+}
+
+impl IvCandidates {
+    /// Judges the IVs of a Pokémon having the given base stats, from its stats observed at the given level, its EVs
+    /// and its nature, with the formulas of generation 3 onwards.
+    ///
+    /// Fails if the level or the EVs are invalid. A stat no IV leads to has no candidate, e.g. if the EVs are wrong.
+    pub fn judge(
+        base_stats: &BaseStats,
+        level: i64,
+        stats: &StatSet,
+        evs: &StatSet,
+        nature: Option<&Nature>,
+    ) -> Result<Self, Error> {
+        let mut calculator = StatCalculator::new(base_stats, level).with_evs(*evs);
+        if let Some(nature) = nature {
+            calculator = calculator.with_nature(nature);
+        }
+        calculator.stats()?;
+
+        let mut candidates = Self::default();
+        for (stat, ivs) in BATTLE_STATS.iter().zip(&mut candidates.candidates) {
+            let observed = stats.get(stat);
+            ivs.retain(|iv| {
+                observed
+                    == Some(
+                        calculator
+                            .with_ivs(StatSet::splat(*iv))
+                            .stat_unchecked(stat),
+                    )
+            });
+        }
+
+        Ok(candidates)
+    }
+
+    /// Narrows the candidates down to the ones matching the given characteristic: its highest stat has one of its
+    /// possible values, and no other stat has a higher IV.
+    pub fn with_characteristic(mut self, characteristic: &Characteristic) -> Self {
+        let highest_stat = StatKind::from(&characteristic.highest_stat);
+        let Some(index) = BATTLE_STATS.iter().position(|stat| *stat == highest_stat) else {
+            return self;
+        };
+
+        self.candidates[index].retain(|iv| characteristic.possible_values.contains(iv));
+        let highest = self.candidates[index].iter().max().copied().unwrap_or(-1);
+        for ivs in &mut self.candidates {
+            ivs.retain(|iv| *iv <= highest);
+        }
+
+        self
+    }
+
+    /// The candidates of the given stat, empty if not one of the six stats.
+    pub fn get(&self, stat: &StatKind) -> &[i64] {
+        BATTLE_STATS
+            .iter()
+            .position(|candidate| candidate == stat)
+            .map_or(&[], |index| &self.candidates[index])
+    }
+
+    /// The range of the candidates of the given stat, if it has any.
+    pub fn range(&self, stat: &StatKind) -> Option<RangeInclusive<i64>> {
+        let ivs = self.get(stat);
+
+        Some(*ivs.iter().min()?..=*ivs.iter().max()?)
+    }
+
+    /// Whether every stat has at least a candidate.
+    pub fn is_consistent(&self) -> bool {
+        self.candidates.iter().all(|ivs| !ivs.is_empty())
+    }
+
+    /// The IVs, if every stat has a single candidate.
+    pub fn exact(&self) -> Option<StatSet> {
+        let mut ivs = StatSet::default();
+        for (stat, candidates) in BATTLE_STATS.iter().zip(&self.candidates) {
+            match candidates[..] {
+                [iv] => *ivs.get_mut(stat)? = iv,
+                _ => return None,
+            }
+        }
+
+        Some(ivs)
+    }
+}
+
+impl Characteristic {
+    /// Whether the characteristic may be shown for a Pokémon having the given IVs: its highest stat has the highest
+    /// IV, which is one of its possible values.
+    ///
+    /// When several stats share the highest IV, the game picks one from the personality value of the Pokémon, see
+    /// [`highest_stat`].
+    pub fn matches(&self, ivs: &StatSet) -> bool {
+        let highest = ivs.iter().map(|(_, iv)| iv).max().unwrap_or_default();
+
+        ivs.get(&StatKind::from(&self.highest_stat))
+            .is_some_and(|iv| iv == highest && self.possible_values.contains(&iv))
+    }
+}
+
+/// Returns the characteristics which may be shown for a Pokémon having the given IVs.
+pub fn characteristics_of<'a>(
+    ivs: &StatSet,
+    characteristics: impl IntoIterator<Item = &'a Characteristic>,
+) -> Vec<&'a Characteristic> {
+    characteristics
+        .into_iter()
+        .filter(|characteristic| characteristic.matches(ivs))
+        .collect()
+}
+
+/// Returns the stat the characteristic of a Pokémon is about: the one having the highest IV, the ties being broken by
+/// starting from the stat given by the personality value, in the order HP, Attack, Defense, Speed, Special Attack and
+/// Special Defense.
+pub fn highest_stat(ivs: &StatSet, personality_value: u32) -> StatKind {
+    let highest = ivs.iter().map(|(_, iv)| iv).max().unwrap_or_default();
+    let start = (personality_value % 6) as usize;
+
+    (0..GENE_ORDER.len())
+        .map(|offset| &GENE_ORDER[(start + offset) % GENE_ORDER.len()])
+        .find(|stat| ivs.get(stat) == Some(highest))
+        .cloned()
+        .unwrap_or(StatKind::Hp)
+}
+
+/// Type and power of the Hidden Power of a Pokémon.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HiddenPower {
+    /// The type of the Hidden Power.
+    pub type_name: TypeName,
+    /// The power of the Hidden Power.
+    pub power: i64,
+}
+
+impl HiddenPower {
+    /// The Hidden Power of a Pokémon having the given IVs, in the given generation.
+    ///
+    /// In generation 2, the IVs are the DVs and the Special DV is the one of the Special Attack. The power varies
+    /// with the IVs up to generation 5, and is always 60 onwards.
+    ///
+    /// Fails if the generation isn't within `2..=7`, which had Hidden Power, or an IV isn't within `0..=31`, or a DV
+    /// within `0..=15`.
+    pub fn of(ivs: &StatSet, generation: i64) -> Result<Self, Error> {
+        check_range("generation", generation, 2, 7)?;
+        let max = if generation == 2 { MAX_DV } else { MAX_IV };
+        for (stat, iv) in ivs.iter() {
+            check_range(&format!("{stat} IV"), iv, 0, max)?;
+        }
+
+        if generation == 2 {
+            let special = ivs.special_attack;
+            let index = 4 * (ivs.attack % 4) + ivs.defense % 4;
+            let bits = (special >> 3)
+                + 2 * (ivs.speed >> 3)
+                + 4 * (ivs.defense >> 3)
+                + 8 * (ivs.attack >> 3);
+
+            return Ok(Self {
+                type_name: HIDDEN_POWER_TYPES[index as usize].clone(),
+                power: (5 * bits + special % 4) / 2 + 31,
+            });
+        }
+
+        let bits = |bit: u32| {
+            GENE_ORDER
+                .iter()
+                .enumerate()
+                .map(|(index, stat)| ((ivs.get(stat).unwrap_or_default() >> bit) & 1) << index)
+                .sum::<i64>()
+        };
+
+        Ok(Self {
+            type_name: HIDDEN_POWER_TYPES[(bits(0) * 15 / 63) as usize].clone(),
+            power: if generation <= 5 {
+                bits(1) * 40 / 63 + 30
+            } else {
+                60
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mechanics::stats::tests::{EVS, IVS, STATS, adamant, garchomp};
+
+    #[test]
+    fn ivs_are_judged_back_from_the_stats() {
+        let candidates =
+            IvCandidates::judge(&garchomp(), 78, &STATS, &EVS, Some(&adamant())).unwrap();
+
+        assert!(candidates.is_consistent());
+        for (stat, iv) in IVS.iter() {
+            assert!(candidates.get(&stat).contains(&iv), "{stat}");
+        }
+        assert_eq!(candidates.range(&StatKind::Hp), Some(24..=24));
+        assert_eq!(candidates.get(&StatKind::Accuracy), &[] as &[i64]);
+    }
+
+    #[test]
+    fn ties_of_the_highest_stat_are_broken_by_the_personality_value() {
+        let ivs = StatSet {
+            hp: 30,
+            attack: 31,
+            defense: 20,
+            special_attack: 10,
+            special_defense: 31,
+            speed: 31,
+        };
+
+        // Starting from HP, Attack, Defense, Speed, Special Attack and Special Defense in turn.
+        assert_eq!(highest_stat(&ivs, 0), StatKind::Attack);
+        assert_eq!(highest_stat(&ivs, 2), StatKind::Speed);
+        assert_eq!(highest_stat(&ivs, 4), StatKind::SpecialDefense);
+        assert_eq!(highest_stat(&ivs, 5), StatKind::SpecialDefense);
+        assert_eq!(highest_stat(&ivs, 6 + 3), StatKind::Speed);
+        assert_eq!(highest_stat(&StatSet::splat(31), 0), StatKind::Hp);
+    }
+
+    #[test]
+    fn hidden_power_of_perfect_ivs_is_dark_70() {
+        let dark_70 = HiddenPower {
+            type_name: TypeName::Dark,
+            power: 70,
+        };
+
+        assert_eq!(HiddenPower::of(&StatSet::splat(31), 5).unwrap(), dark_70);
+        assert_eq!(HiddenPower::of(&StatSet::splat(15), 2).unwrap(), dark_70);
+        assert_eq!(HiddenPower::of(&StatSet::splat(31), 6).unwrap().power, 60);
+    }
+
+    #[test]
+    fn hidden_power_rejects_generations_without_it() {
+        assert!(HiddenPower::of(&StatSet::splat(31), 1).is_err());
+        assert!(HiddenPower::of(&StatSet::splat(31), 8).is_err());
+        assert!(HiddenPower::of(&StatSet::splat(16), 2).is_err());
+    }
+}
@@ -0,0 +1,7 @@
+//! Mechanics of the games computed from the models, e.g. the effectiveness of the types.
+
+pub mod experience;
+pub mod ivs;
+pub mod stats;
+pub mod team;
+pub mod type_chart;
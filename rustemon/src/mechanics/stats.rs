@@ -0,0 +1,515 @@
+//! In-game stats of the Pokémon, computed from their base stats, level, IVs, EVs and nature.
+//!
+//! The [`StatCalculator`] applies the formulas of generation 3 onwards with [`StatCalculator::stats`], and the ones of
+//! generations 1 and 2, based on DVs and stat experience, with [`StatCalculator::classic_stats`].
+//!
+//! ```rust
+//! use rustemon::mechanics::stats::{BaseStats, StatCalculator, StatSet};
+//! # fn garchomp(garchomp: rustemon::model::pokemon::Pokemon, adamant: rustemon::model::pokemon::Nature) -> Result<(), rustemon::error::Error> {
+//!
+//! let base_stats = BaseStats::from(&garchomp);
+//! let stats = StatCalculator::new(&base_stats, 78)
+//!     .with_ivs(StatSet::splat(31))
+//!     .with_evs(StatSet {
+//!         attack: 252,
+//!         speed: 252,
+//!         hp: 6,
+//!         ..StatSet::default()
+//!     })
+//!     .with_nature(&adamant)
+//!     .stats()?;
+//! println!("{} HP, {} Attack", stats.hp, stats.attack);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    error::Error,
+    model::{
+        kinds::StatKind,
+        pokemon::{Nature, Pokemon, PokemonStat},
+    },
+};
+
+/// Highest level of a Pokémon.
+pub const MAX_LEVEL: i64 = 100;
+
+/// Highest IV of a stat, from generation 3 onwards.
+pub const MAX_IV: i64 = 31;
+
+/// Highest EVs in a single stat, from generation 3 onwards.
+pub const MAX_EVS_PER_STAT: i64 = 252;
+
+/// Highest total of the EVs of a Pokémon, from generation 3 onwards.
+pub const MAX_EV_TOTAL: i64 = 510;
+
+/// Highest DV of a stat, in generations 1 and 2.
+pub const MAX_DV: i64 = 15;
+
+/// Highest stat experience of a stat, in generations 1 and 2.
+pub const MAX_STAT_EXPERIENCE: i64 = 65535;
+
+/// The six stats of a Pokémon, in the order of `PokeApi`.
+pub const BATTLE_STATS: [StatKind; 6] = [
+    StatKind::Hp,
+    StatKind::Attack,
+    StatKind::Defense,
+    StatKind::SpecialAttack,
+    StatKind::SpecialDefense,
+    StatKind::Speed,
+];
+
+/// A value for each of the six stats of a Pokémon, e.g. its base stats, IVs, EVs or in-game stats.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct StatSet {
+    /// The value of the HP.
+    pub hp: i64,
+    /// The value of the Attack.
+    pub attack: i64,
+    /// The value of the Defense.
+    pub defense: i64,
+    /// The value of the Special Attack, or of the Special in generation 1.
+    pub special_attack: i64,
+    /// The value of the Special Defense.
+    pub special_defense: i64,
+    /// The value of the Speed.
+    pub speed: i64,
+}
+
+impl StatSet {
+    /// Creates a set having the same value for every stat, e.g. `StatSet::splat(31)` for perfect IVs.
+    pub const fn splat(value: i64) -> Self {
+        Self {
+            hp: value,
+            attack: value,
+            defense: value,
+            special_attack: value,
+            special_defense: value,
+            speed: value,
+        }
+    }
+
+    /// The value of the given stat, if one of the six stats.
+    pub fn get(&self, stat: &StatKind) -> Option<i64> {
+        Some(match stat {
+            StatKind::Hp => self.hp,
+            StatKind::Attack => self.attack,
+            StatKind::Defense => self.defense,
+            StatKind::SpecialAttack => self.special_attack,
+            StatKind::SpecialDefense => self.special_defense,
+            StatKind::Speed => self.speed,
+            _ => return None,
+        })
+    }
+
+    /// The mutable value of the given stat, if one of the six stats.
+    pub fn get_mut(&mut self, stat: &StatKind) -> Option<&mut i64> {
+        Some(match stat {
+            StatKind::Hp => &mut self.hp,
+            StatKind::Attack => &mut self.attack,
+            StatKind::Defense => &mut self.defense,
+            StatKind::SpecialAttack => &mut self.special_attack,
+            StatKind::SpecialDefense => &mut self.special_defense,
+            StatKind::Speed => &mut self.speed,
+            _ => return None,
+        })
+    }
+
+    /// The stats along with their value, in the order of [`BATTLE_STATS`].
+    pub fn iter(&self) -> impl Iterator<Item = (StatKind, i64)> + '_ {
+        BATTLE_STATS.into_iter().map(|stat| {
+            let value = self.get(&stat).unwrap_or_default();
+            (stat, value)
+        })
+    }
+
+    /// The sum of the values of the stats.
+    pub fn total(&self) -> i64 {
+        self.iter().map(|(_, value)| value).sum()
+    }
+
+    /// The HP DV of generations 1 and 2, derived from the lowest bit of the Attack, Defense, Speed and Special DVs,
+    /// the Special DV being the one of the Special Attack.
+    pub fn hp_dv(&self) -> i64 {
+        ((self.attack & 1) << 3)
+            | ((self.defense & 1) << 2)
+            | ((self.speed & 1) << 1)
+            | (self.special_attack & 1)
+    }
+
+    /// Validates the set as EVs: at most [`MAX_EVS_PER_STAT`] in a stat, and [`MAX_EV_TOTAL`] in total.
+    pub fn validate_evs(&self) -> Result<(), Error> {
+        for (stat, value) in self.iter() {
+            check_range(&format!("{stat} EVs"), value, 0, MAX_EVS_PER_STAT)?;
+        }
+        let total = self.total();
+        if total > MAX_EV_TOTAL {
+            return Err(Error::EffortTotalExceeded {
+                total,
+                max: MAX_EV_TOTAL,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Validates every value of the set to be within `0..=max`, `what` naming the values, e.g. `"IV"`.
+    fn validate(&self, what: &str, max: i64) -> Result<(), Error> {
+        for (stat, value) in self.iter() {
+            check_range(&format!("{stat} {what}"), value, 0, max)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Base stats and EV yield of a Pokémon, viewed from its [`PokemonStat`]s.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct BaseStats {
+    /// The base value of the stats.
+    pub stats: StatSet,
+    /// The EVs gained by defeating the Pokémon.
+    pub effort_yield: StatSet,
+    /// The HP of the Pokémon whatever its level, IVs and EVs, e.g. 1 for Shedinja.
+    pub fixed_hp: Option<i64>,
+}
+
+impl BaseStats {
+    /// Creates the view of the given stats, ignoring the ones other than the six stats.
+    pub fn new(stats: &[PokemonStat]) -> Self {
+        let mut base_stats = Self::default();
+        for stat in stats {
+            let kind = stat.kind();
+            if let Some(value) = base_stats.stats.get_mut(&kind) {
+                *value = stat.base_stat;
+            }
+            if let Some(value) = base_stats.effort_yield.get_mut(&kind) {
+                *value = stat.effort;
+            }
+        }
+
+        base_stats
+    }
+
+    /// Sets the HP of the Pokémon whatever its level, IVs and EVs.
+    pub fn with_fixed_hp(mut self, hp: i64) -> Self {
+        self.fixed_hp = Some(hp);
+        self
+    }
+}
+
+impl From<&Pokemon> for BaseStats {
+    /// Creates the view of the stats of the Pokémon, the HP of Shedinja being always 1.
+    fn from(pokemon: &Pokemon) -> Self {
+        let base_stats = Self::new(&pokemon.stats);
+        match &*pokemon.species.name {
+            "shedinja" => base_stats.with_fixed_hp(1),
+            _ => base_stats,
+        }
+    }
+}
+
+impl Nature {
+    /// The multiplier the nature applies to the given stat: `1.1` if increased, `0.9` if decreased, `1.0` otherwise.
+    pub fn stat_modifier(&self, stat: &StatKind) -> f64 {
+        modifier_percent(Some(self), stat) as f64 / 100.0
+    }
+}
+
+/// Computes the in-game stats of a Pokémon, from its base stats, level, IVs, EVs and nature.
+///
+/// The IVs and EVs are zero unless set.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatCalculator<'a> {
+    base_stats: &'a BaseStats,
+    level: i64,
+    ivs: StatSet,
+    evs: StatSet,
+    nature: Option<&'a Nature>,
+}
+
+impl<'a> StatCalculator<'a> {
+    /// Creates the calculator of a Pokémon having the given base stats, at the given level.
+    pub fn new(base_stats: &'a BaseStats, level: i64) -> Self {
+        Self {
+            base_stats,
+            level,
+            ivs: StatSet::default(),
+            evs: StatSet::default(),
+            nature: None,
+        }
+    }
+
+    /// Sets the IVs, or the DVs for [`StatCalculator::classic_stats`].
+    pub fn with_ivs(mut self, ivs: StatSet) -> Self {
+        self.ivs = ivs;
+        self
+    }
+
+    /// Sets the EVs, or the stat experience for [`StatCalculator::classic_stats`].
+    pub fn with_evs(mut self, evs: StatSet) -> Self {
+        self.evs = evs;
+        self
+    }
+
+    /// Sets the nature, neutral unless set.
+    pub fn with_nature(mut self, nature: &'a Nature) -> Self {
+        self.nature = Some(nature);
+        self
+    }
+
+    /// Computes the stats with the formulas of generation 3 onwards.
+    ///
+    /// Fails if the level isn't within `1..=100`, an IV within `0..=31`, or if the EVs aren't valid.
+    pub fn stats(&self) -> Result<StatSet, Error> {
+        self.validate()?;
+
+        let mut stats = StatSet::default();
+        for stat in &BATTLE_STATS {
+            if let Some(value) = stats.get_mut(stat) {
+                *value = self.stat_unchecked(stat);
+            }
+        }
+
+        Ok(stats)
+    }
+
+    /// Computes the given stat with the formulas of generation 3 onwards, if one of the six stats.
+    pub fn stat(&self, stat: &StatKind) -> Result<Option<i64>, Error> {
+        self.validate()?;
+
+        Ok(BATTLE_STATS
+            .contains(stat)
+            .then(|| self.stat_unchecked(stat)))
+    }
+
+    /// Computes the stats with the formulas of generations 1 and 2, the IVs being the DVs and the EVs the stat
+    /// experience.
+    ///
+    /// The HP DV is derived from the other DVs, see [`StatSet::hp_dv`], and the Special DV of the Special Attack is
+    /// used by both special stats. The nature is ignored.
+    ///
+    /// Fails if the level isn't within `1..=100`, a DV within `0..=15`, or a stat experience within `0..=65535`.
+    pub fn classic_stats(&self) -> Result<StatSet, Error> {
+        check_range("level", self.level, 1, MAX_LEVEL)?;
+        self.ivs.validate("DV", MAX_DV)?;
+        self.evs.validate("stat experience", MAX_STAT_EXPERIENCE)?;
+
+        let mut stats = StatSet::default();
+        for stat in &BATTLE_STATS {
+            let dv = match stat {
+                StatKind::Hp => self.ivs.hp_dv(),
+                StatKind::SpecialDefense => self.ivs.special_attack,
+                _ => self.ivs.get(stat).unwrap_or_default(),
+            };
+            let base = self.base_stats.stats.get(stat).unwrap_or_default();
+            let stat_experience = self.evs.get(stat).unwrap_or_default();
+            let experience_bonus = ((stat_experience as f64).sqrt().ceil() as i64).min(255) / 4;
+            let value = ((base + dv) * 2 + experience_bonus) * self.level / 100;
+
+            if let Some(computed) = stats.get_mut(stat) {
+                *computed = match (stat, self.base_stats.fixed_hp) {
+                    (StatKind::Hp, Some(hp)) => hp,
+                    (StatKind::Hp, None) => value + self.level + 10,
+                    _ => value + 5,
+                };
+            }
+        }
+
+        Ok(stats)
+    }
+
+    /// Validates the inputs of the formulas of generation 3 onwards.
+    fn validate(&self) -> Result<(), Error> {
+        check_range("level", self.level, 1, MAX_LEVEL)?;
+        self.ivs.validate("IV", MAX_IV)?;
+        self.evs.validate_evs()
+    }
+
+    /// Computes the given stat with the formulas of generation 3 onwards, without validating the inputs.
+    pub(crate) fn stat_unchecked(&self, stat: &StatKind) -> i64 {
+        let base = self.base_stats.stats.get(stat).unwrap_or_default();
+        let iv = self.ivs.get(stat).unwrap_or_default();
+        let ev = self.evs.get(stat).unwrap_or_default();
+        let value = (2 * base + iv + ev / 4) * self.level / 100;
+
+        match (stat, self.base_stats.fixed_hp) {
+            (StatKind::Hp, Some(hp)) => hp,
+            (StatKind::Hp, None) => value + self.level + 10,
+            _ => (value + 5) * modifier_percent(self.nature, stat) / 100,
+        }
+    }
+}
+
+/// The multiplier the nature applies to the given stat, as a percentage.
+fn modifier_percent(nature: Option<&Nature>, stat: &StatKind) -> i64 {
+    let Some(nature) = nature else {
+        return 100;
+    };
+    let is = |modified: &Option<_>| {
+        modified
+            .as_ref()
+            .is_some_and(|modified| StatKind::from(modified) == *stat)
+    };
+
+    match (is(&nature.increased_stat), is(&nature.decreased_stat)) {
+        (true, false) => 110,
+        (false, true) => 90,
+        _ => 100,
+    }
+}
+
+/// Fails with [`Error::OutOfRange`] if the value isn't within `min..=max`.
+pub(super) fn check_range(what: &str, value: i64, min: i64, max: i64) -> Result<(), Error> {
+    if (min..=max).contains(&value) {
+        Ok(())
+    } else {
+        Err(Error::OutOfRange {
+            what: what.to_string(),
+            value,
+            min,
+            max,
+        })
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    /// The base stats of Garchomp.
+    pub(in crate::mechanics) fn garchomp() -> BaseStats {
+        BaseStats {
+            stats: StatSet {
+                hp: 108,
+                attack: 130,
+                defense: 95,
+                special_attack: 80,
+                special_defense: 85,
+                speed: 102,
+            },
+            ..BaseStats::default()
+        }
+    }
+
+    /// The Adamant nature, increasing the Attack and decreasing the Special Attack.
+    pub(in crate::mechanics) fn adamant() -> Nature {
+        serde_json::from_value(json!({
+            "id": 3,
+            "name": "adamant",
+            "decreased_stat": { "name": "special-attack", "url": "https://pokeapi.co/api/v2/stat/4/" },
+            "increased_stat": { "name": "attack", "url": "https://pokeapi.co/api/v2/stat/2/" },
+            "hates_flavor": null,
+            "likes_flavor": null,
+            "pokeathlon_stat_changes": [],
+            "move_battle_style_preferences": [],
+            "names": [],
+        }))
+        .unwrap()
+    }
+
+    /// The IVs of the Garchomp of the examples of Bulbapedia.
+    pub(in crate::mechanics) const IVS: StatSet = StatSet {
+        hp: 24,
+        attack: 12,
+        defense: 30,
+        special_attack: 16,
+        special_defense: 23,
+        speed: 5,
+    };
+
+    /// The EVs of the Garchomp of the examples of Bulbapedia.
+    pub(in crate::mechanics) const EVS: StatSet = StatSet {
+        hp: 74,
+        attack: 190,
+        defense: 91,
+        special_attack: 48,
+        special_defense: 84,
+        speed: 23,
+    };
+
+    /// The stats of the Garchomp of the examples of Bulbapedia, at level 78.
+    pub(in crate::mechanics) const STATS: StatSet = StatSet {
+        hp: 289,
+        attack: 278,
+        defense: 193,
+        special_attack: 135,
+        special_defense: 171,
+        speed: 171,
+    };
+
+    #[test]
+    fn stats_apply_the_ivs_evs_and_nature() {
+        let base_stats = garchomp();
+        let adamant = adamant();
+        let calculator = StatCalculator::new(&base_stats, 78)
+            .with_ivs(IVS)
+            .with_evs(EVS)
+            .with_nature(&adamant);
+
+        assert_eq!(calculator.stats().unwrap(), STATS);
+        assert_eq!(calculator.stat(&StatKind::Attack).unwrap(), Some(278));
+        assert_eq!(adamant.stat_modifier(&StatKind::Attack), 1.1);
+        assert_eq!(adamant.stat_modifier(&StatKind::SpecialAttack), 0.9);
+        assert_eq!(adamant.stat_modifier(&StatKind::Speed), 1.0);
+    }
+
+    #[test]
+    fn fixed_hp_ignores_the_level() {
+        let shedinja = BaseStats::default().with_fixed_hp(1);
+        let stats = StatCalculator::new(&shedinja, 100)
+            .with_ivs(StatSet::splat(31))
+            .stats()
+            .unwrap();
+
+        assert_eq!(stats.hp, 1);
+    }
+
+    #[test]
+    fn invalid_inputs_are_rejected() {
+        let base_stats = garchomp();
+
+        assert!(matches!(
+            StatCalculator::new(&base_stats, 101).stats(),
+            Err(Error::OutOfRange { value: 101, .. })
+        ));
+        assert!(matches!(
+            StatCalculator::new(&base_stats, 50)
+                .with_ivs(StatSet::splat(32))
+                .stats(),
+            Err(Error::OutOfRange { value: 32, .. })
+        ));
+        assert!(matches!(
+            StatCalculator::new(&base_stats, 50)
+                .with_evs(StatSet::splat(100))
+                .stats(),
+            Err(Error::EffortTotalExceeded {
+                total: 600,
+                max: 510
+            })
+        ));
+    }
+
+    #[test]
+    fn classic_stats_derive_the_hp_dv() {
+        let ivs = StatSet {
+            attack: 15,
+            defense: 14,
+            special_attack: 13,
+            speed: 12,
+            ..StatSet::default()
+        };
+        let base_stats = garchomp();
+        let stats = StatCalculator::new(&base_stats, 100)
+            .with_ivs(ivs)
+            .classic_stats()
+            .unwrap();
+
+        // 0b1001: the Attack and Special DVs are odd.
+        assert_eq!(ivs.hp_dv(), 9);
+        assert_eq!(stats.hp, (108 + 9) * 2 + 100 + 10);
+        assert_eq!(stats.special_defense, (85 + 13) * 2 + 5);
+    }
+}
@@ -0,0 +1,502 @@
+//! Type coverage and weaknesses of a team of up to six Pokémon.
+//!
+//! A [`Team`] is analysed against a [`TypeChart`], which gives:
+//! - the defensive coverage: the multiplier of every attacking type against every member,
+//! - the offensive coverage: the best multiplier the team reaches against every defending type, with the types of
+//!   its chosen moves or, when none is chosen, with its own types,
+//! - the types threatening the team the most, and the types to add to the team to cover its gaps.
+//!
+//! ```rust
+//! use rustemon::mechanics::{
+//!     team::{Team, TeamMember},
+//!     type_chart::TypeChart,
+//! };
+//! # fn team(chart: TypeChart, charizard: rustemon::model::pokemon::Pokemon, gyarados: rustemon::model::pokemon::Pokemon, earthquake: rustemon::model::moves::Move) -> Result<(), rustemon::error::Error> {
+//!
+//! let team = Team::new([
+//!     TeamMember::from_pokemon(&charizard).with_move(&earthquake),
+//!     TeamMember::from_pokemon(&gyarados),
+//! ])?;
+//! let analysis = team.analyze(&chart);
+//! for weakness in analysis.shared_weaknesses() {
+//!     println!("{} hits {} members hard", weakness.attacker, weakness.weak);
+//! }
+//! println!("{:?}", analysis.uncovered());
+//! # Ok(())
+//! # }
+//! ```
+
+use super::type_chart::{TypeChart, Typed};
+use crate::{
+    error::Error,
+    model::{kinds::DamageClassKind, moves::Move, pokemon::Pokemon},
+};
+
+/// Maximum number of members of a [`Team`].
+pub const MAX_TEAM_SIZE: usize = 6;
+
+/// Move chosen for a [`TeamMember`], reduced to what matters to the coverage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TeamMove {
+    /// The name of the move.
+    pub name: String,
+    /// The name of the type of the move.
+    pub type_: String,
+    /// The damage class of the move, the status moves dealing no damage.
+    pub damage_class: DamageClassKind,
+}
+
+impl From<&Move> for TeamMove {
+    fn from(move_: &Move) -> Self {
+        Self {
+            name: move_.name.clone(),
+            type_: move_.type_.name.to_string(),
+            damage_class: move_.damage_class_kind(),
+        }
+    }
+}
+
+/// Pokémon of a [`Team`], along with its chosen moves.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TeamMember {
+    /// The name of the Pokémon.
+    pub name: String,
+    /// The names of the types of the Pokémon, in slot order.
+    pub types: Vec<String>,
+    /// The moves chosen for the Pokémon, if any.
+    pub moves: Vec<TeamMove>,
+}
+
+impl TeamMember {
+    /// Creates a member named `name`, having the types of the given model.
+    pub fn new(name: impl Into<String>, typed: &impl Typed) -> Self {
+        Self {
+            name: name.into(),
+            types: typed.type_names().into_iter().map(String::from).collect(),
+            moves: Vec::new(),
+        }
+    }
+
+    /// Creates a member from the given Pokémon, as it is now.
+    ///
+    /// Use [`Pokemon::as_of`] beforehand to analyse the team in a past generation.
+    pub fn from_pokemon(pokemon: &Pokemon) -> Self {
+        Self::new(&pokemon.name, pokemon)
+    }
+
+    /// Adds a chosen move.
+    pub fn with_move(mut self, move_: &Move) -> Self {
+        self.moves.push(TeamMove::from(move_));
+        self
+    }
+
+    /// Adds several chosen moves.
+    pub fn with_moves<'a>(mut self, moves: impl IntoIterator<Item = &'a Move>) -> Self {
+        self.moves.extend(moves.into_iter().map(TeamMove::from));
+        self
+    }
+
+    /// The types the member attacks with: those of its damaging moves, or its own types when no move is chosen.
+    pub fn attacking_types(&self) -> Vec<&str> {
+        if self.moves.is_empty() {
+            return self.types.iter().map(String::as_str).collect();
+        }
+
+        let mut types = Vec::new();
+        for move_ in &self.moves {
+            if move_.damage_class != DamageClassKind::Status && !types.contains(&&*move_.type_) {
+                types.push(&*move_.type_);
+            }
+        }
+
+        types
+    }
+
+    /// The names of the types of the member part of the given chart.
+    fn known_types(&self, chart: &TypeChart) -> Vec<&str> {
+        self.types
+            .iter()
+            .map(String::as_str)
+            .filter(|type_| chart.contains(type_))
+            .collect()
+    }
+}
+
+/// Party of up to [`MAX_TEAM_SIZE`] Pokémon.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Team {
+    members: Vec<TeamMember>,
+}
+
+impl Team {
+    /// Creates a team of the given members, failing with [`Error::TeamTooLarge`] if there are more than
+    /// [`MAX_TEAM_SIZE`] of them.
+    pub fn new(members: impl IntoIterator<Item = TeamMember>) -> Result<Self, Error> {
+        let members: Vec<TeamMember> = members.into_iter().collect();
+        if members.len() > MAX_TEAM_SIZE {
+            return Err(Error::TeamTooLarge {
+                size: members.len(),
+                max: MAX_TEAM_SIZE,
+            });
+        }
+
+        Ok(Self { members })
+    }
+
+    /// Creates a team of the given Pokémon, without chosen moves.
+    pub fn from_pokemon<'a>(pokemon: impl IntoIterator<Item = &'a Pokemon>) -> Result<Self, Error> {
+        Self::new(pokemon.into_iter().map(TeamMember::from_pokemon))
+    }
+
+    /// The members of the team.
+    pub fn members(&self) -> &[TeamMember] {
+        &self.members
+    }
+
+    /// The types the team attacks with, in the order of the members.
+    pub fn attacking_types(&self) -> Vec<&str> {
+        let mut types = Vec::new();
+        for type_ in self.members.iter().flat_map(TeamMember::attacking_types) {
+            if !types.contains(&type_) {
+                types.push(type_);
+            }
+        }
+
+        types
+    }
+
+    /// Analyses the coverage of the team against every type of the given chart.
+    ///
+    /// The types unknown to the chart are considered neutral, both in attack and in defense.
+    pub fn analyze(&self, chart: &TypeChart) -> TeamAnalysis {
+        let defensive = chart
+            .types()
+            .iter()
+            .map(|attacker| {
+                let multipliers: Vec<f64> = self
+                    .members
+                    .iter()
+                    .map(|member| {
+                        chart
+                            .effectiveness(attacker, &member.known_types(chart))
+                            .unwrap_or(1.0)
+                    })
+                    .collect();
+
+                DefensiveCoverage {
+                    attacker: attacker.clone(),
+                    weak: multipliers.iter().filter(|m| **m > 1.0).count(),
+                    resistant: multipliers
+                        .iter()
+                        .filter(|m| **m > 0.0 && **m < 1.0)
+                        .count(),
+                    immune: multipliers.iter().filter(|m| **m == 0.0).count(),
+                    multipliers,
+                }
+            })
+            .collect();
+
+        let attacking_types = self.attacking_types();
+        let offensive = chart
+            .types()
+            .iter()
+            .map(|defender| {
+                let mut coverage = OffensiveCoverage {
+                    defender: defender.clone(),
+                    best: 0.0,
+                    attackers: Vec::new(),
+                };
+                for attacker in &attacking_types {
+                    let multiplier = chart.multiplier(attacker, defender).unwrap_or(1.0);
+                    // The types the defender is immune to never cover it.
+                    if multiplier == 0.0 {
+                        continue;
+                    }
+                    if multiplier > coverage.best {
+                        coverage.best = multiplier;
+                        coverage.attackers.clear();
+                    }
+                    if multiplier == coverage.best {
+                        coverage.attackers.push(attacker.to_string());
+                    }
+                }
+
+                coverage
+            })
+            .collect();
+
+        let mut analysis = TeamAnalysis {
+            members: self
+                .members
+                .iter()
+                .map(|member| member.name.clone())
+                .collect(),
+            defensive,
+            offensive,
+            suggestions: Vec::new(),
+        };
+        analysis.suggestions = self.suggestions(chart, &analysis);
+
+        analysis
+    }
+
+    /// The types not held by the team resisting its threats or hitting its uncovered types, the most useful first.
+    fn suggestions(&self, chart: &TypeChart, analysis: &TeamAnalysis) -> Vec<Suggestion> {
+        let threats = analysis.threats();
+        let uncovered = analysis.uncovered();
+
+        let mut suggestions: Vec<Suggestion> = chart
+            .types()
+            .iter()
+            .filter(|type_| {
+                !self
+                    .members
+                    .iter()
+                    .any(|member| member.types.contains(type_))
+            })
+            .map(|type_| Suggestion {
+                type_: type_.clone(),
+                resists: threats
+                    .iter()
+                    .filter(|threat| {
+                        chart
+                            .multiplier(&threat.attacker, type_)
+                            .is_some_and(|m| m < 1.0)
+                    })
+                    .map(|threat| threat.attacker.clone())
+                    .collect(),
+                covers: uncovered
+                    .iter()
+                    .filter(|defender| chart.multiplier(type_, defender).is_some_and(|m| m > 1.0))
+                    .map(|defender| defender.to_string())
+                    .collect(),
+            })
+            .filter(|suggestion| !suggestion.resists.is_empty() || !suggestion.covers.is_empty())
+            .collect();
+        suggestions.sort_by_key(|suggestion| {
+            std::cmp::Reverse(suggestion.resists.len() + suggestion.covers.len())
+        });
+
+        suggestions
+    }
+}
+
+/// Multipliers of an attacking type against every member of a [`Team`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefensiveCoverage {
+    /// The name of the attacking type.
+    pub attacker: String,
+    /// The multiplier against every member, in the order of the members.
+    pub multipliers: Vec<f64>,
+    /// The number of members weak to the type.
+    pub weak: usize,
+    /// The number of members resisting the type, without the immune ones.
+    pub resistant: usize,
+    /// The number of members immune to the type.
+    pub immune: usize,
+}
+
+impl DefensiveCoverage {
+    /// How much the type threatens the team: the number of members weak to it, minus the ones resisting it or immune
+    /// to it.
+    pub fn threat(&self) -> i64 {
+        self.weak as i64 - (self.resistant + self.immune) as i64
+    }
+}
+
+/// Best multiplier a [`Team`] reaches against a defending type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OffensiveCoverage {
+    /// The name of the defending type.
+    pub defender: String,
+    /// The best multiplier, `0.0` if no attacking type of the team affects the defending type.
+    pub best: f64,
+    /// The attacking types of the team reaching the best multiplier.
+    pub attackers: Vec<String>,
+}
+
+/// Type to add to a [`Team`] to cover its gaps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    /// The name of the type.
+    pub type_: String,
+    /// The threats to the team the type resists or is immune to.
+    pub resists: Vec<String>,
+    /// The types uncovered by the team the type hits super effectively.
+    pub covers: Vec<String>,
+}
+
+/// Coverage of a [`Team`] against every type of a [`TypeChart`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TeamAnalysis {
+    /// The names of the members, in the order of the multipliers of the defensive coverage.
+    pub members: Vec<String>,
+    /// The defensive coverage, a row per attacking type.
+    pub defensive: Vec<DefensiveCoverage>,
+    /// The offensive coverage, a row per defending type.
+    pub offensive: Vec<OffensiveCoverage>,
+    /// The types to add to the team, the most useful first.
+    pub suggestions: Vec<Suggestion>,
+}
+
+impl TeamAnalysis {
+    /// The attacking types at least two members are weak to, and which more members are weak to than resist.
+    pub fn shared_weaknesses(&self) -> Vec<&DefensiveCoverage> {
+        self.defensive
+            .iter()
+            .filter(|coverage| coverage.weak >= 2 && coverage.threat() > 0)
+            .collect()
+    }
+
+    /// The defending types the team can't hit super effectively.
+    pub fn uncovered(&self) -> Vec<&str> {
+        self.offensive
+            .iter()
+            .filter(|coverage| coverage.best <= 1.0)
+            .map(|coverage| &*coverage.defender)
+            .collect()
+    }
+
+    /// The attacking types threatening the team, the most threatening first.
+    pub fn threats(&self) -> Vec<&DefensiveCoverage> {
+        let mut threats: Vec<_> = self
+            .defensive
+            .iter()
+            .filter(|coverage| coverage.threat() > 0)
+            .collect();
+        threats.sort_by_key(|coverage| std::cmp::Reverse((coverage.threat(), coverage.weak)));
+
+        threats
+    }
+
+    /// Exports the defensive coverage as a Markdown table, a row per attacking type and a column per member.
+    pub fn defensive_markdown(&self) -> String {
+        let mut markdown = String::from("| attacker |");
+        for member in &self.members {
+            markdown.push_str(&format!(" {member} |"));
+        }
+        markdown.push_str(" weak | resistant | immune |\n|---|");
+        markdown.push_str(&"---|".repeat(self.members.len() + 3));
+        markdown.push('\n');
+        for coverage in &self.defensive {
+            markdown.push_str(&format!("| **{}** |", coverage.attacker));
+            for multiplier in &coverage.multipliers {
+                markdown.push_str(&format!(" {multiplier} |"));
+            }
+            markdown.push_str(&format!(
+                " {} | {} | {} |\n",
+                coverage.weak, coverage.resistant, coverage.immune
+            ));
+        }
+
+        markdown
+    }
+
+    /// Exports the offensive coverage as a Markdown table, a row per defending type.
+    pub fn offensive_markdown(&self) -> String {
+        let mut markdown = String::from("| defender | best | attackers |\n|---|---|---|\n");
+        for coverage in &self.offensive {
+            markdown.push_str(&format!(
+                "| **{}** | {} | {} |\n",
+                coverage.defender,
+                coverage.best,
+                coverage.attackers.join(", ")
+            ));
+        }
+
+        markdown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mechanics::type_chart::tests::chart;
+
+    fn member(name: &str, types: &[&str]) -> TeamMember {
+        TeamMember {
+            name: name.to_string(),
+            types: types.iter().map(|type_| type_.to_string()).collect(),
+            moves: Vec::new(),
+        }
+    }
+
+    fn offensive<'a>(analysis: &'a TeamAnalysis, defender: &str) -> &'a OffensiveCoverage {
+        analysis
+            .offensive
+            .iter()
+            .find(|coverage| coverage.defender == defender)
+            .unwrap()
+    }
+
+    #[test]
+    fn teams_hold_at_most_six_members() {
+        let members = (0..7).map(|i| member(&format!("member-{i}"), &["normal"]));
+
+        assert!(matches!(
+            Team::new(members),
+            Err(Error::TeamTooLarge { size: 7, max: 6 })
+        ));
+    }
+
+    #[test]
+    fn immune_defenders_are_left_uncovered_without_attackers() {
+        let team = Team::new([member("snorlax", &["normal"])]).unwrap();
+        let analysis = team.analyze(&chart());
+
+        let ghost = offensive(&analysis, "ghost");
+        assert_eq!(ghost.best, 0.0);
+        assert!(ghost.attackers.is_empty());
+        assert_eq!(offensive(&analysis, "water").attackers, ["normal"]);
+        assert!(analysis.uncovered().contains(&"ghost"));
+    }
+
+    #[test]
+    fn best_attackers_are_kept_and_damaging_moves_replace_the_types() {
+        let earthquake = TeamMove {
+            name: "earthquake".to_string(),
+            type_: "ground".to_string(),
+            damage_class: DamageClassKind::Physical,
+        };
+        let dragon_dance = TeamMove {
+            name: "dragon-dance".to_string(),
+            type_: "dragon".to_string(),
+            damage_class: DamageClassKind::Status,
+        };
+        let mut garchomp = member("garchomp", &["dragon", "ground"]);
+        garchomp.moves = vec![earthquake, dragon_dance];
+        let team = Team::new([garchomp, member("lapras", &["water", "ice"])]).unwrap();
+        let analysis = team.analyze(&chart());
+
+        assert_eq!(team.attacking_types(), ["ground", "water", "ice"]);
+        let dragon = offensive(&analysis, "dragon");
+        assert_eq!(dragon.best, 2.0);
+        assert_eq!(dragon.attackers, ["ice"]);
+        let ground = offensive(&analysis, "ground");
+        assert_eq!(ground.attackers, ["water", "ice"]);
+    }
+
+    #[test]
+    fn shared_weaknesses_need_two_weak_members() {
+        let team = Team::new([
+            member("garchomp", &["dragon", "ground"]),
+            member("salamence", &["dragon", "flying"]),
+        ])
+        .unwrap();
+        let analysis = team.analyze(&chart());
+
+        let ice = analysis
+            .defensive
+            .iter()
+            .find(|coverage| coverage.attacker == "ice")
+            .unwrap();
+        assert_eq!(ice.multipliers, [4.0, 4.0]);
+        let shared: Vec<_> = analysis
+            .shared_weaknesses()
+            .iter()
+            .map(|coverage| &*coverage.attacker)
+            .collect();
+        assert_eq!(shared, ["ice", "dragon"]);
+        assert_eq!(analysis.threats()[0].attacker, "ice");
+    }
+}
@@ -0,0 +1,383 @@
+//! Effectiveness of the attacking types against the defending ones.
+//!
+//! A [`TypeChart`] is built from the [`Type`]s of `PokeApi`, either as they are now or as they were in a given
+//! [`Era`], and answers the damage multiplier of a type against one or two others.
+//!
+//! ```rust
+//! use rustemon::mechanics::type_chart::TypeChart;
+//! # async fn chart(rustemon_client: &rustemon::client::RustemonClient) -> Result<(), rustemon::error::Error> {
+//!
+//! let chart = TypeChart::fetch(rustemon_client).await?;
+//! assert_eq!(chart.effectiveness("ground", &["water", "flying"]), Some(0.0));
+//! assert_eq!(chart.effectiveness("ice", &["grass", "dragon"]), Some(4.0));
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    client::RustemonClient,
+    error::Error,
+    model::{
+        generational::Era,
+        pokemon::{Pokemon, PokemonForm, PokemonSummary, PokemonType, Type},
+        resource::NamedApiResource,
+    },
+};
+
+/// Model having one or two types, e.g. a [`Pokemon`].
+pub trait Typed {
+    /// The names of the types of the model, in slot order.
+    fn type_names(&self) -> Vec<&str>;
+}
+
+/// Returns the names of the given types, in slot order.
+fn type_names(types: &[PokemonType]) -> Vec<&str> {
+    let mut types: Vec<_> = types.iter().collect();
+    types.sort_by_key(|t| t.slot);
+
+    types.into_iter().map(|t| &*t.type_.name).collect()
+}
+
+impl Typed for Pokemon {
+    fn type_names(&self) -> Vec<&str> {
+        type_names(&self.types)
+    }
+}
+
+impl Typed for PokemonSummary {
+    fn type_names(&self) -> Vec<&str> {
+        type_names(&self.types)
+    }
+}
+
+impl Typed for PokemonForm {
+    fn type_names(&self) -> Vec<&str> {
+        let mut types: Vec<_> = self.types.iter().collect();
+        types.sort_by_key(|t| t.slot);
+
+        types.into_iter().map(|t| &*t.type_.name).collect()
+    }
+}
+
+/// Damage multipliers of every attacking type against every defending type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TypeChart {
+    types: Vec<String>,
+    /// The multipliers, by attacking type and then defending type, in the order of `types`.
+    multipliers: Vec<Vec<f64>>,
+}
+
+impl TypeChart {
+    /// Creates the chart of the given types as they are now.
+    ///
+    /// The types having no damage relation at all, e.g. `unknown` or `shadow`, are left out.
+    pub fn new<'a>(types: impl IntoIterator<Item = &'a Type>) -> Self {
+        let mut types: Vec<&Type> = types
+            .into_iter()
+            .filter(|t| {
+                let relations = &t.damage_relations;
+                [
+                    &relations.no_damage_to,
+                    &relations.half_damage_to,
+                    &relations.double_damage_to,
+                    &relations.no_damage_from,
+                    &relations.half_damage_from,
+                    &relations.double_damage_from,
+                ]
+                .iter()
+                .any(|relation| !relation.is_empty())
+            })
+            .collect();
+        types.sort_by_key(|t| t.id);
+
+        let names: Vec<String> = types.iter().map(|t| t.name.clone()).collect();
+        let multipliers = types
+            .iter()
+            .map(|attacker| {
+                let relations = &attacker.damage_relations;
+                let multiplier_of = |defender: &str| {
+                    let is_in = |relation: &[NamedApiResource<Type>]| {
+                        relation.iter().any(|t| t.name == defender)
+                    };
+                    if is_in(&relations.no_damage_to) {
+                        0.0
+                    } else if is_in(&relations.half_damage_to) {
+                        0.5
+                    } else if is_in(&relations.double_damage_to) {
+                        2.0
+                    } else {
+                        1.0
+                    }
+                };
+
+                names
+                    .iter()
+                    .map(|defender| multiplier_of(defender))
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            types: names,
+            multipliers,
+        }
+    }
+
+    /// Creates the chart of the given types as they were in the given era, leaving out the types not existing yet.
+    pub fn as_of<'a>(types: impl IntoIterator<Item = &'a Type>, era: Era<'_>) -> Self {
+        let types: Vec<Type> = types.into_iter().filter_map(|t| t.as_of(era)).collect();
+
+        Self::new(&types)
+    }
+
+    /// Pulls every type from `PokeApi` to create the current chart.
+    pub async fn fetch(rustemon_client: &RustemonClient) -> Result<Self, Error> {
+        Ok(Self::new(&fetch_types(rustemon_client).await?))
+    }
+
+    /// Pulls every type from `PokeApi` to create the chart of the given era.
+    pub async fn fetch_as_of(
+        rustemon_client: &RustemonClient,
+        era: Era<'_>,
+    ) -> Result<Self, Error> {
+        Ok(Self::as_of(&fetch_types(rustemon_client).await?, era))
+    }
+
+    /// The names of the types of the chart, by id.
+    pub fn types(&self) -> &[String] {
+        &self.types
+    }
+
+    /// Whether the given type is part of the chart.
+    pub fn contains(&self, type_: &str) -> bool {
+        self.index_of(type_).is_some()
+    }
+
+    /// The multiplier of the damage dealt by `attacker` to `defender`, if both are part of the chart.
+    pub fn multiplier(&self, attacker: &str, defender: &str) -> Option<f64> {
+        Some(self.multipliers[self.index_of(attacker)?][self.index_of(defender)?])
+    }
+
+    /// The multiplier of the damage dealt by `attacker` to a Pokémon having all the `defenders` types, if they are all
+    /// part of the chart.
+    pub fn effectiveness(&self, attacker: &str, defenders: &[&str]) -> Option<f64> {
+        defenders.iter().try_fold(1.0, |product, defender| {
+            Some(product * self.multiplier(attacker, defender)?)
+        })
+    }
+
+    /// The multiplier of the damage dealt by `attacker` to the given Pokémon, if its types are part of the chart.
+    pub fn effectiveness_against(&self, attacker: &str, defender: &impl Typed) -> Option<f64> {
+        self.effectiveness(attacker, &defender.type_names())
+    }
+
+    /// The weaknesses, resistances and immunities of a Pokémon having all the `defenders` types, against every type of
+    /// the chart.
+    pub fn matchups(&self, defenders: &[&str]) -> Matchups {
+        let mut matchups = Matchups::default();
+        for attacker in &self.types {
+            let Some(multiplier) = self.effectiveness(attacker, defenders) else {
+                continue;
+            };
+            if multiplier == 0.0 {
+                matchups.immunities.push(attacker.clone());
+            } else if multiplier < 1.0 {
+                matchups.resistances.push((attacker.clone(), multiplier));
+            } else if multiplier > 1.0 {
+                matchups.weaknesses.push((attacker.clone(), multiplier));
+            }
+        }
+
+        matchups
+    }
+
+    /// The weaknesses, resistances and immunities of the given Pokémon, against every type of the chart.
+    pub fn matchups_of(&self, defender: &impl Typed) -> Matchups {
+        self.matchups(&defender.type_names())
+    }
+
+    /// Exports the chart as CSV, a row per attacking type and a column per defending type.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("attacker");
+        for defender in &self.types {
+            csv.push(',');
+            csv.push_str(defender);
+        }
+        csv.push('\n');
+        for (attacker, multipliers) in self.types.iter().zip(&self.multipliers) {
+            csv.push_str(attacker);
+            for multiplier in multipliers {
+                csv.push(',');
+                csv.push_str(&multiplier.to_string());
+            }
+            csv.push('\n');
+        }
+
+        csv
+    }
+
+    /// Exports the chart as a Markdown table, a row per attacking type and a column per defending type.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::from("| attacker \\ defender |");
+        for defender in &self.types {
+            markdown.push_str(&format!(" {defender} |"));
+        }
+        markdown.push_str("\n|---|");
+        markdown.push_str(&"---|".repeat(self.types.len()));
+        markdown.push('\n');
+        for (attacker, multipliers) in self.types.iter().zip(&self.multipliers) {
+            markdown.push_str(&format!("| **{attacker}** |"));
+            for multiplier in multipliers {
+                markdown.push_str(&format!(" {multiplier} |"));
+            }
+            markdown.push('\n');
+        }
+
+        markdown
+    }
+
+    /// The index of the given type in the chart, if part of it.
+    fn index_of(&self, type_: &str) -> Option<usize> {
+        self.types.iter().position(|t| t == type_)
+    }
+}
+
+/// Weaknesses, resistances and immunities of a Pokémon, along with their multiplier.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Matchups {
+    /// The types dealing more damage than usual, e.g. `("ice", 4.0)`.
+    pub weaknesses: Vec<(String, f64)>,
+    /// The types dealing less damage than usual, e.g. `("fire", 0.25)`.
+    pub resistances: Vec<(String, f64)>,
+    /// The types dealing no damage.
+    pub immunities: Vec<String>,
+}
+
+/// Pulls every type from `PokeApi`.
+async fn fetch_types(rustemon_client: &RustemonClient) -> Result<Vec<Type>, Error> {
+    let mut types = Vec::new();
+    for entry in crate::pokemon::type_::get_all_entries(rustemon_client).await? {
+        types.push(crate::pokemon::type_::get_by_name(&entry.name, rustemon_client).await?);
+    }
+
+    Ok(types)
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    /// A type dealing no, half or double damage to the given types.
+    fn type_(id: i64, name: &str, no: &[&str], half: &[&str], double: &[&str]) -> Type {
+        let references = |names: &[&str]| {
+            names
+                .iter()
+                .map(|name| json!({ "name": name, "url": format!("https://pokeapi.co/api/v2/type/{name}/") }))
+                .collect::<Vec<_>>()
+        };
+
+        serde_json::from_value(json!({
+            "id": id,
+            "name": name,
+            "damage_relations": {
+                "no_damage_to": references(no),
+                "half_damage_to": references(half),
+                "double_damage_to": references(double),
+                "no_damage_from": [],
+                "half_damage_from": [],
+                "double_damage_from": [],
+            },
+            "past_damage_relations": [],
+            "game_indices": [],
+            "generation": { "name": "generation-i", "url": "https://pokeapi.co/api/v2/generation/1/" },
+            "move_damage_class": null,
+            "names": [],
+            "pokemon": [],
+            "moves": [],
+        }))
+        .unwrap()
+    }
+
+    /// The chart of a few types, with their current relations between each other.
+    pub(in crate::mechanics) fn chart() -> TypeChart {
+        TypeChart::new(&[
+            type_(16, "dragon", &[], &[], &["dragon"]),
+            type_(1, "normal", &["ghost"], &[], &[]),
+            type_(3, "flying", &[], &[], &["grass"]),
+            type_(5, "ground", &["flying"], &["grass"], &[]),
+            type_(8, "ghost", &["normal"], &[], &["ghost"]),
+            type_(11, "water", &[], &["water", "grass", "dragon"], &["ground"]),
+            type_(
+                12,
+                "grass",
+                &[],
+                &["flying", "grass", "dragon"],
+                &["water", "ground"],
+            ),
+            type_(
+                15,
+                "ice",
+                &[],
+                &["water", "ice"],
+                &["flying", "ground", "grass", "dragon"],
+            ),
+            type_(10001, "unknown", &[], &[], &[]),
+        ])
+    }
+
+    #[test]
+    fn types_are_sorted_by_id_without_the_ones_lacking_relations() {
+        let chart = chart();
+
+        assert_eq!(
+            chart.types(),
+            [
+                "normal", "flying", "ground", "ghost", "water", "grass", "ice", "dragon"
+            ]
+        );
+        assert!(!chart.contains("unknown"));
+    }
+
+    #[test]
+    fn multipliers_of_dual_types_are_multiplied() {
+        let chart = chart();
+
+        assert_eq!(
+            chart.effectiveness("ground", &["water", "flying"]),
+            Some(0.0)
+        );
+        assert_eq!(chart.effectiveness("ice", &["grass", "dragon"]), Some(4.0));
+        assert_eq!(
+            chart.effectiveness("water", &["grass", "dragon"]),
+            Some(0.25)
+        );
+        assert_eq!(chart.effectiveness("normal", &["water"]), Some(1.0));
+        assert_eq!(chart.effectiveness("ice", &["unknown"]), None);
+    }
+
+    #[test]
+    fn matchups_are_split_by_multiplier() {
+        let matchups = chart().matchups(&["grass", "dragon"]);
+
+        assert_eq!(
+            matchups.weaknesses,
+            [
+                ("flying".to_string(), 2.0),
+                ("ice".to_string(), 4.0),
+                ("dragon".to_string(), 2.0)
+            ]
+        );
+        assert_eq!(
+            matchups.resistances,
+            [
+                ("ground".to_string(), 0.5),
+                ("water".to_string(), 0.25),
+                ("grass".to_string(), 0.25)
+            ]
+        );
+        assert!(matchups.immunities.is_empty());
+        assert_eq!(chart().matchups(&["ghost"]).immunities, ["normal"]);
+    }
+}
@@ -10,6 +10,13 @@ use super::{
 /// [Berry official documentation](https:///pokeapi.co/docs/v2#berry)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct Berry {
     /// The identifier for this resource.
     pub id: i64,
@@ -38,21 +45,51 @@ pub struct Berry {
     pub item: NamedApiResource<Item>,
     /// The type inherited by "Natural Gift" when used with this Berry.
     pub natural_gift_type: NamedApiResource<Type>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [BerryFlavorMap official documentation](https:///pokeapi.co/docs/v2#berryflavormap)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct BerryFlavorMap {
     /// How powerful the referenced flavor is for this berry.
     pub potency: i64,
     /// The referenced berry flavor.
     pub flavor: NamedApiResource<BerryFlavor>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [BerryFirmness official documentation](https:///pokeapi.co/docs/v2#berryfirmness)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct BerryFirmness {
     /// The identifier for this resource.
     pub id: i64,
@@ -62,11 +99,26 @@ pub struct BerryFirmness {
     pub berries: Vec<NamedApiResource<Berry>>,
     /// The name of this resource listed in different languages.
     pub names: Vec<Name>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [BerryFlavor official documentation](https:///pokeapi.co/docs/v2#berryflavor)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct BerryFlavor {
     /// The identifier for this resource.
     pub id: i64,
@@ -78,14 +130,37 @@ pub struct BerryFlavor {
     pub contest_type: NamedApiResource<ContestType>,
     /// The name of this resource listed in different languages.
     pub names: Vec<Name>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [FlavorBerryMap official documentation](https:///pokeapi.co/docs/v2#flavorberrymap)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct FlavorBerryMap {
     /// How powerful the referenced flavor is for this berry.
     pub potency: i64,
     /// The berry with the referenced flavor.
     pub berry: NamedApiResource<Berry>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
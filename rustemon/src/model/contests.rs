@@ -10,6 +10,13 @@ use super::{
 /// [`ContestType` official documentation] (<https:///pokeapi.co/docs/v2#contesttype>)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct ContestType {
     /// The identifier for this resource.
     pub id: i64,
@@ -19,11 +26,26 @@ pub struct ContestType {
     pub berry_flavor: NamedApiResource<BerryFlavor>,
     /// The name of this contest type listed in different languages.
     pub names: Vec<ContestName>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [ContestName official documentation](https:///pokeapi.co/docs/v2#contestname)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct ContestName {
     /// The name for this contest.
     pub name: Option<String>,
@@ -31,11 +53,26 @@ pub struct ContestName {
     pub color: Option<String>,
     /// The language that this name is in.
     pub language: Option<NamedApiResource<Language>>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [ContestEffect official documentation](https:///pokeapi.co/docs/v2#contesteffect)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct ContestEffect {
     /// The identifier for this resource.
     pub id: i64,
@@ -47,11 +84,26 @@ pub struct ContestEffect {
     pub effect_entries: Vec<Effect>,
     /// The flavor text of this contest effect listed in different languages.
     pub flavor_text_entries: Vec<FlavorText>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [SuperContestEffect official documentation](https:///pokeapi.co/docs/v2#supercontesteffect)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct SuperContestEffect {
     /// The identifier for this resource.
     pub id: i64,
@@ -61,4 +113,12 @@ pub struct SuperContestEffect {
     pub flavor_text_entries: Vec<FlavorText>,
     /// A list of moves that have the effect when used in super contests.
     pub moves: Vec<NamedApiResource<Move>>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
@@ -5,6 +5,13 @@ use super::resource::{Name, NamedApiResource};
 /// [EncounterMethod official documentation](https:///pokeapi.co/docs/v2#encountermethod)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct EncounterMethod {
     /// The identifier for this resource.
     pub id: i64,
@@ -14,11 +21,26 @@ pub struct EncounterMethod {
     pub order: i64,
     /// The name of this resource listed in different languages.
     pub names: Vec<Name>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [EncounterCondition official documentation](https:///pokeapi.co/docs/v2#encountercondition)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct EncounterCondition {
     /// The identifier for this resource.
     pub id: i64,
@@ -28,11 +50,26 @@ pub struct EncounterCondition {
     pub names: Vec<Name>,
     /// A list of possible values for this encounter condition.
     pub values: Vec<NamedApiResource<EncounterConditionValue>>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [EncounterConditionValue](https:///pokeapi.co/docs/v2#encounterconditionvalue)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct EncounterConditionValue {
     /// The identifier for this resource.
     pub id: i64,
@@ -42,4 +79,12 @@ pub struct EncounterConditionValue {
     pub condition: NamedApiResource<EncounterCondition>,
     /// The name of this resource listed in different languages.
     pub names: Vec<Name>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
@@ -12,6 +12,13 @@ use super::{
 /// [EvolutionChain official documentation](https:///pokeapi.co/docs/v2#evolutionchain)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct EvolutionChain {
     /// The identifier for this resource.
     pub id: i64,
@@ -21,11 +28,26 @@ pub struct EvolutionChain {
     /// The base chain link object. Each link contains evolution details for a Pokémon in the chain.
     /// Each link references the next Pokémon in the natural evolution order.
     pub chain: ChainLink,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [ChainLink official documentation](https:///pokeapi.co/docs/v2#chainlink)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct ChainLink {
     /// Whether or not this link is for a baby Pokémon. This would only ever be true on the base link.
     pub is_baby: bool,
@@ -35,11 +57,26 @@ pub struct ChainLink {
     pub evolution_details: Vec<EvolutionDetail>,
     /// A List of chain objects.
     pub evolves_to: Vec<ChainLink>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [EvolutionDetail official documentation](https:///pokeapi.co/docs/v2#evolutiondetail)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct EvolutionDetail {
     /// The version group in which the evolution was introduced.
     pub version_group: NamedApiResource<VersionGroup>,
@@ -110,11 +147,26 @@ pub struct EvolutionDetail {
     /// The minimum amount of damage taken during the evolution trigger event in order to
     /// evolve into this Pokémon species.
     pub min_damage_taken: Option<i64>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [EvolutionTrigger official documentation](https:///pokeapi.co/docs/v2#evolutiontrigger)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct EvolutionTrigger {
     /// The identifier for this resource.
     pub id: i64,
@@ -124,4 +176,12 @@ pub struct EvolutionTrigger {
     pub names: Vec<Name>,
     /// A list of pokemon species that result from this evolution trigger.
     pub pokemon_species: Vec<NamedApiResource<PokemonSpecies>>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
@@ -0,0 +1,428 @@
+//! Fields of the payloads unknown to the models, preserved for lossless round-tripping.
+//!
+//! Every model holds the fields of its payload it doesn't know in its `extra` field. They are only kept, and
+//! serialized back along with the known ones, with the `preserve-unknown` feature: without it, `extra` is always empty.
+
+use serde_json::{Map, Value};
+
+/// Fields of a payload unknown to its model.
+pub type Extra = Map<String, Value>;
+
+#[cfg(feature = "preserve-unknown")]
+pub use self::unknown::unknown_keys;
+
+#[cfg(feature = "preserve-unknown")]
+pub(crate) use self::unknown::serialize;
+
+#[cfg(feature = "preserve-unknown")]
+mod unknown {
+    use serde::{
+        Serialize, Serializer,
+        ser::{
+            SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+            SerializeTupleStruct, SerializeTupleVariant,
+        },
+    };
+    use serde_json::Value;
+
+    use super::Extra;
+
+    /// Name of the newtype wrapping the values of the unknown fields while serializing, telling them apart from
+    /// the known ones. Serializers treat newtypes as the value they wrap, leaving the output untouched.
+    const UNKNOWN: &str = "$rustemon::model::extra::Unknown";
+
+    /// Value of an unknown field.
+    struct Unknown<'a>(&'a Value);
+
+    impl Serialize for Unknown<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_newtype_struct(UNKNOWN, self.0)
+        }
+    }
+
+    /// Serializes `extra`, marking its values as unknown.
+    pub(crate) fn serialize<S: Serializer>(
+        extra: &Extra,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(extra.iter().map(|(key, value)| (key, Unknown(value))))
+    }
+
+    /// Returns the paths of the unknown fields held by `model` and by the models it holds,
+    /// e.g. `chain.evolves_to[0].new_field`.
+    pub fn unknown_keys<T: Serialize>(model: &T) -> Vec<String> {
+        let mut keys = Vec::new();
+        model
+            .serialize(Keys {
+                path: String::new(),
+                keys: &mut keys,
+            })
+            .expect("models always serialize");
+        keys
+    }
+
+    /// Path of `key` within `path`.
+    fn join(path: &str, key: &str) -> String {
+        if path.is_empty() {
+            key.to_owned()
+        } else {
+            format!("{path}.{key}")
+        }
+    }
+
+    /// Serializer pushing into `keys` the paths of the unknown fields, `path` being the path of the value serialized.
+    struct Keys<'a> {
+        path: String,
+        keys: &'a mut Vec<String>,
+    }
+
+    impl<'a> Keys<'a> {
+        /// Serializes `value`, found at `path`.
+        fn at<T: ?Sized + Serialize>(
+            &mut self,
+            path: String,
+            value: &T,
+        ) -> Result<(), serde_json::Error> {
+            value.serialize(Keys {
+                path,
+                keys: self.keys,
+            })
+        }
+
+        /// Serializer of the elements of a sequence.
+        fn elements(self) -> Elements<'a> {
+            Elements {
+                keys: self,
+                index: 0,
+            }
+        }
+
+        /// Serializer of the entries of a map.
+        fn entries(self) -> Entries<'a> {
+            Entries {
+                keys: self,
+                key: String::new(),
+            }
+        }
+    }
+
+    impl<'a> Serializer for Keys<'a> {
+        type Ok = ();
+        type Error = serde_json::Error;
+        type SerializeSeq = Elements<'a>;
+        type SerializeTuple = Elements<'a>;
+        type SerializeTupleStruct = Elements<'a>;
+        type SerializeTupleVariant = Elements<'a>;
+        type SerializeMap = Entries<'a>;
+        type SerializeStruct = Entries<'a>;
+        type SerializeStructVariant = Entries<'a>;
+
+        fn serialize_bool(self, _: bool) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn serialize_i64(self, _: i64) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn serialize_i128(self, _: i128) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn serialize_u64(self, _: u64) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn serialize_u128(self, _: u128) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn serialize_i8(self, _: i8) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn serialize_i16(self, _: i16) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn serialize_i32(self, _: i32) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn serialize_u8(self, _: u8) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn serialize_u16(self, _: u16) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn serialize_u32(self, _: u32) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn serialize_f32(self, _: f32) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn serialize_f64(self, _: f64) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn serialize_char(self, _: char) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn serialize_str(self, _: &str) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn serialize_bytes(self, _: &[u8]) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn serialize_none(self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<(), Self::Error> {
+            value.serialize(self)
+        }
+
+        fn serialize_unit(self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn serialize_unit_struct(self, _: &'static str) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn serialize_unit_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+        ) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn serialize_newtype_struct<T: ?Sized + Serialize>(
+            self,
+            name: &'static str,
+            value: &T,
+        ) -> Result<(), Self::Error> {
+            if name == UNKNOWN {
+                self.keys.push(self.path);
+                Ok(())
+            } else {
+                value.serialize(self)
+            }
+        }
+
+        fn serialize_newtype_variant<T: ?Sized + Serialize>(
+            mut self,
+            _: &'static str,
+            _: u32,
+            variant: &'static str,
+            value: &T,
+        ) -> Result<(), Self::Error> {
+            let path = join(&self.path, variant);
+            self.at(path, value)
+        }
+
+        fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+            Ok(self.elements())
+        }
+
+        fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+            Ok(self.elements())
+        }
+
+        fn serialize_tuple_struct(
+            self,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+            Ok(self.elements())
+        }
+
+        fn serialize_tuple_variant(
+            mut self,
+            _: &'static str,
+            _: u32,
+            variant: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+            self.path = join(&self.path, variant);
+            Ok(self.elements())
+        }
+
+        fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+            Ok(self.entries())
+        }
+
+        fn serialize_struct(
+            self,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeStruct, Self::Error> {
+            Ok(self.entries())
+        }
+
+        fn serialize_struct_variant(
+            mut self,
+            _: &'static str,
+            _: u32,
+            variant: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeStructVariant, Self::Error> {
+            self.path = join(&self.path, variant);
+            Ok(self.entries())
+        }
+    }
+
+    /// Serializer of the elements of a sequence, `index` being the index of the next one.
+    struct Elements<'a> {
+        keys: Keys<'a>,
+        index: usize,
+    }
+
+    impl Elements<'_> {
+        fn element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), serde_json::Error> {
+            let path = format!("{}[{}]", self.keys.path, self.index);
+            self.index += 1;
+            self.keys.at(path, value)
+        }
+    }
+
+    impl SerializeSeq for Elements<'_> {
+        type Ok = ();
+        type Error = serde_json::Error;
+
+        fn serialize_element<T: ?Sized + Serialize>(
+            &mut self,
+            value: &T,
+        ) -> Result<(), Self::Error> {
+            self.element(value)
+        }
+
+        fn end(self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    impl SerializeTuple for Elements<'_> {
+        type Ok = ();
+        type Error = serde_json::Error;
+
+        fn serialize_element<T: ?Sized + Serialize>(
+            &mut self,
+            value: &T,
+        ) -> Result<(), Self::Error> {
+            self.element(value)
+        }
+
+        fn end(self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    impl SerializeTupleStruct for Elements<'_> {
+        type Ok = ();
+        type Error = serde_json::Error;
+
+        fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+            self.element(value)
+        }
+
+        fn end(self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    impl SerializeTupleVariant for Elements<'_> {
+        type Ok = ();
+        type Error = serde_json::Error;
+
+        fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+            self.element(value)
+        }
+
+        fn end(self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    /// Serializer of the entries of a map, `key` being the key of the entry whose value is serialized next.
+    struct Entries<'a> {
+        keys: Keys<'a>,
+        key: String,
+    }
+
+    impl Entries<'_> {
+        fn field<T: ?Sized + Serialize>(
+            &mut self,
+            key: &str,
+            value: &T,
+        ) -> Result<(), serde_json::Error> {
+            let path = join(&self.keys.path, key);
+            self.keys.at(path, value)
+        }
+    }
+
+    impl SerializeMap for Entries<'_> {
+        type Ok = ();
+        type Error = serde_json::Error;
+
+        fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
+            self.key = match serde_json::to_value(key)? {
+                Value::String(key) => key,
+                key => key.to_string(),
+            };
+            Ok(())
+        }
+
+        fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+            let key = std::mem::take(&mut self.key);
+            self.field(&key, value)
+        }
+
+        fn end(self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    impl SerializeStruct for Entries<'_> {
+        type Ok = ();
+        type Error = serde_json::Error;
+
+        fn serialize_field<T: ?Sized + Serialize>(
+            &mut self,
+            key: &'static str,
+            value: &T,
+        ) -> Result<(), Self::Error> {
+            self.field(key, value)
+        }
+
+        fn end(self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    impl SerializeStructVariant for Entries<'_> {
+        type Ok = ();
+        type Error = serde_json::Error;
+
+        fn serialize_field<T: ?Sized + Serialize>(
+            &mut self,
+            key: &'static str,
+            value: &T,
+        ) -> Result<(), Self::Error> {
+            self.field(key, value)
+        }
+
+        fn end(self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+}
@@ -10,6 +10,13 @@ use super::{
 /// [Generation official documentation]
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct Generation {
     /// The identifier for this resource.
     pub id: i64,
@@ -29,11 +36,26 @@ pub struct Generation {
     pub types: Vec<NamedApiResource<Type>>,
     /// A list of version groups that were introduced in this generation.
     pub version_groups: Vec<NamedApiResource<VersionGroup>>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [Pokedex official documentation](https:///pokeapi.co/docs/v2#pokedex)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct Pokedex {
     /// The identifier for this resource.
     pub id: i64,
@@ -51,21 +73,51 @@ pub struct Pokedex {
     pub region: Option<NamedApiResource<Region>>,
     /// A list of version groups this Pokédex is relevant to.
     pub version_groups: Vec<NamedApiResource<VersionGroup>>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [PokemonEntry official documentation](https:///pokeapi.co/docs/v2#pokemonentry)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct PokemonEntry {
     /// The index of this Pokémon species entry within the Pokédex.
     pub entry_number: i64,
     /// The Pokémon species being encountered.
     pub pokemon_species: NamedApiResource<PokemonSpecies>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [Version offcial documentation](https:///pokeapi.co/docs/v2#version)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct Version {
     /// The identifier for this resource.
     pub id: i64,
@@ -75,11 +127,26 @@ pub struct Version {
     pub names: Vec<Name>,
     /// The version group this version belongs to.
     pub version_group: NamedApiResource<VersionGroup>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [VersionGroup official documentation](https:///pokeapi.co/docs/v2#versiongroup)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct VersionGroup {
     /// The identifier for this resource.
     pub id: i64,
@@ -97,4 +164,12 @@ pub struct VersionGroup {
     pub regions: Vec<NamedApiResource<Region>>,
     /// The versions this version group owns.
     pub versions: Vec<NamedApiResource<Version>>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
@@ -0,0 +1,362 @@
+//! Views of the models as they were in a given generation or version group.
+//!
+//! `PokeApi` returns the current data of the resources, along with their history, e.g. [`Pokemon::past_types`] or
+//! [`Move::past_values`]. The models are projected into an [`Era`], obtained from a [`Chronology`] of the version
+//! groups, by applying that history:
+//! - the past entries tagged with a generation, e.g. [`PokemonTypePast`](super::pokemon::PokemonTypePast), apply up to
+//!   and including that generation,
+//! - the past entries tagged with a version group, e.g. [`PastMoveStatValues`], apply before that version group, in which
+//!   the values changed.
+//!
+//! ```rust
+//! use rustemon::model::generational::Chronology;
+//! # fn clefairy(clefairy: rustemon::model::pokemon::Pokemon, version_groups: Vec<rustemon::model::games::VersionGroup>) {
+//!
+//! let chronology = Chronology::new(&version_groups);
+//! // Clefairy was Normal type up to generation 5.
+//! let clefairy = clefairy.as_of(chronology.at_generation(5));
+//! # }
+//! ```
+
+use super::{
+    games::{Generation, VersionGroup},
+    moves::{Move, PastMoveStatValues},
+    pokemon::{Ability, AbilityEffectChange, Pokemon, PokemonAbility, Type},
+    resource::{NamedApiResource, VerboseEffect},
+};
+
+/// Generation in which the abilities were introduced.
+const ABILITIES_GENERATION: i64 = 3;
+
+/// Generation in which the hidden abilities were introduced.
+const HIDDEN_ABILITIES_GENERATION: i64 = 5;
+
+/// Version group known to a [`Chronology`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ChronologyEntry {
+    name: String,
+    order: i64,
+    generation: i64,
+}
+
+/// Order and generation of the version groups, e.g. from the [`VersionGroup`]s of `PokeApi`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Chronology {
+    version_groups: Vec<ChronologyEntry>,
+}
+
+impl Chronology {
+    /// Creates the chronology of the given version groups.
+    pub fn new<'a>(version_groups: impl IntoIterator<Item = &'a VersionGroup>) -> Self {
+        version_groups
+            .into_iter()
+            .fold(
+                Self::default(),
+                |chronology, version_group| match version_group.generation.id() {
+                    Some(generation) => chronology.with_version_group(
+                        &version_group.name,
+                        version_group.order,
+                        generation,
+                    ),
+                    None => chronology,
+                },
+            )
+    }
+
+    /// Adds a version group, along with its order and the number of its generation.
+    pub fn with_version_group(
+        mut self,
+        name: impl Into<String>,
+        order: i64,
+        generation: i64,
+    ) -> Self {
+        self.version_groups.push(ChronologyEntry {
+            name: name.into(),
+            order,
+            generation,
+        });
+        self
+    }
+
+    /// The order of the given version group, if known.
+    pub fn order_of(&self, version_group: &str) -> Option<i64> {
+        self.entry(version_group).map(|entry| entry.order)
+    }
+
+    /// The number of the generation of the given version group, if known.
+    pub fn generation_of(&self, version_group: &str) -> Option<i64> {
+        self.entry(version_group).map(|entry| entry.generation)
+    }
+
+    /// The era of the given generation, e.g. `5`, as of its latest version group.
+    pub fn at_generation(&self, generation: i64) -> Era<'_> {
+        Era {
+            chronology: self,
+            generation,
+            order: self
+                .version_groups
+                .iter()
+                .filter(|entry| entry.generation == generation)
+                .map(|entry| entry.order)
+                .max(),
+        }
+    }
+
+    /// The era of the given version group, e.g. `black-white`, if known.
+    pub fn at_version_group(&self, version_group: &str) -> Option<Era<'_>> {
+        let entry = self.entry(version_group)?;
+
+        Some(Era {
+            chronology: self,
+            generation: entry.generation,
+            order: Some(entry.order),
+        })
+    }
+
+    /// The entry of the given version group, if known.
+    fn entry(&self, version_group: &str) -> Option<&ChronologyEntry> {
+        self.version_groups
+            .iter()
+            .find(|entry| entry.name == version_group)
+    }
+}
+
+/// Generation or version group the models are projected into, created by a [`Chronology`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Era<'a> {
+    chronology: &'a Chronology,
+    generation: i64,
+    order: Option<i64>,
+}
+
+impl Era<'_> {
+    /// The number of the generation of the era.
+    pub fn generation(&self) -> i64 {
+        self.generation
+    }
+
+    /// Whether the resource introduced in the given generation exists in the era.
+    pub fn has(&self, generation: &NamedApiResource<Generation>) -> bool {
+        generation.id().is_none_or(|id| id <= self.generation)
+    }
+
+    /// Whether the entry tagged with the given generation, up to and including which it applies, applies to the era.
+    fn is_up_to(&self, generation: &NamedApiResource<Generation>) -> bool {
+        generation.id().is_some_and(|id| self.generation <= id)
+    }
+
+    /// The order of the version group of an entry applying before it to the era, if it does.
+    ///
+    /// The entries of the version groups unknown to the chronology never apply.
+    fn changed_after(&self, version_group: &NamedApiResource<VersionGroup>) -> Option<i64> {
+        let changed_in = self.chronology.entry(&version_group.name)?;
+        let is_before = match self.order {
+            Some(order) => order < changed_in.order,
+            None => self.generation < changed_in.generation,
+        };
+
+        is_before.then_some(changed_in.order)
+    }
+}
+
+/// Returns the past entries tagged with a generation applying to the era, from the closest to the farthest.
+fn entries_up_to<'a, E>(
+    entries: &'a [E],
+    era: Era<'_>,
+    generation: impl Fn(&E) -> &NamedApiResource<Generation>,
+) -> Vec<&'a E> {
+    let mut applying: Vec<_> = entries
+        .iter()
+        .filter(|entry| era.is_up_to(generation(entry)))
+        .collect();
+    applying.sort_by_key(|entry| generation(entry).id());
+
+    applying
+}
+
+/// Returns the past entries tagged with a version group applying to the era, from the closest to the farthest.
+fn entries_before<'a, E>(
+    entries: &'a [E],
+    era: Era<'_>,
+    version_group: impl Fn(&E) -> &NamedApiResource<VersionGroup>,
+) -> Vec<&'a E> {
+    let mut applying: Vec<_> = entries
+        .iter()
+        .filter_map(|entry| Some((era.changed_after(version_group(entry))?, entry)))
+        .collect();
+    applying.sort_by_key(|(order, _)| *order);
+
+    applying.into_iter().map(|(_, entry)| entry).collect()
+}
+
+/// Returns the previous effect of the first change applying to the era, with the order of its version group.
+fn effect_change<'a>(
+    changes: &'a [AbilityEffectChange],
+    era: Era<'_>,
+) -> Option<(i64, &'a AbilityEffectChange)> {
+    changes
+        .iter()
+        .filter_map(|change| Some((era.changed_after(&change.version_group)?, change)))
+        .min_by_key(|(order, _)| *order)
+}
+
+/// Converts the previous effect of a change into verbose effects, the short effect being the effect itself.
+fn verbose_effects(change: &AbilityEffectChange) -> Vec<VerboseEffect> {
+    change
+        .effect_entries
+        .iter()
+        .map(|effect| VerboseEffect {
+            effect: effect.effect.clone(),
+            short_effect: effect.effect.clone(),
+            language: effect.language.clone(),
+            extra: Default::default(),
+        })
+        .collect()
+}
+
+impl Pokemon {
+    /// The Pokémon as it was in the given era: its types, abilities and stats are replaced by the past ones applying
+    /// to it, and the past entries are cleared.
+    ///
+    /// The abilities are removed before generation 3, which introduced them, and the hidden ones before generation 5.
+    pub fn as_of(&self, era: Era<'_>) -> Pokemon {
+        let mut pokemon = self.clone();
+
+        if let Some(past) = entries_up_to(&self.past_types, era, |past| &past.generation).first() {
+            pokemon.types.clone_from(&past.types);
+        }
+
+        let past_abilities = entries_up_to(&self.past_abilities, era, |past| &past.generation);
+        let mut slots: Vec<i64> = self.abilities.iter().map(|ability| ability.slot).collect();
+        for past in &past_abilities {
+            slots.extend(past.abilities.iter().map(|ability| ability.slot));
+        }
+        slots.sort_unstable();
+        slots.dedup();
+        pokemon.abilities = slots
+            .into_iter()
+            .filter_map(|slot| {
+                let in_slot = |abilities: &[PokemonAbility]| {
+                    abilities
+                        .iter()
+                        .find(|ability| ability.slot == slot)
+                        .cloned()
+                };
+                match past_abilities
+                    .iter()
+                    .find_map(|past| in_slot(&past.abilities))
+                {
+                    Some(past) => past.ability.is_some().then_some(past),
+                    None => in_slot(&self.abilities),
+                }
+            })
+            .filter(|ability| {
+                era.generation >= ABILITIES_GENERATION
+                    && (!ability.is_hidden || era.generation >= HIDDEN_ABILITIES_GENERATION)
+            })
+            .collect();
+
+        let past_stats = entries_up_to(&self.past_stats, era, |past| &past.generation);
+        for stat in &mut pokemon.stats {
+            let past = past_stats.iter().find_map(|past| {
+                past.stats
+                    .iter()
+                    .find(|past| past.stat.name == stat.stat.name)
+            });
+            if let Some(past) = past {
+                stat.clone_from(past);
+            }
+        }
+
+        pokemon.past_types.clear();
+        pokemon.past_abilities.clear();
+        pokemon.past_stats.clear();
+
+        pokemon
+    }
+}
+
+impl Move {
+    /// The move as it was in the given era, if it existed: its accuracy, effect chance, power, PP, type and effect are
+    /// replaced by the past ones applying to it, and the past entries are cleared.
+    pub fn as_of(&self, era: Era<'_>) -> Option<Move> {
+        if !era.has(&self.generation) {
+            return None;
+        }
+        let mut move_ = self.clone();
+
+        let past_values: Vec<&PastMoveStatValues> =
+            entries_before(&self.past_values, era, |past| &past.version_group);
+        if let Some(accuracy) = past_values.iter().find_map(|past| past.accuracy) {
+            move_.accuracy = Some(accuracy);
+        }
+        if let Some(effect_chance) = past_values.iter().find_map(|past| past.effect_chance) {
+            move_.effect_chance = Some(effect_chance);
+        }
+        if let Some(power) = past_values.iter().find_map(|past| past.power) {
+            move_.power = Some(power);
+        }
+        if let Some(pp) = past_values.iter().find_map(|past| past.pp) {
+            move_.pp = Some(pp);
+        }
+        if let Some(type_) = past_values.iter().find_map(|past| past.type_.as_ref()) {
+            move_.type_ = type_.clone();
+        }
+
+        let past_effect = past_values
+            .iter()
+            .find(|past| !past.effect_entries.is_empty())
+            .and_then(|past| Some((era.changed_after(&past.version_group)?, past)));
+        match (past_effect, effect_change(&self.effect_changes, era)) {
+            (Some((order, past)), Some((change_order, _))) if order <= change_order => {
+                move_.effect_entries.clone_from(&past.effect_entries);
+            }
+            (_, Some((_, change))) => move_.effect_entries = verbose_effects(change),
+            (Some((_, past)), None) => move_.effect_entries.clone_from(&past.effect_entries),
+            (None, None) => {}
+        }
+
+        move_.past_values.clear();
+        move_.effect_changes.clear();
+
+        Some(move_)
+    }
+}
+
+impl Ability {
+    /// The ability as it was in the given era, if it existed: its effect is replaced by the previous effect applying
+    /// to it, both as effect and short effect, and the effect changes are cleared.
+    pub fn as_of(&self, era: Era<'_>) -> Option<Ability> {
+        if !era.has(&self.generation) {
+            return None;
+        }
+        let mut ability = self.clone();
+
+        if let Some((_, change)) = effect_change(&self.effect_changes, era) {
+            ability.effect_entries = verbose_effects(change);
+        }
+        ability.effect_changes.clear();
+
+        Some(ability)
+    }
+}
+
+impl Type {
+    /// The type as it was in the given era, if it existed: its damage relations are replaced by the past ones applying
+    /// to it, and the past damage relations are cleared.
+    pub fn as_of(&self, era: Era<'_>) -> Option<Type> {
+        if !era.has(&self.generation) {
+            return None;
+        }
+        let mut type_ = self.clone();
+
+        if let Some(past) =
+            entries_up_to(&self.past_damage_relations, era, |past| &past.generation).first()
+        {
+            type_.damage_relations.clone_from(&past.damage_relations);
+        }
+        type_.past_damage_relations.clear();
+
+        Some(type_)
+    }
+}
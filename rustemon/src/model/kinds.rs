@@ -0,0 +1,460 @@
+//! Typed identifiers of the well-known resources, e.g. stats or types, compared instead of their names.
+//!
+//! Every identifier has an `Unknown` variant holding the names it doesn't know yet, e.g. a type added by a newer
+//! generation, so that decoding never fails on them.
+//!
+//! ```rust
+//! use rustemon::model::kinds::{StatKind, TypeName};
+//! # fn stats(pokemon: rustemon::model::pokemon::Pokemon) {
+//!
+//! let special_attack = pokemon
+//!     .stats
+//!     .iter()
+//!     .find(|stat| stat.kind() == StatKind::SpecialAttack);
+//! let is_fire = pokemon.types.iter().any(|t| t.type_name() == TypeName::Fire);
+//! # }
+//! ```
+
+use std::{convert::Infallible, fmt, str::FromStr};
+
+use super::{
+    encounters::EncounterMethod,
+    evolution::{EvolutionDetail, EvolutionTrigger},
+    locations::EncounterMethodRate,
+    moves::{Move, MoveDamageClass, MoveLearnMethod, MoveStatChange, MoveSummary},
+    pokemon::{
+        Characteristic, PokemonFormType, PokemonMoveVersion, PokemonStat, PokemonType, Stat, Type,
+    },
+    resource::{Encounter, NamedApiResource},
+    utility::Language,
+};
+
+/// Declares an identifier of the resources of a model, along with its conversions.
+macro_rules! kind {
+    (
+        $(#[$doc:meta])*
+        $kind:ident($what:literal) for $model:ty {
+            $($variant:ident => $name:literal),+ $(,)?
+        }
+    ) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $kind {
+            $(
+                #[doc = concat!("The `", $name, "` ", $what, ".")]
+                $variant,
+            )+
+            #[doc = concat!("Any other ", $what, ", by name.")]
+            Unknown(String),
+        }
+
+        impl $kind {
+            #[doc = concat!("The name of the ", $what, " in `PokeApi`, e.g. `\"", kind!(@first $($name),+), "\"`.")]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $name,)+
+                    Self::Unknown(name) => name,
+                }
+            }
+        }
+
+        impl From<&str> for $kind {
+            fn from(name: &str) -> Self {
+                match name {
+                    $($name => Self::$variant,)+
+                    _ => Self::Unknown(name.to_owned()),
+                }
+            }
+        }
+
+        impl From<String> for $kind {
+            fn from(name: String) -> Self {
+                match name.as_str() {
+                    $($name => Self::$variant,)+
+                    _ => Self::Unknown(name),
+                }
+            }
+        }
+
+        impl FromStr for $kind {
+            type Err = Infallible;
+
+            fn from_str(name: &str) -> Result<Self, Infallible> {
+                Ok(Self::from(name))
+            }
+        }
+
+        impl From<&NamedApiResource<$model>> for $kind {
+            fn from(resource: &NamedApiResource<$model>) -> Self {
+                Self::from(resource.name.as_str())
+            }
+        }
+
+        impl From<&$model> for $kind {
+            fn from(model: &$model) -> Self {
+                Self::from(model.name.as_str())
+            }
+        }
+
+        impl fmt::Display for $kind {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $kind {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                String::deserialize(deserializer).map(Self::from)
+            }
+        }
+
+        #[cfg(feature = "serialize")]
+        impl serde::Serialize for $kind {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+    };
+    (@first $first:literal $(, $rest:literal)*) => {
+        $first
+    };
+}
+
+kind!(
+    /// Identifier of a [`Stat`].
+    StatKind("stat") for Stat {
+        Hp => "hp",
+        Attack => "attack",
+        Defense => "defense",
+        SpecialAttack => "special-attack",
+        SpecialDefense => "special-defense",
+        Speed => "speed",
+        Accuracy => "accuracy",
+        Evasion => "evasion",
+    }
+);
+
+kind!(
+    /// Identifier of a [`Type`].
+    ///
+    /// [`TypeName::Mystery`] is the `???` type, named `unknown` by `PokeApi`, not to be confused with
+    /// [`TypeName::Unknown`].
+    TypeName("type") for Type {
+        Normal => "normal",
+        Fighting => "fighting",
+        Flying => "flying",
+        Poison => "poison",
+        Ground => "ground",
+        Rock => "rock",
+        Bug => "bug",
+        Ghost => "ghost",
+        Steel => "steel",
+        Fire => "fire",
+        Water => "water",
+        Grass => "grass",
+        Electric => "electric",
+        Psychic => "psychic",
+        Ice => "ice",
+        Dragon => "dragon",
+        Dark => "dark",
+        Fairy => "fairy",
+        Stellar => "stellar",
+        Mystery => "unknown",
+        Shadow => "shadow",
+    }
+);
+
+kind!(
+    /// Identifier of a [`Language`].
+    LanguageCode("language") for Language {
+        JaHrkt => "ja-hrkt",
+        Roomaji => "roomaji",
+        Ko => "ko",
+        ZhHant => "zh-hant",
+        Fr => "fr",
+        De => "de",
+        Es => "es",
+        It => "it",
+        En => "en",
+        Cs => "cs",
+        Ja => "ja",
+        ZhHans => "zh-hans",
+        PtBr => "pt-br",
+        Es419 => "es-419",
+        JaRoma => "ja-roma",
+    }
+);
+
+kind!(
+    /// Identifier of a [`MoveDamageClass`].
+    DamageClassKind("damage class") for MoveDamageClass {
+        Status => "status",
+        Physical => "physical",
+        Special => "special",
+    }
+);
+
+kind!(
+    /// Identifier of a [`MoveLearnMethod`].
+    LearnMethodKind("learn method") for MoveLearnMethod {
+        LevelUp => "level-up",
+        Egg => "egg",
+        Tutor => "tutor",
+        Machine => "machine",
+        StadiumSurfingPikachu => "stadium-surfing-pikachu",
+        LightBallEgg => "light-ball-egg",
+        ColosseumPurification => "colosseum-purification",
+        XdShadow => "xd-shadow",
+        XdPurification => "xd-purification",
+        FormChange => "form-change",
+        ZygardeCube => "zygarde-cube",
+    }
+);
+
+kind!(
+    /// Identifier of an [`EncounterMethod`].
+    EncounterMethodKind("encounter method") for EncounterMethod {
+        Walk => "walk",
+        OldRod => "old-rod",
+        GoodRod => "good-rod",
+        SuperRod => "super-rod",
+        Surf => "surf",
+        RockSmash => "rock-smash",
+        Headbutt => "headbutt",
+        DarkGrass => "dark-grass",
+        GrassSpots => "grass-spots",
+        CaveSpots => "cave-spots",
+        BridgeSpots => "bridge-spots",
+        SuperRodSpots => "super-rod-spots",
+        SurfSpots => "surf-spots",
+        YellowFlowers => "yellow-flowers",
+        PurpleFlowers => "purple-flowers",
+        RedFlowers => "red-flowers",
+        RoughTerrain => "rough-terrain",
+        Gift => "gift",
+        GiftEgg => "gift-egg",
+        OnlyOne => "only-one",
+    }
+);
+
+kind!(
+    /// Identifier of an [`EvolutionTrigger`].
+    EvolutionTriggerKind("evolution trigger") for EvolutionTrigger {
+        LevelUp => "level-up",
+        Trade => "trade",
+        UseItem => "use-item",
+        Shed => "shed",
+        Spin => "spin",
+        TowerOfDarkness => "tower-of-darkness",
+        TowerOfWaters => "tower-of-waters",
+        ThreeCriticalHits => "three-critical-hits",
+        TakeDamage => "take-damage",
+        Other => "other",
+        AgileStyleMove => "agile-style-move",
+        StrongStyleMove => "strong-style-move",
+        RecoilDamage => "recoil-damage",
+    }
+);
+
+impl PokemonStat {
+    /// The stat of the value.
+    pub fn kind(&self) -> StatKind {
+        StatKind::from(&self.stat)
+    }
+}
+
+impl MoveStatChange {
+    /// The stat being changed.
+    pub fn kind(&self) -> StatKind {
+        StatKind::from(&self.stat)
+    }
+}
+
+impl Characteristic {
+    /// The stat which results in this characteristic.
+    pub fn highest_stat_kind(&self) -> StatKind {
+        StatKind::from(&self.highest_stat)
+    }
+}
+
+impl PokemonType {
+    /// The type the Pokémon has.
+    pub fn type_name(&self) -> TypeName {
+        TypeName::from(&self.type_)
+    }
+}
+
+impl PokemonFormType {
+    /// The type the Pokémon form has.
+    pub fn type_name(&self) -> TypeName {
+        TypeName::from(&self.type_)
+    }
+}
+
+impl Move {
+    /// The type of the move.
+    pub fn type_name(&self) -> TypeName {
+        TypeName::from(&self.type_)
+    }
+
+    /// The damage class of the move, e.g. physical.
+    pub fn damage_class_kind(&self) -> DamageClassKind {
+        DamageClassKind::from(&self.damage_class)
+    }
+}
+
+impl MoveSummary {
+    /// The type of the move.
+    pub fn type_name(&self) -> TypeName {
+        TypeName::from(&self.type_)
+    }
+
+    /// The damage class of the move, e.g. physical.
+    pub fn damage_class_kind(&self) -> DamageClassKind {
+        DamageClassKind::from(&self.damage_class)
+    }
+}
+
+impl PokemonMoveVersion {
+    /// The method by which the move is learned.
+    pub fn learn_method_kind(&self) -> LearnMethodKind {
+        LearnMethodKind::from(&self.move_learn_method)
+    }
+}
+
+impl EncounterMethodRate {
+    /// The method in which Pokémon may be encountered.
+    pub fn method_kind(&self) -> EncounterMethodKind {
+        EncounterMethodKind::from(&self.encounter_method)
+    }
+}
+
+impl Encounter {
+    /// The method by which the encounter happens.
+    pub fn method_kind(&self) -> EncounterMethodKind {
+        EncounterMethodKind::from(&self.method)
+    }
+}
+
+impl EvolutionDetail {
+    /// The type of event that triggers the evolution.
+    pub fn trigger_kind(&self) -> EvolutionTriggerKind {
+        EvolutionTriggerKind::from(&self.trigger)
+    }
+}
+
+impl Language {
+    /// The code of the language.
+    pub fn code(&self) -> LanguageCode {
+        LanguageCode::from(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn known_names_decode_into_their_variant() {
+        let kinds: Vec<TypeName> =
+            serde_json::from_value(json!(["fire", "unknown", "shadow"])).unwrap();
+        assert_eq!(kinds, [TypeName::Fire, TypeName::Mystery, TypeName::Shadow]);
+
+        let kind: StatKind = serde_json::from_value(json!("special-attack")).unwrap();
+        assert_eq!(kind, StatKind::SpecialAttack);
+
+        let kind: LanguageCode = serde_json::from_value(json!("es-419")).unwrap();
+        assert_eq!(kind, LanguageCode::Es419);
+
+        let kind: EvolutionTriggerKind =
+            serde_json::from_value(json!("three-critical-hits")).unwrap();
+        assert_eq!(kind, EvolutionTriggerKind::ThreeCriticalHits);
+    }
+
+    #[test]
+    fn other_names_decode_into_unknown() {
+        let kind: TypeName = serde_json::from_value(json!("cosmic")).unwrap();
+        assert_eq!(kind, TypeName::Unknown("cosmic".to_owned()));
+        assert_eq!(kind.as_str(), "cosmic");
+
+        let kind: LearnMethodKind = serde_json::from_value(json!("mystery-gift")).unwrap();
+        assert_eq!(kind, LearnMethodKind::Unknown("mystery-gift".to_owned()));
+
+        assert!(serde_json::from_value::<StatKind>(json!(6)).is_err());
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn kinds_round_trip_through_their_name() {
+        let kinds = [
+            TypeName::Fairy,
+            TypeName::Mystery,
+            TypeName::Unknown("cosmic".to_owned()),
+        ];
+
+        let json = serde_json::to_value(&kinds).unwrap();
+        assert_eq!(json, json!(["fairy", "unknown", "cosmic"]));
+        assert_eq!(
+            serde_json::from_value::<Vec<TypeName>>(json).unwrap(),
+            kinds
+        );
+
+        for kind in [
+            DamageClassKind::Physical,
+            DamageClassKind::Unknown("shadow".to_owned()),
+        ] {
+            let json = serde_json::to_value(&kind).unwrap();
+            assert_eq!(
+                serde_json::from_value::<DamageClassKind>(json).unwrap(),
+                kind
+            );
+        }
+    }
+
+    #[test]
+    fn names_parse_and_display_as_in_poke_api() {
+        assert_eq!("ground".parse(), Ok(TypeName::Ground));
+        assert_eq!(EncounterMethodKind::SuperRod.to_string(), "super-rod");
+        assert_eq!(
+            EncounterMethodKind::from("fishing".to_owned()).to_string(),
+            "fishing"
+        );
+    }
+
+    #[test]
+    fn references_convert_into_their_kind() {
+        let fire = NamedApiResource::<Type>::new("fire", "https://pokeapi.co/api/v2/type/10/");
+        let speed = NamedApiResource::<Stat>::new("speed", "https://pokeapi.co/api/v2/stat/6/");
+        let stellar =
+            NamedApiResource::<Type>::new("stellar", "https://pokeapi.co/api/v2/type/19/");
+        let cosmic = NamedApiResource::<Type>::new("cosmic", "https://pokeapi.co/api/v2/type/20/");
+
+        assert_eq!(TypeName::from(&fire), TypeName::Fire);
+        assert_eq!(StatKind::from(&speed), StatKind::Speed);
+        assert_eq!(TypeName::from(&stellar), TypeName::Stellar);
+        assert_eq!(
+            TypeName::from(&cosmic),
+            TypeName::Unknown("cosmic".to_owned())
+        );
+    }
+
+    #[test]
+    fn models_expose_the_kinds_of_their_references() {
+        let pokemon_type: PokemonType = serde_json::from_value(json!({
+            "slot": 1,
+            "type": { "name": "dragon", "url": "https://pokeapi.co/api/v2/type/16/" }
+        }))
+        .unwrap();
+        let stat: PokemonStat = serde_json::from_value(json!({
+            "stat": { "name": "hp", "url": "https://pokeapi.co/api/v2/stat/1/" },
+            "effort": 0,
+            "base_stat": 108
+        }))
+        .unwrap();
+
+        assert_eq!(pokemon_type.type_name(), TypeName::Dragon);
+        assert_eq!(stat.kind(), StatKind::Hp);
+    }
+}
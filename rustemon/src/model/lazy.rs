@@ -0,0 +1,87 @@
+//! Lazily decoded fields of the summary models.
+
+use std::{fmt, sync::OnceLock};
+
+use serde::de::DeserializeOwned;
+use serde_json::value::RawValue;
+
+/// Field held as raw JSON, only decoded into a `T` on first access.
+///
+/// Used by the summary models, e.g. [`PokemonSummary`](crate::model::pokemon::PokemonSummary), for the fields rarely
+/// needed but expensive to decode. The payload is still validated as JSON when the model is decoded, but none of the
+/// values it holds are allocated until [`Lazy::get`] is called.
+///
+/// Holding raw JSON, a `Lazy` can only be deserialized by the `serde_json` deserializers, e.g.
+/// [`serde_json::from_str`] or [`serde_json::from_slice`]. Deserializing it from a [`serde_json::Value`] works too, but
+/// writes the value back to text. Deserializing it from another format, or within a model using `#[serde(flatten)]`,
+/// fails.
+pub struct Lazy<T> {
+    raw: Box<RawValue>,
+    decoded: OnceLock<T>,
+}
+
+impl<T> Lazy<T> {
+    /// The raw JSON of the field.
+    pub fn raw(&self) -> &str {
+        self.raw.get()
+    }
+}
+
+impl<T: DeserializeOwned> Lazy<T> {
+    /// Decodes the field on first access, returning the same value afterwards.
+    pub fn get(&self) -> Result<&T, serde_json::Error> {
+        if let Some(decoded) = self.decoded.get() {
+            return Ok(decoded);
+        }
+        let decoded = serde_json::from_str(self.raw.get())?;
+
+        Ok(self.decoded.get_or_init(|| decoded))
+    }
+
+    /// Decodes the field, unless already decoded, and returns it.
+    pub fn into_inner(self) -> Result<T, serde_json::Error> {
+        match self.decoded.into_inner() {
+            Some(decoded) => Ok(decoded),
+            None => serde_json::from_str(self.raw.get()),
+        }
+    }
+}
+
+impl<T: Clone> Clone for Lazy<T> {
+    fn clone(&self) -> Self {
+        Self {
+            raw: self.raw.clone(),
+            decoded: self.decoded.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Lazy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Lazy").field(&self.raw.get()).finish()
+    }
+}
+
+impl<T> PartialEq for Lazy<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw.get() == other.raw.get()
+    }
+}
+
+impl<T> Eq for Lazy<T> {}
+
+impl<'de, T> serde::Deserialize<'de> for Lazy<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self {
+            raw: Box::deserialize(deserializer)?,
+            decoded: OnceLock::new(),
+        })
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<T> serde::Serialize for Lazy<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.raw.serialize(serializer)
+    }
+}
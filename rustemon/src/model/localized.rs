@@ -0,0 +1,398 @@
+//! Localized texts of the models, looked up by language with fallbacks.
+//!
+//! The collections of localized texts, e.g. `Vec<Name>`, implement [`Localized`], and the models owning them implement
+//! [`LocalizedNames`], [`LocalizedDescriptions`], [`LocalizedEffects`], [`LocalizedFlavorTexts`] or
+//! [`LocalizedGenera`].
+//!
+//! ```rust
+//! use rustemon::model::localized::{LanguageFallback, Localized, LocalizedNames};
+//! # fn names(species: rustemon::model::pokemon::PokemonSpecies) {
+//!
+//! let french = species.name_in("fr");
+//! let mexican = species.name_with(&LanguageFallback::for_locale("es-419"));
+//! let languages = species.names.languages();
+//! # }
+//! ```
+
+use super::{
+    berries::{BerryFirmness, BerryFlavor},
+    contests::{ContestEffect, ContestName, ContestType, SuperContestEffect},
+    encounters::{EncounterCondition, EncounterConditionValue, EncounterMethod},
+    evolution::EvolutionTrigger,
+    games::{Generation, Pokedex, Version},
+    items::{Item, ItemAttribute, ItemCategory, ItemFlingEffect, ItemPocket},
+    locations::{Location, LocationArea, PalParkArea, Region},
+    moves::{
+        Move, MoveAilment, MoveBattleStyle, MoveCategory, MoveDamageClass, MoveFlavorText,
+        MoveLearnMethod, MoveTarget, PastMoveStatValues,
+    },
+    pokemon::{
+        Ability, AbilityEffectChange, AbilityFlavorText, AwesomeName, Characteristic, EggGroup,
+        Genus, GrowthRate, Nature, PokeathlonStat, PokemonColor, PokemonForm, PokemonHabitat,
+        PokemonShape, PokemonSpecies, Stat, Type,
+    },
+    resource::{Description, Effect, FlavorText, Name, VerboseEffect, VersionGroupFlavorText},
+    utility::Language,
+};
+
+/// Languages looked up in order, until one of them has a text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageFallback {
+    languages: Vec<String>,
+}
+
+impl LanguageFallback {
+    /// Creates a chain looking up the given languages in order, e.g. `["es-419", "es", "en"]`.
+    pub fn new(languages: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            languages: languages.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Creates a chain looking up `locale`, then its parent locales, and finally English.
+    ///
+    /// ```rust
+    /// use rustemon::model::localized::LanguageFallback;
+    ///
+    /// let fallback = LanguageFallback::for_locale("zh-Hant-TW");
+    /// assert!(fallback.languages().eq(["zh-Hant-TW", "zh-Hant", "zh", "en"]));
+    /// ```
+    pub fn for_locale(locale: &str) -> Self {
+        let mut languages = vec![locale.to_owned()];
+        let mut parent = locale;
+        while let Some((rest, _)) = parent.rsplit_once('-') {
+            languages.push(rest.to_owned());
+            parent = rest;
+        }
+        if !languages
+            .iter()
+            .any(|language| language.eq_ignore_ascii_case("en"))
+        {
+            languages.push("en".to_owned());
+        }
+
+        Self { languages }
+    }
+
+    /// Appends `language` to the chain.
+    pub fn then(mut self, language: impl Into<String>) -> Self {
+        self.languages.push(language.into());
+        self
+    }
+
+    /// The languages of the chain, in order.
+    pub fn languages(&self) -> impl Iterator<Item = &str> {
+        self.languages.iter().map(String::as_str)
+    }
+}
+
+impl Default for LanguageFallback {
+    /// Returns a chain looking up English only.
+    fn default() -> Self {
+        Self::new(["en"])
+    }
+}
+
+/// Text of a model in a given language, e.g. a [`Name`].
+pub trait LocalizedEntry {
+    /// The name of the language of the text, e.g. `"fr"`.
+    fn language(&self) -> Option<&str>;
+    /// The text itself.
+    fn text(&self) -> Option<&str>;
+}
+
+macro_rules! localized_entry {
+    ($($entry:ty => $text:ident),+ $(,)?) => {
+        $(
+            impl LocalizedEntry for $entry {
+                fn language(&self) -> Option<&str> {
+                    Some(self.language.name.as_str())
+                }
+
+                fn text(&self) -> Option<&str> {
+                    Some(self.$text.as_str())
+                }
+            }
+        )+
+    };
+}
+
+localized_entry!(
+    Name => name,
+    Description => description,
+    Effect => effect,
+    VerboseEffect => effect,
+    FlavorText => flavor_text,
+    VersionGroupFlavorText => text,
+    MoveFlavorText => flavor_text,
+    AbilityFlavorText => flavor_text,
+    AwesomeName => awesome_name,
+    Genus => genus,
+);
+
+impl LocalizedEntry for ContestName {
+    fn language(&self) -> Option<&str> {
+        self.language
+            .as_ref()
+            .map(|language| language.name.as_str())
+    }
+
+    fn text(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+}
+
+/// Whether `entry` is in `language`, the languages being compared ignoring case, e.g. `zh-Hant` and `zh-hant`.
+fn is_in(entry: &impl LocalizedEntry, language: &str) -> bool {
+    entry
+        .language()
+        .is_some_and(|entry_language| entry_language.eq_ignore_ascii_case(language))
+}
+
+/// Collection of texts in several languages, e.g. `Vec<Name>`.
+pub trait Localized {
+    /// The texts of the collection.
+    type Entry: LocalizedEntry;
+
+    /// Every text of the collection.
+    fn entries(&self) -> &[Self::Entry];
+
+    /// Every text in `language`, e.g. the flavor texts of every version.
+    fn entries_in<'a>(&'a self, language: &'a str) -> impl Iterator<Item = &'a Self::Entry> {
+        self.entries()
+            .iter()
+            .filter(move |entry| is_in(*entry, language))
+    }
+
+    /// The first text in `language`, e.g. `"fr"`, if any.
+    fn entry_in(&self, language: &str) -> Option<&Self::Entry> {
+        self.entries()
+            .iter()
+            .find(|entry| is_in(*entry, language) && entry.text().is_some())
+    }
+
+    /// The first text in the first language of `fallback` having one, if any.
+    fn entry_with(&self, fallback: &LanguageFallback) -> Option<&Self::Entry> {
+        fallback
+            .languages()
+            .find_map(|language| self.entry_in(language))
+    }
+
+    /// The first text in `language`, e.g. `"fr"`, if any.
+    fn text_in(&self, language: &str) -> Option<&str> {
+        self.entry_in(language)?.text()
+    }
+
+    /// The first text in the first language of `fallback` having one, if any.
+    fn text_with(&self, fallback: &LanguageFallback) -> Option<&str> {
+        self.entry_with(fallback)?.text()
+    }
+
+    /// Every language having a text, in order of first appearance.
+    fn languages(&self) -> Vec<&str> {
+        let mut languages = Vec::new();
+        for language in self.entries().iter().filter_map(LocalizedEntry::language) {
+            if !languages.contains(&language) {
+                languages.push(language);
+            }
+        }
+
+        languages
+    }
+}
+
+impl<E: LocalizedEntry> Localized for [E] {
+    type Entry = E;
+
+    fn entries(&self) -> &[E] {
+        self
+    }
+}
+
+/// Declares a trait of the models owning a collection of localized texts.
+macro_rules! localized_owner {
+    ($(#[$doc:meta])* $trait:ident, $what:literal, $entries:ident, $in:ident, $with:ident) => {
+        $(#[$doc])*
+        pub trait $trait {
+            /// The texts of the collection.
+            type Entry: LocalizedEntry;
+
+            #[doc = concat!("The ", $what, " of the model, in every language.")]
+            fn $entries(&self) -> &[Self::Entry];
+
+            #[doc = concat!("The ", $what, " of the model in `language`, e.g. `\"fr\"`, if any.")]
+            fn $in(&self, language: &str) -> Option<&str> {
+                self.$entries().text_in(language)
+            }
+
+            #[doc = concat!("The ", $what, " of the model in the first language of `fallback` having one, if any.")]
+            fn $with(&self, fallback: &LanguageFallback) -> Option<&str> {
+                self.$entries().text_with(fallback)
+            }
+        }
+    };
+}
+
+/// Implements a trait declared by [`localized_owner`] for the models holding the collection in the given field.
+macro_rules! impl_localized_owner {
+    ($trait:ident, $entries:ident, $field:ident: $entry:ty => $($model:ty),+ $(,)?) => {
+        $(
+            impl $trait for $model {
+                type Entry = $entry;
+
+                fn $entries(&self) -> &[$entry] {
+                    &self.$field
+                }
+            }
+        )+
+    };
+}
+
+localized_owner!(
+    /// Model having a name in several languages.
+    LocalizedNames, "name", names, name_in, name_with
+);
+localized_owner!(
+    /// Model having a description in several languages.
+    LocalizedDescriptions, "description", descriptions, description_in, description_with
+);
+localized_owner!(
+    /// Model having an effect in several languages.
+    LocalizedEffects, "effect", effects, effect_in, effect_with
+);
+localized_owner!(
+    /// Model having a flavor text in several languages.
+    LocalizedFlavorTexts, "flavor text", flavor_texts, flavor_text_in, flavor_text_with
+);
+localized_owner!(
+    /// Model having a genus in several languages.
+    LocalizedGenera, "genus", genera, genus_in, genus_with
+);
+
+impl_localized_owner!(LocalizedNames, names, names: Name =>
+    BerryFirmness, BerryFlavor, EncounterMethod, EncounterCondition, EncounterConditionValue, EvolutionTrigger,
+    Generation, Pokedex, Version, Item, ItemAttribute, ItemCategory, ItemPocket, Location, LocationArea, PalParkArea,
+    Region, Move, MoveAilment, MoveBattleStyle, MoveDamageClass, MoveLearnMethod, MoveTarget, Ability, EggGroup, Nature,
+    PokeathlonStat, PokemonColor, PokemonForm, PokemonHabitat, PokemonShape, PokemonSpecies, Stat, Type, Language,
+);
+impl_localized_owner!(LocalizedNames, names, names: ContestName => ContestType);
+
+impl_localized_owner!(LocalizedDescriptions, descriptions, descriptions: Description =>
+    Pokedex, ItemAttribute, MoveCategory, MoveDamageClass, MoveLearnMethod, MoveTarget, Characteristic, GrowthRate,
+);
+
+impl_localized_owner!(LocalizedEffects, effects, effect_entries: Effect =>
+    ContestEffect, ItemFlingEffect, AbilityEffectChange,
+);
+impl_localized_owner!(LocalizedEffects, effects, effect_entries: VerboseEffect =>
+    Item, Move, PastMoveStatValues, Ability,
+);
+
+impl_localized_owner!(LocalizedFlavorTexts, flavor_texts, flavor_text_entries: FlavorText =>
+    ContestEffect, SuperContestEffect, PokemonSpecies,
+);
+impl_localized_owner!(LocalizedFlavorTexts, flavor_texts, flavor_text_entries: VersionGroupFlavorText => Item);
+impl_localized_owner!(LocalizedFlavorTexts, flavor_texts, flavor_text_entries: MoveFlavorText => Move);
+impl_localized_owner!(LocalizedFlavorTexts, flavor_texts, flavor_text_entries: AbilityFlavorText => Ability);
+
+impl_localized_owner!(LocalizedGenera, genera, genera: Genus => PokemonSpecies);
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    /// Names of a model in the given languages.
+    fn names(entries: &[(&str, &str)]) -> Vec<Name> {
+        let names = entries
+            .iter()
+            .map(|(language, name)| {
+                json!({
+                    "name": name,
+                    "language": { "name": language, "url": format!("https://pokeapi.co/api/v2/language/{language}/") }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        serde_json::from_value(json!(names)).unwrap()
+    }
+
+    #[test]
+    fn locales_fall_back_to_their_parents_then_to_english() {
+        let fallback = LanguageFallback::for_locale("es-419");
+        assert!(fallback.languages().eq(["es-419", "es", "en"]));
+
+        let fallback = LanguageFallback::for_locale("zh-Hant-TW");
+        assert!(
+            fallback
+                .languages()
+                .eq(["zh-Hant-TW", "zh-Hant", "zh", "en"])
+        );
+    }
+
+    #[test]
+    fn english_locales_fall_back_to_english_once() {
+        let fallback = LanguageFallback::for_locale("en-GB");
+        assert!(fallback.languages().eq(["en-GB", "en"]));
+    }
+
+    #[test]
+    fn unknown_locales_fall_back_to_english() {
+        let fallback = LanguageFallback::for_locale("tlh");
+        assert!(fallback.languages().eq(["tlh", "en"]));
+    }
+
+    #[test]
+    fn lookups_go_through_the_chain_in_order() {
+        let names = names(&[
+            ("en", "Charizard"),
+            ("es", "Charizard ES"),
+            ("ja", "リザードン"),
+        ]);
+
+        let fallback = LanguageFallback::for_locale("es-419");
+        assert_eq!(names.text_with(&fallback), Some("Charizard ES"));
+
+        let fallback = LanguageFallback::for_locale("fr-CA");
+        assert_eq!(names.text_with(&fallback), Some("Charizard"));
+
+        let fallback = LanguageFallback::new(["ko", "ja"]).then("en");
+        assert_eq!(names.text_with(&fallback), Some("リザードン"));
+    }
+
+    #[test]
+    fn languages_are_compared_ignoring_case() {
+        let names = names(&[("zh-Hant", "噴火龍")]);
+
+        assert_eq!(names.text_in("zh-hant"), Some("噴火龍"));
+        assert_eq!(
+            names.text_with(&LanguageFallback::for_locale("zh-HANT-TW")),
+            Some("噴火龍")
+        );
+    }
+
+    #[test]
+    fn lookups_without_any_language_of_the_chain_find_nothing() {
+        let names = names(&[("ja", "リザードン"), ("ko", "리자몽")]);
+
+        assert_eq!(names.text_in("fr"), None);
+        assert_eq!(
+            names.text_with(&LanguageFallback::for_locale("fr-CA")),
+            None
+        );
+        assert_eq!(names.text_with(&LanguageFallback::default()), None);
+        assert_eq!(
+            Vec::<Name>::new().text_with(&LanguageFallback::default()),
+            None
+        );
+    }
+
+    #[test]
+    fn languages_are_listed_once_in_order_of_appearance() {
+        let names = names(&[("en", "Soft"), ("fr", "Tendre"), ("en", "Soft again")]);
+
+        assert_eq!(names.languages(), ["en", "fr"]);
+        assert_eq!(names.entries_in("en").count(), 2);
+    }
+}
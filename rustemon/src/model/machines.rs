@@ -5,6 +5,13 @@ use super::{games::VersionGroup, items::Item, moves::Move, resource::NamedApiRes
 /// [Machine official documentation](https://pokeapi.co/docs/v2#machine)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct Machine {
     /// The identifier for this resource.
     pub id: i64,
@@ -15,4 +22,12 @@ pub struct Machine {
     pub move_: NamedApiResource<Move>,
     /// The version group that this machine applies to.
     pub version_group: NamedApiResource<VersionGroup>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
@@ -4,11 +4,20 @@ pub mod berries;
 pub mod contests;
 pub mod encounters;
 pub mod evolution;
+pub mod extra;
+pub mod generational;
 pub mod games;
 pub mod items;
+pub mod kinds;
+pub mod lazy;
+pub mod localized;
 pub mod locations;
 pub mod machines;
 pub mod moves;
 pub mod pokemon;
 pub mod resource;
+#[cfg(feature = "shared-strings")]
+pub mod shared;
+pub mod text;
 pub mod utility;
+pub mod versioned;
@@ -13,6 +13,13 @@ use super::{
 /// [Move official documentation](https://pokeapi.co/docs/v2#move)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct Move {
     /// The identifier for this resource.
     pub id: i64,
@@ -64,32 +71,114 @@ pub struct Move {
     /// The elemental type of this move.
     #[serde(rename = "type")]
     pub type_: NamedApiResource<Type>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
+}
+
+/// Lightweight view of a [`Move`], fetched through the same endpoint with
+/// [`get_summary_by_id`](crate::moves::move_::get_summary_by_id) or
+/// [`get_summary_by_name`](crate::moves::move_::get_summary_by_name).
+///
+/// Only the fields used in battle are decoded, leaving out the texts, contest details and the Pokémon learning it.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct MoveSummary {
+    /// The identifier for this resource.
+    pub id: i64,
+    /// The name for this resource.
+    pub name: String,
+    /// The percent value of how likely this move is to be successful.
+    pub accuracy: Option<i64>,
+    /// The percent value of how likely it is this moves effect will happen.
+    pub effect_chance: Option<i64>,
+    /// Power points. The number of times this move can be used.
+    pub pp: Option<i64>,
+    /// A value between -8 and 8. Sets the order in which moves are executed during battle.
+    pub priority: i64,
+    /// The base power of this move with a value of 0 if it does not have a base power.
+    pub power: Option<i64>,
+    /// The type of damage the move inflicts on the target, e.g. physical.
+    pub damage_class: NamedApiResource<MoveDamageClass>,
+    /// The generation in which this move was introduced.
+    pub generation: NamedApiResource<Generation>,
+    /// Metadata about this move.
+    pub meta: Option<MoveMetaData>,
+    /// A list of stats this moves effects and how much it effects them.
+    pub stat_changes: Vec<MoveStatChange>,
+    /// The type of target that will receive the effects of the attack.
+    pub target: NamedApiResource<MoveTarget>,
+    /// The elemental type of this move.
+    #[serde(rename = "type")]
+    pub type_: NamedApiResource<Type>,
 }
 
 /// [ContestComboSets official documentation](https://pokeapi.co/docs/v2#contestcombosets)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct ContestComboSets {
     /// A detail of moves this move can be used before or after, granting additional appeal points in contests.
     pub normal: ContestComboDetail,
     /// A detail of moves this move can be used before or after, granting additional appeal points in super contests.
     #[serde(rename = "super")]
     pub super_: ContestComboDetail,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [ContestComboDetail official documentation](https://pokeapi.co/docs/v2#contestcombodetail)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct ContestComboDetail {
     /// A list of moves to use before this move.
     pub use_before: Option<Vec<NamedApiResource<Move>>>,
     /// A list of moves to use after this move.
     pub use_after: Option<Vec<NamedApiResource<Move>>>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [MoveFlavorText official documentation](https://pokeapi.co/docs/v2#moveflavortext)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct MoveFlavorText {
     /// The localized flavor text for an api resource in a specific language.
     pub flavor_text: String,
@@ -97,11 +186,26 @@ pub struct MoveFlavorText {
     pub language: NamedApiResource<Language>,
     /// The version group that uses this flavor text.
     pub version_group: NamedApiResource<VersionGroup>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [MoveMetaData official documentation](https://pokeapi.co/docs/v2#movemetadata)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct MoveMetaData {
     /// The status ailment this move inflicts on its target.
     pub ailment: NamedApiResource<MoveAilment>,
@@ -127,21 +231,51 @@ pub struct MoveMetaData {
     pub flinch_chance: i64,
     /// The likelihood this attack will cause a stat change in the target Pokémon.
     pub stat_chance: i64,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [MoveStatChange official documentation](https://pokeapi.co/docs/v2#movestatchange)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct MoveStatChange {
     /// The amount of change.
     pub change: i64,
     /// The stat being affected.
     pub stat: NamedApiResource<Stat>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [PastMoveStatValues official documentation](https://pokeapi.co/docs/v2#pastmovestatvalues)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct PastMoveStatValues {
     /// The percent value of how likely this move is to be successful.
     pub accuracy: Option<i64>,
@@ -158,11 +292,26 @@ pub struct PastMoveStatValues {
     pub type_: Option<NamedApiResource<Type>>,
     /// The version group in which these move stat values were in effect.
     pub version_group: NamedApiResource<VersionGroup>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [MoveAilment official documentation](https://pokeapi.co/docs/v2#moveailment)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct MoveAilment {
     /// The identifier for this resource.
     pub id: i64,
@@ -172,11 +321,26 @@ pub struct MoveAilment {
     pub moves: Vec<NamedApiResource<Move>>,
     /// The name of this resource listed in different languages.
     pub names: Vec<Name>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [MoveBattleStyle official documentation](https://pokeapi.co/docs/v2#movebattlestyle)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct MoveBattleStyle {
     /// The identifier for this resource.
     pub id: i64,
@@ -184,11 +348,26 @@ pub struct MoveBattleStyle {
     pub name: String,
     /// The name of this resource listed in different languages.
     pub names: Vec<Name>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [MoveCategory official documentation](https://pokeapi.co/docs/v2#movecategory)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct MoveCategory {
     /// The identifier for this resource.
     pub id: i64,
@@ -198,11 +377,26 @@ pub struct MoveCategory {
     pub moves: Vec<NamedApiResource<Move>>,
     /// The description of this resource listed in different languages.
     pub descriptions: Vec<Description>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [MoveDamageClass official documentation](https://pokeapi.co/docs/v2#movedamageclass)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct MoveDamageClass {
     /// The identifier for this resource.
     pub id: i64,
@@ -214,11 +408,26 @@ pub struct MoveDamageClass {
     pub moves: Vec<NamedApiResource<Move>>,
     /// The name of this resource listed in different languages.
     pub names: Vec<Name>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [MoveLearnMethod official documentation](https://pokeapi.co/docs/v2#movelearnmethod)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct MoveLearnMethod {
     /// The identifier for this resource.
     pub id: i64,
@@ -230,11 +439,26 @@ pub struct MoveLearnMethod {
     pub names: Vec<Name>,
     /// A list of version groups where moves can be learned through this method.
     pub version_groups: Vec<NamedApiResource<VersionGroup>>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [MoveTarget official documentation](https://pokeapi.co/docs/v2#movetarget)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct MoveTarget {
     /// The identifier for this resource.
     pub id: i64,
@@ -246,4 +470,12 @@ pub struct MoveTarget {
     pub moves: Vec<NamedApiResource<Move>>,
     /// The name of this resource listed in different languages.ƒ
     pub names: Vec<Name>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
@@ -1,6 +1,10 @@
 //! Common models
 
-use std::marker::PhantomData;
+use std::{fmt, marker::PhantomData};
+
+use reqwest::Url;
+
+use crate::{client::Environment, error::Error};
 
 use super::{
     encounters::{EncounterConditionValue, EncounterMethod},
@@ -9,6 +13,119 @@ use super::{
     utility::Language,
 };
 
+/// Model served by an endpoint of `PokeApi`.
+pub trait Endpoint {
+    /// The name of the endpoint, as found in the URLs of `PokeApi`, e.g. `"pokemon-species"`.
+    const NAME: &'static str;
+    /// The list returned by the pages of the endpoint, either a [`NamedApiResourceList`] or an [`ApiResourceList`].
+    type List;
+}
+
+/// Returns the URL of the resource of `T` with the given id or name, in the given `environment`.
+fn resource_url<T: Endpoint>(
+    environment: &Environment,
+    key: impl fmt::Display,
+) -> Result<String, Error> {
+    let base = Url::try_from(environment.clone())?;
+
+    Ok(format!("{base}{}/{key}/", T::NAME))
+}
+
+/// Returns the name of `T`, without its path, to name the schemas of the references to `T`.
+#[cfg(feature = "schemars")]
+fn schema_suffix<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+
+    name.rsplit_once("::").map_or(name, |(_, name)| name)
+}
+
+/// Returns the schema of the URL of a reference to a resource of `T`, matching the endpoint of `T`.
+#[cfg(feature = "schemars")]
+fn url_schema<T: Endpoint>() -> serde_json::Value {
+    serde_json::json!({
+        "type": "string",
+        "format": "uri",
+        "pattern": format!("/{}/[0-9]+/$", T::NAME),
+        "description": format!("The URL of the referenced resource, on the `{}` endpoint.", T::NAME),
+    })
+}
+
+/// Returns the schema of a page of references to resources of `T`.
+#[cfg(feature = "schemars")]
+fn list_schema<T: Endpoint, R: schemars::JsonSchema>(
+    generator: &mut schemars::SchemaGenerator,
+) -> schemars::Schema {
+    schemars::json_schema!({
+        "type": "object",
+        "description": format!("A page of the `{}` endpoint.", T::NAME),
+        "properties": {
+            "count": generator.subschema_for::<i64>(),
+            "next": generator.subschema_for::<Option<String>>(),
+            "previous": generator.subschema_for::<Option<String>>(),
+            "results": generator.subschema_for::<Vec<R>>(),
+        },
+        "required": ["count", "next", "previous", "results"],
+        "x-endpoint": T::NAME,
+    })
+}
+
+/// Generates the URL of a resource of `T` in the production environment, from its id or name.
+#[cfg(feature = "arbitrary")]
+fn arbitrary_url<T: Endpoint>(key: impl fmt::Display) -> arbitrary::Result<String> {
+    resource_url::<T>(&Environment::Production, key).map_err(|_| arbitrary::Error::IncorrectFormat)
+}
+
+/// Generates the name of a resource, made of lowercase letters, digits and dashes like the names of `PokeApi`,
+/// e.g. `"mr-mime"`.
+#[cfg(feature = "arbitrary")]
+fn arbitrary_name(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<String> {
+    const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+    const CHARACTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789-";
+
+    let mut name = String::from(char::from(*u.choose(LETTERS)?));
+    for _ in 0..u.int_in_range(0..=15)? {
+        name.push(char::from(*u.choose(CHARACTERS)?));
+    }
+
+    Ok(name)
+}
+
+/// Fields of an arbitrary page, shared by [`NamedApiResourceList`] and [`ApiResourceList`].
+#[cfg(feature = "arbitrary")]
+struct ArbitraryPage<R> {
+    count: i64,
+    next: Option<String>,
+    previous: Option<String>,
+    results: Vec<R>,
+}
+
+/// Generates a page of the endpoint of `T`, its `next` and `previous` URLs being consistent with its count, offset
+/// and limit.
+#[cfg(feature = "arbitrary")]
+fn arbitrary_page<'a, T: Endpoint, R: arbitrary::Arbitrary<'a>>(
+    u: &mut arbitrary::Unstructured<'a>,
+) -> arbitrary::Result<ArbitraryPage<R>> {
+    let count = u.int_in_range(0..=2_000)?;
+    let limit = u.int_in_range(1..=100)?;
+    let offset = u.int_in_range(0..=count)?;
+    let base =
+        Url::try_from(Environment::Production).map_err(|_| arbitrary::Error::IncorrectFormat)?;
+    let page_url = |offset: i64| format!("{base}{}?offset={offset}&limit={limit}", T::NAME);
+
+    let next = (offset + limit < count).then(|| page_url(offset + limit));
+    let previous = (offset > 0).then(|| page_url((offset - limit).max(0)));
+    let results = (0..limit.min(count - offset))
+        .map(|_| R::arbitrary(u))
+        .collect::<arbitrary::Result<_>>()?;
+
+    Ok(ArbitraryPage {
+        count,
+        next,
+        previous,
+        results,
+    })
+}
+
 /// [NamedApiResource official documentation](https://pokeapi.co/docs/v2#namedapiresource)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
@@ -19,11 +136,23 @@ pub struct NamedApiResource<T> {
     pub url: String,
     #[serde(skip)]
     _marker: PhantomData<T>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [NamedApiResourceList official documentation](https:///pokeapi.co/docs/v2#namedapiresourcelist)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct NamedApiResourceList<T> {
     /// The total number of resources available from this API.
     pub count: i64,
@@ -33,6 +162,150 @@ pub struct NamedApiResourceList<T> {
     pub previous: Option<String>,
     /// A list of  named API resources.
     pub results: Vec<NamedApiResource<T>>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
+}
+
+impl<T> NamedApiResource<T> {
+    /// Creates a reference to the resource with the given name and URL.
+    pub fn new(name: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            url: url.into(),
+            _marker: PhantomData,
+            extra: Default::default(),
+        }
+    }
+
+    /// The id of the resource, when found at the end of its URL, e.g. `10` for `https://pokeapi.co/api/v2/type/10/`.
+    pub fn id(&self) -> Option<i64> {
+        self.url
+            .trim_end_matches('/')
+            .rsplit('/')
+            .next()?
+            .parse()
+            .ok()
+    }
+}
+
+impl<T: Endpoint> NamedApiResource<T> {
+    /// Creates a reference to the resource with the given id and name, in the given `environment`.
+    ///
+    /// ```rust
+    /// use rustemon::{client::Environment, model::{pokemon::Type, resource::NamedApiResource}};
+    ///
+    /// let fire = NamedApiResource::<Type>::from_id(&Environment::Production, 10, "fire").unwrap();
+    /// assert_eq!(fire.url, "https://pokeapi.co/api/v2/type/10/");
+    /// ```
+    pub fn from_id(
+        environment: &Environment,
+        id: i64,
+        name: impl Into<String>,
+    ) -> Result<Self, Error> {
+        Ok(Self::new(name, resource_url::<T>(environment, id)?))
+    }
+
+    /// Creates a reference to the resource with the given name, in the given `environment`.
+    ///
+    /// `PokeApi` serves the resources by name as well as by id, the URL being built from the name in that case.
+    ///
+    /// ```rust
+    /// use rustemon::{client::Environment, model::{moves::Move, resource::NamedApiResource}};
+    ///
+    /// let thunder = NamedApiResource::<Move>::from_name(&Environment::Production, "thunder").unwrap();
+    /// assert_eq!(thunder.url, "https://pokeapi.co/api/v2/move/thunder/");
+    /// ```
+    pub fn from_name(environment: &Environment, name: &str) -> Result<Self, Error> {
+        Ok(Self::new(name, resource_url::<T>(environment, name)?))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T: Endpoint> arbitrary::Arbitrary<'a> for NamedApiResource<T> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let name = arbitrary_name(u)?;
+        let url = arbitrary_url::<T>(&name)?;
+
+        Ok(Self::new(name, url))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T: Endpoint> arbitrary::Arbitrary<'a> for NamedApiResourceList<T> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let ArbitraryPage {
+            count,
+            next,
+            previous,
+            results,
+        } = arbitrary_page::<T, _>(u)?;
+
+        Ok(Self {
+            count,
+            next,
+            previous,
+            results,
+            extra: Default::default(),
+        })
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl<T: Endpoint> schemars::JsonSchema for NamedApiResource<T> {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        format!("NamedApiResource_for_{}", schema_suffix::<T>()).into()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        format!("rustemon::NamedApiResource<{}>", std::any::type_name::<T>()).into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "object",
+            "description": format!("A reference to a resource of the `{}` endpoint.", T::NAME),
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "description": "The name of the referenced resource.",
+                },
+                "url": url_schema::<T>(),
+            },
+            "required": ["name", "url"],
+            "x-endpoint": T::NAME,
+        })
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl<T: Endpoint> schemars::JsonSchema for NamedApiResourceList<T> {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        format!("NamedApiResourceList_for_{}", schema_suffix::<T>()).into()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        format!(
+            "rustemon::NamedApiResourceList<{}>",
+            std::any::type_name::<T>()
+        )
+        .into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        list_schema::<T, NamedApiResource<T>>(generator)
+    }
+}
+
+#[cfg(feature = "test-builders")]
+impl<T> Default for NamedApiResource<T> {
+    fn default() -> Self {
+        Self::new(String::new(), String::new())
+    }
 }
 
 /// [ApiResource official documentation](https://pokeapi.co/docs/v2#apiresource)
@@ -43,11 +316,23 @@ pub struct ApiResource<T> {
     pub url: String,
     #[serde(skip)]
     _marker: PhantomData<T>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [ApiResourceList official documentation](https:///pokeapi.co/docs/v2#apiresourcelist)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct ApiResourceList<T> {
     /// The total number of resources available from this API.
     pub count: i64,
@@ -57,31 +342,165 @@ pub struct ApiResourceList<T> {
     pub previous: Option<String>,
     /// A list of  named API resources.
     pub results: Vec<ApiResource<T>>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
+}
+
+impl<T> ApiResource<T> {
+    /// Creates a reference to the resource with the given URL.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            _marker: PhantomData,
+            extra: Default::default(),
+        }
+    }
+}
+
+impl<T: Endpoint> ApiResource<T> {
+    /// Creates a reference to the resource with the given id, in the given `environment`.
+    pub fn from_id(environment: &Environment, id: i64) -> Result<Self, Error> {
+        Ok(Self::new(resource_url::<T>(environment, id)?))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T: Endpoint> arbitrary::Arbitrary<'a> for ApiResource<T> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::new(arbitrary_url::<T>(u.int_in_range(1..=10_000)?)?))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T: Endpoint> arbitrary::Arbitrary<'a> for ApiResourceList<T> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let ArbitraryPage {
+            count,
+            next,
+            previous,
+            results,
+        } = arbitrary_page::<T, _>(u)?;
+
+        Ok(Self {
+            count,
+            next,
+            previous,
+            results,
+            extra: Default::default(),
+        })
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl<T: Endpoint> schemars::JsonSchema for ApiResource<T> {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        format!("ApiResource_for_{}", schema_suffix::<T>()).into()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        format!("rustemon::ApiResource<{}>", std::any::type_name::<T>()).into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "object",
+            "description": format!("A reference to a resource of the `{}` endpoint.", T::NAME),
+            "properties": {
+                "url": url_schema::<T>(),
+            },
+            "required": ["url"],
+            "x-endpoint": T::NAME,
+        })
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl<T: Endpoint> schemars::JsonSchema for ApiResourceList<T> {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        format!("ApiResourceList_for_{}", schema_suffix::<T>()).into()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        format!("rustemon::ApiResourceList<{}>", std::any::type_name::<T>()).into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        list_schema::<T, ApiResource<T>>(generator)
+    }
+}
+
+#[cfg(feature = "test-builders")]
+impl<T> Default for ApiResource<T> {
+    fn default() -> Self {
+        Self::new(String::new())
+    }
 }
 
 /// [Description official documentation](https://pokeapi.co/docs/v2#description)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct Description {
     /// The localized description for an API resource in a specific language.
     pub description: String,
     /// The language this name is in.
     pub language: NamedApiResource<Language>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [Effect official documentation](https://pokeapi.co/docs/v2#effect)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct Effect {
     /// The localized effect text for an API resource in a specific language.
     pub effect: String,
     /// The language this effect is in.
     pub language: NamedApiResource<Language>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [Encounter official documentation](https://pokeapi.co/docs/v2#encounter)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct Encounter {
     /// The lowest level the Pokémon could be encountered at.
     pub min_level: i64,
@@ -93,11 +512,26 @@ pub struct Encounter {
     pub chance: i64,
     /// The method by which this encounter happens.
     pub method: NamedApiResource<EncounterMethod>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [FlavorText official documentation](https://pokeapi.co/docs/v2#flavortext)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct FlavorText {
     /// The localized flavor text for an API resource in a specific language.
     pub flavor_text: String,
@@ -105,41 +539,101 @@ pub struct FlavorText {
     pub language: NamedApiResource<Language>,
     /// The game version this flavor text is extracted from.
     pub version: Option<NamedApiResource<Version>>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [GenerationGameIndex official documentation](https://pokeapi.co/docs/v2#generationgameindex)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct GenerationGameIndex {
     /// The internal id of an API resource within game data.
     pub game_index: i64,
     /// The generation relevent to this game index.
     pub generation: NamedApiResource<Generation>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [MachineVersionDetail official documentation](https://pokeapi.co/docs/v2#machineversiondetail)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct MachineVersionDetail {
     /// The machine that teaches a move from an item.
     pub machine: ApiResource<Machine>,
     /// The version group of this specific machine.
     pub version_group: NamedApiResource<VersionGroup>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [Name official documentation](https://pokeapi.co/docs/v2#name)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct Name {
     /// The localized name for an API resource in a specific language.
     pub name: String,
     /// The language this name is in.
     pub language: NamedApiResource<Language>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [VerboseEffect official documentation](https://pokeapi.co/docs/v2#verboseeffect)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct VerboseEffect {
     /// The localized effect text for an API resource in a specific language.
     pub effect: String,
@@ -147,11 +641,26 @@ pub struct VerboseEffect {
     pub short_effect: String,
     /// The language this effect is in.
     pub language: NamedApiResource<Language>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [VersionEncounterDetail official documentation](https://pokeapi.co/docs/v2#versionencounterdetail)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct VersionEncounterDetail {
     /// The game version this encounter happens in.
     pub version: NamedApiResource<Version>,
@@ -159,21 +668,51 @@ pub struct VersionEncounterDetail {
     pub max_chance: i64,
     /// A list of encounters and their specifics.
     pub encounter_details: Vec<Encounter>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [VersionGameIndex official documentation](https://pokeapi.co/docs/v2#versiongameindex)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct VersionGameIndex {
     /// The internal id of an API resource within game data.
     pub game_index: i64,
     /// The version relevent to this game index.
     pub version: NamedApiResource<Version>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
 
 /// [VersionGroupFlavorText official documentation](https://pokeapi.co/docs/v2#versiongroupflavortext)
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "test-builders",
+    derive(Default, typed_builder::TypedBuilder),
+    builder(field_defaults(default, setter(into)))
+)]
 pub struct VersionGroupFlavorText {
     /// The localized name for an API resource in a specific language.
     pub text: String,
@@ -181,4 +720,12 @@ pub struct VersionGroupFlavorText {
     pub language: NamedApiResource<Language>,
     /// The version group which uses this flavor text.
     pub version_group: NamedApiResource<VersionGroup>,
+    /// Fields of the payload unknown to the model, only kept with the `preserve-unknown` feature.
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    #[cfg_attr(
+        feature = "preserve-unknown",
+        serde(flatten, serialize_with = "crate::model::extra::serialize")
+    )]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: crate::model::extra::Extra,
 }
@@ -0,0 +1,332 @@
+//! Interned views of the references to resources and of their localized texts, for large datasets kept in memory.
+//!
+//! The names and URLs of the [`NamedApiResource`]s and [`ApiResource`]s are repeated throughout the payloads, e.g. the
+//! language of every [`Name`], as are the texts of the names and flavor texts shared by several languages or versions.
+//! The models hold them as `String`s, one allocation per occurrence. The views of this module, e.g.
+//! [`SharedNamedApiResource`], deserialize from the same payloads but hold them as [`SharedStr`]s, interned when decoded,
+//! so that every occurrence of the same string shares a single allocation. They are meant to be used by the models of
+//! the applications keeping only the fields they need, and convert from and into the models of `rustemon`.
+//!
+//! The interner is global, and never bounded: the strings interned stay in memory once every [`SharedStr`] holding them
+//! is dropped, to be shared with the next views decoded, until [`release_unused`] is called.
+
+use std::{
+    borrow::Borrow,
+    collections::HashSet,
+    fmt,
+    hash::{DefaultHasher, Hash, Hasher},
+    marker::PhantomData,
+    ops::Deref,
+    sync::{Arc, Mutex, OnceLock, PoisonError},
+};
+
+use super::{
+    games::Version,
+    resource::{ApiResource, FlavorText, Name, NamedApiResource},
+    utility::Language,
+};
+
+/// Number of shards of the interner, each behind its own lock so that concurrent decodings seldom wait for each other.
+const SHARDS: usize = 64;
+
+/// Interned strings, spread over shards by hash, kept until released by [`release_unused`].
+fn interner() -> &'static [Mutex<HashSet<Arc<str>>>; SHARDS] {
+    static INTERNER: OnceLock<[Mutex<HashSet<Arc<str>>>; SHARDS]> = OnceLock::new();
+
+    INTERNER.get_or_init(|| std::array::from_fn(|_| Mutex::default()))
+}
+
+/// Shard of the interner holding `s`.
+fn shard(s: &str) -> &'static Mutex<HashSet<Arc<str>>> {
+    let mut hasher = DefaultHasher::new();
+    s.hash(&mut hasher);
+
+    &interner()[hasher.finish() as usize % SHARDS]
+}
+
+/// Removes from the interner the strings no longer held by any [`SharedStr`], returning how many were released.
+///
+/// The strings are kept interned when the views holding them are dropped, to be shared with the next views decoded, the
+/// interner only growing otherwise. Calling this after dropping a dataset gives their memory back.
+pub fn release_unused() -> usize {
+    interner()
+        .iter()
+        .map(|shard| {
+            let mut shard = shard.lock().unwrap_or_else(PoisonError::into_inner);
+            let before = shard.len();
+            shard.retain(|s| Arc::strong_count(s) > 1);
+
+            before - shard.len()
+        })
+        .sum()
+}
+
+/// Immutable string interned when created, shared by every [`SharedStr`] holding the same value.
+///
+/// It dereferences to a `str`, and compares with `str`s and `String`s.
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SharedStr(Arc<str>);
+
+impl SharedStr {
+    /// Returns the interned string equal to `s`, interning it if needed.
+    pub fn new(s: &str) -> Self {
+        let mut shard = shard(s).lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(shared) = shard.get(s) {
+            return Self(Arc::clone(shared));
+        }
+        let shared: Arc<str> = Arc::from(s);
+        shard.insert(Arc::clone(&shared));
+
+        Self(shared)
+    }
+
+    /// Extracts a string slice containing the entire string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for SharedStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for SharedStr {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for SharedStr {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Hash for SharedStr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl fmt::Debug for SharedStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.0, f)
+    }
+}
+
+impl fmt::Display for SharedStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&*self.0, f)
+    }
+}
+
+impl From<&str> for SharedStr {
+    fn from(s: &str) -> Self {
+        Self::new(s)
+    }
+}
+
+impl From<String> for SharedStr {
+    fn from(s: String) -> Self {
+        Self::new(&s)
+    }
+}
+
+impl From<SharedStr> for String {
+    fn from(s: SharedStr) -> Self {
+        s.0.to_string()
+    }
+}
+
+impl PartialEq<str> for SharedStr {
+    fn eq(&self, other: &str) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl PartialEq<&str> for SharedStr {
+    fn eq(&self, other: &&str) -> bool {
+        &*self.0 == *other
+    }
+}
+
+impl PartialEq<String> for SharedStr {
+    fn eq(&self, other: &String) -> bool {
+        *self.0 == **other
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for SharedStr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = SharedStr;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<SharedStr, E> {
+                Ok(SharedStr::new(s))
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl serde::Serialize for SharedStr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+/// Interned view of a [`NamedApiResource`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct SharedNamedApiResource<T> {
+    /// The name of the referenced resource.
+    pub name: SharedStr,
+    /// The URL of the referenced resource.
+    pub url: SharedStr,
+    #[serde(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T> SharedNamedApiResource<T> {
+    /// Creates a reference to the resource with the given name and URL, interning them.
+    pub fn new(name: impl Into<SharedStr>, url: impl Into<SharedStr>) -> Self {
+        Self {
+            name: name.into(),
+            url: url.into(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> From<&NamedApiResource<T>> for SharedNamedApiResource<T> {
+    fn from(resource: &NamedApiResource<T>) -> Self {
+        Self::new(resource.name.as_str(), resource.url.as_str())
+    }
+}
+
+impl<T> From<NamedApiResource<T>> for SharedNamedApiResource<T> {
+    fn from(resource: NamedApiResource<T>) -> Self {
+        Self::from(&resource)
+    }
+}
+
+impl<T> From<SharedNamedApiResource<T>> for NamedApiResource<T> {
+    fn from(resource: SharedNamedApiResource<T>) -> Self {
+        Self::new(resource.name, resource.url)
+    }
+}
+
+/// Interned view of an [`ApiResource`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct SharedApiResource<T> {
+    /// The URL of the referenced resource.
+    pub url: SharedStr,
+    #[serde(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T> SharedApiResource<T> {
+    /// Creates a reference to the resource with the given URL, interning it.
+    pub fn new(url: impl Into<SharedStr>) -> Self {
+        Self {
+            url: url.into(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> From<&ApiResource<T>> for SharedApiResource<T> {
+    fn from(resource: &ApiResource<T>) -> Self {
+        Self::new(resource.url.as_str())
+    }
+}
+
+impl<T> From<ApiResource<T>> for SharedApiResource<T> {
+    fn from(resource: ApiResource<T>) -> Self {
+        Self::from(&resource)
+    }
+}
+
+impl<T> From<SharedApiResource<T>> for ApiResource<T> {
+    fn from(resource: SharedApiResource<T>) -> Self {
+        Self::new(resource.url)
+    }
+}
+
+/// Interned view of a [`Name`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct SharedName {
+    /// The localized name for an API resource in a specific language.
+    pub name: SharedStr,
+    /// The language this name is in.
+    pub language: SharedNamedApiResource<Language>,
+}
+
+impl From<&Name> for SharedName {
+    fn from(name: &Name) -> Self {
+        Self {
+            name: SharedStr::new(&name.name),
+            language: SharedNamedApiResource::from(&name.language),
+        }
+    }
+}
+
+impl From<SharedName> for Name {
+    fn from(name: SharedName) -> Self {
+        Self {
+            name: name.name.into(),
+            language: name.language.into(),
+            extra: Default::default(),
+        }
+    }
+}
+
+/// Interned view of a [`FlavorText`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct SharedFlavorText {
+    /// The localized flavor text for an API resource in a specific language.
+    pub flavor_text: SharedStr,
+    /// The language this name is in.
+    pub language: SharedNamedApiResource<Language>,
+    /// The game version this flavor text is extracted from.
+    pub version: Option<SharedNamedApiResource<Version>>,
+}
+
+impl From<&FlavorText> for SharedFlavorText {
+    fn from(flavor_text: &FlavorText) -> Self {
+        Self {
+            flavor_text: SharedStr::new(&flavor_text.flavor_text),
+            language: SharedNamedApiResource::from(&flavor_text.language),
+            version: flavor_text
+                .version
+                .as_ref()
+                .map(SharedNamedApiResource::from),
+        }
+    }
+}
+
+impl From<SharedFlavorText> for FlavorText {
+    fn from(flavor_text: SharedFlavorText) -> Self {
+        Self {
+            flavor_text: flavor_text.flavor_text.into(),
+            language: flavor_text.language.into(),
+            version: flavor_text.version.map(Into::into),
+            extra: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,593 @@
+//! Rendering of the texts of the models, as plain text, Markdown or HTML.
+//!
+//! The effects, e.g. [`VerboseEffect`], hold `$effect_chance` placeholders and an inline markup referring to other
+//! resources, e.g. `[paralyze]{mechanic:paralysis}` or `[Thunder]{move:thunder}`. They are rendered into a
+//! [`RichText`], the placeholders substituted from an [`EffectChance`] and the markup parsed into [`Link`]s.
+//!
+//! The flavor texts, e.g. [`FlavorText`], hold the line breaks, form feeds and soft hyphens of the games they come from.
+//! They are normalized into a single line of text.
+//!
+//! ```rust
+//! use rustemon::model::text::RichText;
+//!
+//! let effect = RichText::parse("Has a $effect_chance% chance to [paralyze]{mechanic:paralysis} the target.")
+//!     .with_effect_chance(Some(30));
+//! assert_eq!(effect.to_plain(), "Has a 30% chance to paralyze the target.");
+//! ```
+
+use std::{borrow::Cow, fmt};
+
+use crate::{client::Environment, error::Error};
+
+use super::{
+    items::Item,
+    locations::Location,
+    moves::{Move, MoveFlavorText, MoveSummary, PastMoveStatValues},
+    pokemon::{Ability, AbilityFlavorText, PokemonSpecies, Type},
+    resource::{Effect, FlavorText, NamedApiResource, VerboseEffect, VersionGroupFlavorText},
+};
+
+/// Placeholder of the effects substituted by the chance of the effect.
+const EFFECT_CHANCE: &str = "$effect_chance";
+
+/// Source of the `$effect_chance` of an effect.
+pub trait EffectChance {
+    /// The percent value of the chance of the effect occurring, if any.
+    fn effect_chance(&self) -> Option<i64>;
+}
+
+impl EffectChance for Move {
+    fn effect_chance(&self) -> Option<i64> {
+        self.effect_chance
+    }
+}
+
+impl EffectChance for MoveSummary {
+    fn effect_chance(&self) -> Option<i64> {
+        self.effect_chance
+    }
+}
+
+/// The chance of the effect of the move before the version group of the entry.
+///
+/// The entries only hold the chance when it changed, the chance being the same as in the next newer entry otherwise,
+/// as done by [`Move::as_of`]. The first chance held by the entry or by the newer ones, listed after it in
+/// [`Move::past_values`], applies, or else the chance of the [`Move`].
+impl EffectChance for (&PastMoveStatValues, &Move) {
+    fn effect_chance(&self) -> Option<i64> {
+        let (past, move_) = *self;
+        let newer = move_
+            .past_values
+            .iter()
+            .position(|entry| entry == past)
+            .map_or(&[][..], |index| &move_.past_values[index..]);
+
+        past.effect_chance
+            .or_else(|| newer.iter().find_map(|entry| entry.effect_chance))
+            .or(move_.effect_chance)
+    }
+}
+
+impl EffectChance for Option<i64> {
+    fn effect_chance(&self) -> Option<i64> {
+        *self
+    }
+}
+
+impl Effect {
+    /// The effect, its chance taken from `source`, e.g. a [`Move`] or one of its [`PastMoveStatValues`] along with
+    /// the move.
+    pub fn render(&self, source: &impl EffectChance) -> RichText {
+        RichText::parse(&self.effect).with_effect_chance(source.effect_chance())
+    }
+}
+
+impl VerboseEffect {
+    /// The effect, its chance taken from `source`, e.g. a [`Move`] or one of its [`PastMoveStatValues`] along with
+    /// the move.
+    pub fn render_effect(&self, source: &impl EffectChance) -> RichText {
+        RichText::parse(&self.effect).with_effect_chance(source.effect_chance())
+    }
+
+    /// The short effect, its chance taken from `source`, e.g. a [`Move`] or one of its [`PastMoveStatValues`] along with
+    /// the move.
+    pub fn render_short_effect(&self, source: &impl EffectChance) -> RichText {
+        RichText::parse(&self.short_effect).with_effect_chance(source.effect_chance())
+    }
+}
+
+/// Normalizes a flavor text into a single line: the soft hyphens breaking words are removed, and every line break, form
+/// feed or run of spaces is replaced by a single space.
+///
+/// ```rust
+/// use rustemon::model::text::normalize_flavor_text;
+///
+/// let text = "Obviously prefers\nhot places. When\nit rains, steam\u{c}is said to spout\nfrom the tip of\nits tail.";
+/// assert_eq!(
+///     normalize_flavor_text(text),
+///     "Obviously prefers hot places. When it rains, steam is said to spout from the tip of its tail."
+/// );
+/// assert_eq!(normalize_flavor_text("A POKé\u{ad}\nMON with\u{ad}out-\nequal."), "A POKéMON without-equal.");
+/// ```
+pub fn normalize_flavor_text(text: &str) -> String {
+    let text = text
+        .replace("\u{ad}\n", "")
+        .replace('\u{ad}', "")
+        .replace("-\n", "-");
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+macro_rules! flavor_text {
+    ($($entry:ty => $text:ident),+ $(,)?) => {
+        $(
+            impl $entry {
+                /// The text, normalized into a single line. See [`normalize_flavor_text`].
+                pub fn normalized(&self) -> String {
+                    normalize_flavor_text(&self.$text)
+                }
+
+                /// The normalized text, to be rendered as plain text, Markdown or HTML.
+                pub fn rich_text(&self) -> RichText {
+                    RichText::from(self.normalized())
+                }
+            }
+        )+
+    };
+}
+
+flavor_text!(
+    FlavorText => flavor_text,
+    MoveFlavorText => flavor_text,
+    VersionGroupFlavorText => text,
+    AbilityFlavorText => flavor_text,
+);
+
+/// Resource referred to by the markup of an effect, e.g. `{move:thunder}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkTarget {
+    /// A mechanic of the games, e.g. `paralysis`, having no resource.
+    Mechanic(String),
+    /// A move, e.g. `thunder`.
+    Move(NamedApiResource<Move>),
+    /// An ability, e.g. `static`.
+    Ability(NamedApiResource<Ability>),
+    /// A type, e.g. `electric`.
+    Type(NamedApiResource<Type>),
+    /// An item, e.g. `light-ball`.
+    Item(NamedApiResource<Item>),
+    /// A Pokémon species, e.g. `pikachu`.
+    Pokemon(NamedApiResource<PokemonSpecies>),
+    /// A location, e.g. `viridian-forest`.
+    Location(NamedApiResource<Location>),
+    /// Any other kind of reference.
+    Other {
+        /// The kind of the reference, e.g. `"condition"`.
+        kind: String,
+        /// The name of the referred value.
+        name: String,
+    },
+}
+
+impl LinkTarget {
+    /// Creates the target of a reference of the given kind, e.g. `"move"`, to the given name.
+    fn new(kind: &str, name: &str, environment: &Environment) -> Result<Self, Error> {
+        Ok(match kind {
+            "mechanic" => Self::Mechanic(name.to_owned()),
+            "move" => Self::Move(NamedApiResource::from_name(environment, name)?),
+            "ability" => Self::Ability(NamedApiResource::from_name(environment, name)?),
+            "type" => Self::Type(NamedApiResource::from_name(environment, name)?),
+            "item" => Self::Item(NamedApiResource::from_name(environment, name)?),
+            "pokemon" => Self::Pokemon(NamedApiResource::from_name(environment, name)?),
+            "location" => Self::Location(NamedApiResource::from_name(environment, name)?),
+            _ => Self::Other {
+                kind: kind.to_owned(),
+                name: name.to_owned(),
+            },
+        })
+    }
+
+    /// The name of the referred value, e.g. `"thunder"`.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Mechanic(name) | Self::Other { name, .. } => name,
+            Self::Move(resource) => &resource.name,
+            Self::Ability(resource) => &resource.name,
+            Self::Type(resource) => &resource.name,
+            Self::Item(resource) => &resource.name,
+            Self::Pokemon(resource) => &resource.name,
+            Self::Location(resource) => &resource.name,
+        }
+    }
+
+    /// The URL of the referred resource, if any.
+    pub fn url(&self) -> Option<&str> {
+        match self {
+            Self::Mechanic(_) | Self::Other { .. } => None,
+            Self::Move(resource) => Some(&resource.url),
+            Self::Ability(resource) => Some(&resource.url),
+            Self::Type(resource) => Some(&resource.url),
+            Self::Item(resource) => Some(&resource.url),
+            Self::Pokemon(resource) => Some(&resource.url),
+            Self::Location(resource) => Some(&resource.url),
+        }
+    }
+}
+
+/// Reference of an effect to another resource, e.g. `[Thunder]{move:thunder}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Link {
+    /// The text of the reference, empty when the name of the target is displayed instead, e.g. `[]{move:thunder}`.
+    pub label: String,
+    /// The referred resource.
+    pub target: LinkTarget,
+}
+
+impl Link {
+    /// The text displayed for the reference: its label, or else the name of the target, e.g. `"light ball"`.
+    pub fn text(&self) -> Cow<'_, str> {
+        if self.label.is_empty() {
+            Cow::Owned(self.target.name().replace('-', " "))
+        } else {
+            Cow::Borrowed(&self.label)
+        }
+    }
+}
+
+/// Part of a [`RichText`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RichNode {
+    /// Text displayed as is.
+    Text(String),
+    /// Reference to another resource.
+    Link(Link),
+}
+
+/// Text made of plain text and references to other resources, parsed from the markup of the effects.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RichText {
+    /// The parts of the text, in order.
+    pub nodes: Vec<RichNode>,
+}
+
+impl RichText {
+    /// Parses the markup of `text`, referring to the resources of the production environment.
+    ///
+    /// Brackets not forming a reference, e.g. `[sic]`, are kept as text.
+    ///
+    /// ```rust
+    /// use rustemon::model::text::{LinkTarget, RichNode, RichText};
+    ///
+    /// let text = RichText::parse("Has a chance to [paralyze]{mechanic:paralysis}. See [Thunder]{move:thunder}.");
+    /// let RichNode::Link(thunder) = &text.nodes[3] else { panic!() };
+    /// assert_eq!(thunder.label, "Thunder");
+    /// assert_eq!(thunder.target.url(), Some("https://pokeapi.co/api/v2/move/thunder/"));
+    /// ```
+    pub fn parse(text: &str) -> Self {
+        Self::parse_in(text, &Environment::Production)
+            .expect("the URL of the production environment is valid")
+    }
+
+    /// Parses the markup of `text`, referring to the resources of the given `environment`.
+    pub fn parse_in(text: &str, environment: &Environment) -> Result<Self, Error> {
+        let mut rich_text = Self::default();
+        let mut rest = text;
+        while let Some(start) = rest.find('[') {
+            let (before, candidate) = rest.split_at(start);
+            rich_text.push_text(before);
+            match parse_link(candidate) {
+                Some((label, kind, name, len)) => {
+                    rich_text.nodes.push(RichNode::Link(Link {
+                        label: label.to_owned(),
+                        target: LinkTarget::new(kind, name, environment)?,
+                    }));
+                    rest = &candidate[len..];
+                }
+                None => {
+                    rich_text.push_text("[");
+                    rest = &candidate[1..];
+                }
+            }
+        }
+        rich_text.push_text(rest);
+
+        Ok(rich_text)
+    }
+
+    /// Substitutes the `$effect_chance` placeholders of the text by the given chance, leaving them if `None`.
+    pub fn with_effect_chance(mut self, effect_chance: Option<i64>) -> Self {
+        if let Some(effect_chance) = effect_chance {
+            for node in &mut self.nodes {
+                if let RichNode::Text(text) = node
+                    && text.contains(EFFECT_CHANCE)
+                {
+                    *text = text.replace(EFFECT_CHANCE, &effect_chance.to_string());
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Every reference of the text, in order.
+    pub fn links(&self) -> impl Iterator<Item = &Link> {
+        self.nodes.iter().filter_map(|node| match node {
+            RichNode::Link(link) => Some(link),
+            RichNode::Text(_) => None,
+        })
+    }
+
+    /// Renders the text without markup, the references replaced by their text.
+    pub fn to_plain(&self) -> String {
+        let mut plain = String::new();
+        for node in &self.nodes {
+            match node {
+                RichNode::Text(text) => plain.push_str(text),
+                RichNode::Link(link) => plain.push_str(&link.text()),
+            }
+        }
+
+        plain
+    }
+
+    /// Renders the text as Markdown, the references to resources becoming links to their URL.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        for node in &self.nodes {
+            match node {
+                RichNode::Text(text) => markdown.push_str(&escape_markdown(text)),
+                RichNode::Link(link) => match link.target.url() {
+                    Some(url) => {
+                        markdown.push_str(&format!("[{}]({url})", escape_markdown(&link.text())))
+                    }
+                    None => markdown.push_str(&escape_markdown(&link.text())),
+                },
+            }
+        }
+
+        markdown
+    }
+
+    /// Renders the text as HTML, the references to resources becoming links to their URL, and the mechanics being
+    /// wrapped in a `<span class="mechanic">`. Line breaks become `<br>`s.
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        for node in &self.nodes {
+            match node {
+                RichNode::Text(text) => html.push_str(&escape_html(text).replace('\n', "<br>")),
+                RichNode::Link(link) => {
+                    let text = escape_html(&link.text());
+                    match &link.target {
+                        LinkTarget::Mechanic(name) => html.push_str(&format!(
+                            r#"<span class="mechanic" data-mechanic="{}">{text}</span>"#,
+                            escape_html(name)
+                        )),
+                        target => match target.url() {
+                            Some(url) => html
+                                .push_str(&format!(r#"<a href="{}">{text}</a>"#, escape_html(url))),
+                            None => html.push_str(&text),
+                        },
+                    }
+                }
+            }
+        }
+
+        html
+    }
+
+    /// Appends `text`, merging it with the text ending the rich text, if any.
+    fn push_text(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        match self.nodes.last_mut() {
+            Some(RichNode::Text(last)) => last.push_str(text),
+            _ => self.nodes.push(RichNode::Text(text.to_owned())),
+        }
+    }
+}
+
+impl From<String> for RichText {
+    fn from(text: String) -> Self {
+        Self {
+            nodes: vec![RichNode::Text(text)],
+        }
+    }
+}
+
+impl fmt::Display for RichText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_plain())
+    }
+}
+
+/// Parses the reference starting `candidate`, e.g. `[Thunder]{move:thunder}`, returning its label, kind, name and
+/// length.
+fn parse_link(candidate: &str) -> Option<(&str, &str, &str, usize)> {
+    let label_end = candidate.find(']')?;
+    let label = &candidate[1..label_end];
+    if label.contains('[') {
+        return None;
+    }
+    let reference = candidate[label_end + 1..].strip_prefix('{')?;
+    let reference_end = reference.find('}')?;
+    let (kind, name) = reference[..reference_end].split_once(':')?;
+
+    Some((label, kind, name, label_end + 2 + reference_end + 1))
+}
+
+/// Escapes the characters having a meaning in Markdown.
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+/// Escapes the characters having a meaning in HTML.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    /// Thunder, its chance to paralyze having changed in two version groups, as listed from the oldest to the newest.
+    fn thunder() -> Move {
+        let past = |version_group: &str, effect_chance: Option<i64>| {
+            json!({
+                "accuracy": null,
+                "effect_chance": effect_chance,
+                "power": null,
+                "pp": null,
+                "effect_entries": [],
+                "type": null,
+                "version_group": {
+                    "name": version_group,
+                    "url": format!("https://pokeapi.co/api/v2/version-group/{version_group}/")
+                }
+            })
+        };
+
+        serde_json::from_value(json!({
+            "id": 87,
+            "name": "thunder",
+            "accuracy": 70,
+            "effect_chance": 30,
+            "pp": 10,
+            "priority": 0,
+            "power": 110,
+            "contest_combos": null,
+            "contest_type": null,
+            "contest_effect": null,
+            "damage_class": { "name": "special", "url": "https://pokeapi.co/api/v2/move-damage-class/3/" },
+            "effect_entries": [],
+            "effect_changes": [],
+            "learned_by_pokemon": [],
+            "flavor_text_entries": [],
+            "generation": { "name": "generation-i", "url": "https://pokeapi.co/api/v2/generation/1/" },
+            "machines": [],
+            "meta": null,
+            "names": [],
+            "past_values": [
+                past("gold-silver", Some(10)),
+                past("ruby-sapphire", None),
+                past("diamond-pearl", Some(20)),
+                past("x-y", None),
+            ],
+            "stat_changes": [],
+            "super_contest_effect": null,
+            "target": { "name": "selected-pokemon", "url": "https://pokeapi.co/api/v2/move-target/10/" },
+            "type": { "name": "electric", "url": "https://pokeapi.co/api/v2/type/13/" }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn past_values_hold_the_chance_when_it_changed() {
+        let thunder = thunder();
+
+        assert_eq!(
+            (&thunder.past_values[0], &thunder).effect_chance(),
+            Some(10)
+        );
+        assert_eq!(
+            (&thunder.past_values[2], &thunder).effect_chance(),
+            Some(20)
+        );
+    }
+
+    #[test]
+    fn past_values_without_a_chance_take_the_one_of_the_next_newer_entry() {
+        let thunder = thunder();
+
+        assert_eq!(
+            (&thunder.past_values[1], &thunder).effect_chance(),
+            Some(20)
+        );
+    }
+
+    #[test]
+    fn past_values_without_newer_chances_take_the_one_of_the_move() {
+        let thunder = thunder();
+
+        assert_eq!(
+            (&thunder.past_values[3], &thunder).effect_chance(),
+            Some(30)
+        );
+    }
+
+    #[test]
+    fn placeholders_are_substituted_by_the_chance() {
+        let text = RichText::parse("Has a $effect_chance% chance to paralyze.");
+
+        assert_eq!(
+            text.clone().with_effect_chance(Some(30)).to_plain(),
+            "Has a 30% chance to paralyze."
+        );
+        assert_eq!(
+            text.with_effect_chance(None).to_plain(),
+            "Has a $effect_chance% chance to paralyze."
+        );
+    }
+
+    #[test]
+    fn markdown_links_the_resources_and_escapes_the_text() {
+        let text = RichText::parse(
+            "Can [paralyze]{mechanic:paralysis} *2* targets. See [Thunder]{move:thunder} and []{item:light-ball}.",
+        );
+
+        assert_eq!(
+            text.to_markdown(),
+            "Can paralyze \\*2\\* targets. See [Thunder](https://pokeapi.co/api/v2/move/thunder/) and \
+             [light ball](https://pokeapi.co/api/v2/item/light-ball/)."
+        );
+    }
+
+    #[test]
+    fn html_links_the_resources_and_escapes_the_text() {
+        let text = RichText::parse(
+            "Can [paralyze]{mechanic:paralysis} <all> targets.\nSee [Thunder & co]{move:thunder}, [sic] [x]{condition:y}.",
+        );
+
+        assert_eq!(
+            text.to_html(),
+            "Can <span class=\"mechanic\" data-mechanic=\"paralysis\">paralyze</span> &lt;all&gt; targets.<br>\
+             See <a href=\"https://pokeapi.co/api/v2/move/thunder/\">Thunder &amp; co</a>, [sic] x."
+        );
+    }
+
+    #[test]
+    fn flavor_texts_are_normalized_into_a_single_line() {
+        assert_eq!(
+            normalize_flavor_text(
+                "Obviously prefers\nhot places.\u{c}When it rains, steam\nis said to spout."
+            ),
+            "Obviously prefers hot places. When it rains, steam is said to spout."
+        );
+        assert_eq!(
+            normalize_flavor_text("A POKé\u{ad}\nMON with\u{ad}out-\nequal."),
+            "A POKéMON without-equal."
+        );
+        assert_eq!(normalize_flavor_text("  \n "), "");
+    }
+}
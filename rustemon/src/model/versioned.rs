@@ -0,0 +1,263 @@
+//! Data of the models tagged with a version or a version group, filtered to a given game.
+//!
+//! The collections of tagged entries, e.g. `Vec<MoveFlavorText>`, implement [`Versioned`], filtering them to a
+//! [`VersionScope`], picking their latest entry or grouping them by version.
+//!
+//! ```rust
+//! use rustemon::model::versioned::{VersionScope, Versioned};
+//! # fn moves(pokemon: rustemon::model::pokemon::Pokemon) {
+//!
+//! // The moves learned in Sword or Shield, by level up or otherwise.
+//! let sword_shield = VersionScope::version_group("sword-shield").with_versions(["sword", "shield"]);
+//! let moves = pokemon
+//!     .moves
+//!     .iter()
+//!     .filter(|m| m.version_group_details.any_in_scope(&sword_shield));
+//! # }
+//! ```
+
+use std::cmp::Ordering;
+
+use super::{
+    evolution::EvolutionDetail,
+    games::{Version, VersionGroup},
+    items::ItemHolderPokemonVersionDetail,
+    locations::EncounterVersionDetails,
+    moves::{MoveFlavorText, PastMoveStatValues},
+    pokemon::{AbilityEffectChange, AbilityFlavorText, PokemonHeldItemVersion, PokemonMoveVersion},
+    resource::{
+        FlavorText, MachineVersionDetail, NamedApiResource, VersionEncounterDetail,
+        VersionGameIndex, VersionGroupFlavorText,
+    },
+};
+
+/// Version or version group an entry is tagged with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionTag<'a> {
+    /// A version, e.g. `sword`.
+    Version(&'a NamedApiResource<Version>),
+    /// A version group, e.g. `sword-shield`.
+    VersionGroup(&'a NamedApiResource<VersionGroup>),
+}
+
+impl<'a> VersionTag<'a> {
+    /// The name of the version or version group.
+    pub fn name(&self) -> &'a str {
+        match self {
+            Self::Version(version) => &version.name,
+            Self::VersionGroup(version_group) => &version_group.name,
+        }
+    }
+
+    /// The id of the version or version group.
+    ///
+    /// The ids follow the order in which the games were added to `PokeApi` rather than their release, e.g. Colosseum
+    /// and XD come after Black and White. See [`Versioned::latest`] to order them by release.
+    pub fn id(&self) -> Option<i64> {
+        match self {
+            Self::Version(version) => version.id(),
+            Self::VersionGroup(version_group) => version_group.id(),
+        }
+    }
+}
+
+/// Entry of a model tagged with a version or a version group, e.g. a [`FlavorText`].
+pub trait VersionTagged {
+    /// The version or version group of the entry, if any.
+    fn version_tag(&self) -> Option<VersionTag<'_>>;
+}
+
+macro_rules! version_tagged {
+    ($tag:ident: $($entry:ty => $field:ident),+ $(,)?) => {
+        $(
+            impl VersionTagged for $entry {
+                fn version_tag(&self) -> Option<VersionTag<'_>> {
+                    Some(VersionTag::$tag(&self.$field))
+                }
+            }
+        )+
+    };
+}
+
+version_tagged!(Version:
+    ItemHolderPokemonVersionDetail => version,
+    EncounterVersionDetails => version,
+    PokemonHeldItemVersion => version,
+    VersionEncounterDetail => version,
+    VersionGameIndex => version,
+);
+
+version_tagged!(VersionGroup:
+    EvolutionDetail => version_group,
+    MoveFlavorText => version_group,
+    PastMoveStatValues => version_group,
+    AbilityEffectChange => version_group,
+    AbilityFlavorText => version_group,
+    PokemonMoveVersion => version_group,
+    MachineVersionDetail => version_group,
+    VersionGroupFlavorText => version_group,
+);
+
+impl VersionTagged for FlavorText {
+    fn version_tag(&self) -> Option<VersionTag<'_>> {
+        self.version.as_ref().map(VersionTag::Version)
+    }
+}
+
+/// Versions and version groups making up a game, e.g. Sword and Shield.
+///
+/// An entry is in the scope when tagged with one of its versions or version groups. Scoping to both a version group
+/// and its versions thus matches the entries tagged either way.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VersionScope {
+    versions: Vec<String>,
+    version_groups: Vec<String>,
+}
+
+impl VersionScope {
+    /// Creates a scope made of the given version, e.g. `sword`.
+    pub fn version(version: impl Into<String>) -> Self {
+        Self::default().with_version(version)
+    }
+
+    /// Creates a scope made of the given version group, e.g. `sword-shield`.
+    pub fn version_group(version_group: impl Into<String>) -> Self {
+        Self::default().with_version_group(version_group)
+    }
+
+    /// Creates a scope made of the given version group and its versions.
+    pub fn of_version_group(version_group: &VersionGroup) -> Self {
+        Self::version_group(&version_group.name).with_versions(
+            version_group
+                .versions
+                .iter()
+                .map(|version| version.name.as_str()),
+        )
+    }
+
+    /// Creates a scope made of the given version and its version group.
+    pub fn of_version(version: &Version) -> Self {
+        Self::version(&version.name).with_version_group(version.version_group.name.as_str())
+    }
+
+    /// Adds the given version to the scope.
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.versions.push(version.into());
+        self
+    }
+
+    /// Adds the given versions to the scope.
+    pub fn with_versions(mut self, versions: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.versions.extend(versions.into_iter().map(Into::into));
+        self
+    }
+
+    /// Adds the given version group to the scope.
+    pub fn with_version_group(mut self, version_group: impl Into<String>) -> Self {
+        self.version_groups.push(version_group.into());
+        self
+    }
+
+    /// The versions of the scope.
+    pub fn versions(&self) -> impl Iterator<Item = &str> {
+        self.versions.iter().map(String::as_str)
+    }
+
+    /// The version groups of the scope.
+    pub fn version_groups(&self) -> impl Iterator<Item = &str> {
+        self.version_groups.iter().map(String::as_str)
+    }
+
+    /// Whether the given tag is one of the versions or version groups of the scope.
+    pub fn contains(&self, tag: VersionTag<'_>) -> bool {
+        match tag {
+            VersionTag::Version(version) => self.versions.iter().any(|v| *v == *version.name),
+            VersionTag::VersionGroup(version_group) => self
+                .version_groups
+                .iter()
+                .any(|v| *v == *version_group.name),
+        }
+    }
+}
+
+/// Collection of entries tagged with a version or a version group, e.g. `Vec<FlavorText>`.
+pub trait Versioned {
+    /// The entries of the collection.
+    type Entry: VersionTagged;
+
+    /// Every entry of the collection.
+    fn tagged_entries(&self) -> &[Self::Entry];
+
+    /// Every entry in `scope`, in order.
+    fn in_scope<'a>(&'a self, scope: &'a VersionScope) -> impl Iterator<Item = &'a Self::Entry> {
+        self.tagged_entries()
+            .iter()
+            .filter(move |entry| entry.version_tag().is_some_and(|tag| scope.contains(tag)))
+    }
+
+    /// Whether any entry is in `scope`.
+    fn any_in_scope(&self, scope: &VersionScope) -> bool {
+        self.in_scope(scope).next().is_some()
+    }
+
+    /// The entry of the latest version or version group, the first one among those ordered equally.
+    ///
+    /// The versions and version groups are ordered by `compare`, e.g. following the order in which the games were
+    /// released, the ones it can't compare, e.g. unknown ones, being left out.
+    ///
+    /// ```rust
+    /// use std::cmp::Ordering;
+    ///
+    /// use rustemon::model::versioned::{VersionTag, Versioned};
+    /// # fn texts(texts: Vec<rustemon::model::resource::VersionGroupFlavorText>) {
+    ///
+    /// let releases = ["ruby-sapphire", "colosseum", "xd", "black-white"];
+    /// let release = |tag: &VersionTag<'_>| releases.iter().position(|name| *name == tag.name());
+    /// let latest = texts.latest(|a, b| Some(release(a)?.cmp(&release(b)?)));
+    /// # }
+    /// ```
+    fn latest(
+        &self,
+        compare: impl Fn(&VersionTag<'_>, &VersionTag<'_>) -> Option<Ordering>,
+    ) -> Option<&Self::Entry> {
+        let mut latest: Option<(&Self::Entry, VersionTag<'_>)> = None;
+        for entry in self.tagged_entries() {
+            let Some(tag) = entry.version_tag() else {
+                continue;
+            };
+            let later = match latest {
+                None => compare(&tag, &tag).is_some(),
+                Some((_, latest_tag)) => compare(&tag, &latest_tag) == Some(Ordering::Greater),
+            };
+            if later {
+                latest = Some((entry, tag));
+            }
+        }
+
+        latest.map(|(entry, _)| entry)
+    }
+
+    /// The entries grouped by version or version group, in order of first appearance.
+    fn group_by_version(&self) -> Vec<(VersionTag<'_>, Vec<&Self::Entry>)> {
+        let mut groups: Vec<(VersionTag<'_>, Vec<&Self::Entry>)> = Vec::new();
+        for entry in self.tagged_entries() {
+            let Some(tag) = entry.version_tag() else {
+                continue;
+            };
+            match groups.iter_mut().find(|(group, _)| *group == tag) {
+                Some((_, entries)) => entries.push(entry),
+                None => groups.push((tag, vec![entry])),
+            }
+        }
+
+        groups
+    }
+}
+
+impl<E: VersionTagged> Versioned for [E] {
+    type Entry = E;
+
+    fn tagged_entries(&self) -> &[E] {
+        self
+    }
+}
@@ -6,6 +6,7 @@
 /// usually for the purpose of removing obstacles or exploring new areas.
 pub mod move_ {
     crate::endpoint!(crate::model::moves::Move; for "move");
+    crate::summary!(crate::model::moves::MoveSummary; for "move");
 }
 
 /// Move Ailments are status conditions caused by moves used during battle.
@@ -61,6 +61,7 @@ pub mod pokeathlon_stat {
 #[allow(clippy::module_inception)]
 pub mod pokemon {
     crate::endpoint!(crate::model::pokemon::Pokemon; for "pokemon"; with (encounters, Vec<crate::model::pokemon::LocationAreaEncounter>));
+    crate::summary!(crate::model::pokemon::PokemonSummary; for "pokemon");
 }
 
 /// Colors used for sorting Pokémon in a Pokédex. The color listed in the Pokédex is usually the color
@@ -0,0 +1,57 @@
+//! Middleware limiting the rate of calls reaching `PokeApi`.
+
+use std::{
+    sync::{Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use http::Extensions;
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next, Result};
+
+/// Spaces out the requests going through it, so that no more than `max_requests`
+/// are sent during any `period`.
+pub(crate) struct RateLimiter {
+    interval: Duration,
+    next_slot: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    /// Creates a limiter allowing `max_requests` every `period`.
+    pub(crate) fn new(max_requests: u32, period: Duration) -> Self {
+        Self {
+            interval: period / max_requests.max(1),
+            next_slot: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Reserves the next free slot, returning how long to wait before using it.
+    fn reserve_slot(&self) -> Duration {
+        let mut next_slot = self
+            .next_slot
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let now = Instant::now();
+        let slot = (*next_slot).max(now);
+        *next_slot = slot + self.interval;
+
+        slot - now
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for RateLimiter {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> Result<Response> {
+        let wait = self.reserve_slot();
+        if !wait.is_zero() {
+            futures_timer::Delay::new(wait).await;
+        }
+
+        next.run(req, extensions).await
+    }
+}
@@ -0,0 +1,572 @@
+//! Games known to the built-in [`Registry`](super::Registry), generated by `rustemon-static-test`.
+
+use super::{RawGeneration, RawRegion, RawVersion, RawVersionGroup};
+
+pub(super) static GENERATIONS: &[RawGeneration] = &[
+    RawGeneration {
+        id: 1,
+        name: "generation-i",
+        main_region: "kanto",
+    },
+    RawGeneration {
+        id: 2,
+        name: "generation-ii",
+        main_region: "johto",
+    },
+    RawGeneration {
+        id: 3,
+        name: "generation-iii",
+        main_region: "hoenn",
+    },
+    RawGeneration {
+        id: 4,
+        name: "generation-iv",
+        main_region: "sinnoh",
+    },
+    RawGeneration {
+        id: 5,
+        name: "generation-v",
+        main_region: "unova",
+    },
+    RawGeneration {
+        id: 6,
+        name: "generation-vi",
+        main_region: "kalos",
+    },
+    RawGeneration {
+        id: 7,
+        name: "generation-vii",
+        main_region: "alola",
+    },
+    RawGeneration {
+        id: 8,
+        name: "generation-viii",
+        main_region: "galar",
+    },
+    RawGeneration {
+        id: 9,
+        name: "generation-ix",
+        main_region: "paldea",
+    },
+];
+
+pub(super) static VERSION_GROUPS: &[RawVersionGroup] = &[
+    RawVersionGroup {
+        id: 28,
+        name: "red-green-japan",
+        order: 1,
+        generation: 1,
+        versions: &["red-japan", "green-japan"],
+        regions: &["kanto"],
+    },
+    RawVersionGroup {
+        id: 29,
+        name: "blue-japan",
+        order: 2,
+        generation: 1,
+        versions: &["blue-japan"],
+        regions: &["kanto"],
+    },
+    RawVersionGroup {
+        id: 1,
+        name: "red-blue",
+        order: 3,
+        generation: 1,
+        versions: &["red", "blue"],
+        regions: &["kanto"],
+    },
+    RawVersionGroup {
+        id: 2,
+        name: "yellow",
+        order: 4,
+        generation: 1,
+        versions: &["yellow"],
+        regions: &["kanto"],
+    },
+    RawVersionGroup {
+        id: 3,
+        name: "gold-silver",
+        order: 5,
+        generation: 2,
+        versions: &["gold", "silver"],
+        regions: &["kanto", "johto"],
+    },
+    RawVersionGroup {
+        id: 4,
+        name: "crystal",
+        order: 6,
+        generation: 2,
+        versions: &["crystal"],
+        regions: &["kanto", "johto"],
+    },
+    RawVersionGroup {
+        id: 5,
+        name: "ruby-sapphire",
+        order: 7,
+        generation: 3,
+        versions: &["ruby", "sapphire"],
+        regions: &["hoenn"],
+    },
+    RawVersionGroup {
+        id: 6,
+        name: "emerald",
+        order: 8,
+        generation: 3,
+        versions: &["emerald"],
+        regions: &["hoenn"],
+    },
+    RawVersionGroup {
+        id: 7,
+        name: "firered-leafgreen",
+        order: 9,
+        generation: 3,
+        versions: &["firered", "leafgreen"],
+        regions: &["kanto"],
+    },
+    RawVersionGroup {
+        id: 12,
+        name: "colosseum",
+        order: 10,
+        generation: 3,
+        versions: &["colosseum"],
+        regions: &[],
+    },
+    RawVersionGroup {
+        id: 13,
+        name: "xd",
+        order: 11,
+        generation: 3,
+        versions: &["xd"],
+        regions: &[],
+    },
+    RawVersionGroup {
+        id: 8,
+        name: "diamond-pearl",
+        order: 12,
+        generation: 4,
+        versions: &["diamond", "pearl"],
+        regions: &["sinnoh"],
+    },
+    RawVersionGroup {
+        id: 9,
+        name: "platinum",
+        order: 13,
+        generation: 4,
+        versions: &["platinum"],
+        regions: &["sinnoh"],
+    },
+    RawVersionGroup {
+        id: 10,
+        name: "heartgold-soulsilver",
+        order: 14,
+        generation: 4,
+        versions: &["heartgold", "soulsilver"],
+        regions: &["kanto", "johto"],
+    },
+    RawVersionGroup {
+        id: 11,
+        name: "black-white",
+        order: 15,
+        generation: 5,
+        versions: &["black", "white"],
+        regions: &["unova"],
+    },
+    RawVersionGroup {
+        id: 14,
+        name: "black-2-white-2",
+        order: 16,
+        generation: 5,
+        versions: &["black-2", "white-2"],
+        regions: &["unova"],
+    },
+    RawVersionGroup {
+        id: 15,
+        name: "x-y",
+        order: 17,
+        generation: 6,
+        versions: &["x", "y"],
+        regions: &["kalos"],
+    },
+    RawVersionGroup {
+        id: 16,
+        name: "omega-ruby-alpha-sapphire",
+        order: 18,
+        generation: 6,
+        versions: &["omega-ruby", "alpha-sapphire"],
+        regions: &["hoenn"],
+    },
+    RawVersionGroup {
+        id: 17,
+        name: "sun-moon",
+        order: 19,
+        generation: 7,
+        versions: &["sun", "moon"],
+        regions: &["alola"],
+    },
+    RawVersionGroup {
+        id: 18,
+        name: "ultra-sun-ultra-moon",
+        order: 20,
+        generation: 7,
+        versions: &["ultra-sun", "ultra-moon"],
+        regions: &["alola"],
+    },
+    RawVersionGroup {
+        id: 19,
+        name: "lets-go-pikachu-lets-go-eevee",
+        order: 21,
+        generation: 7,
+        versions: &["lets-go-pikachu", "lets-go-eevee"],
+        regions: &["kanto"],
+    },
+    RawVersionGroup {
+        id: 20,
+        name: "sword-shield",
+        order: 22,
+        generation: 8,
+        versions: &["sword", "shield"],
+        regions: &["galar"],
+    },
+    RawVersionGroup {
+        id: 21,
+        name: "the-isle-of-armor",
+        order: 23,
+        generation: 8,
+        versions: &["the-isle-of-armor"],
+        regions: &["galar"],
+    },
+    RawVersionGroup {
+        id: 22,
+        name: "the-crown-tundra",
+        order: 24,
+        generation: 8,
+        versions: &["the-crown-tundra"],
+        regions: &["galar"],
+    },
+    RawVersionGroup {
+        id: 23,
+        name: "brilliant-diamond-shining-pearl",
+        order: 25,
+        generation: 8,
+        versions: &["brilliant-diamond", "shining-pearl"],
+        regions: &["sinnoh"],
+    },
+    RawVersionGroup {
+        id: 24,
+        name: "legends-arceus",
+        order: 26,
+        generation: 8,
+        versions: &["legends-arceus"],
+        regions: &["hisui"],
+    },
+    RawVersionGroup {
+        id: 25,
+        name: "scarlet-violet",
+        order: 27,
+        generation: 9,
+        versions: &["scarlet", "violet"],
+        regions: &["paldea"],
+    },
+    RawVersionGroup {
+        id: 26,
+        name: "the-teal-mask",
+        order: 28,
+        generation: 9,
+        versions: &["the-teal-mask"],
+        regions: &["paldea"],
+    },
+    RawVersionGroup {
+        id: 27,
+        name: "the-indigo-disk",
+        order: 29,
+        generation: 9,
+        versions: &["the-indigo-disk"],
+        regions: &["paldea"],
+    },
+];
+
+pub(super) static VERSIONS: &[RawVersion] = &[
+    RawVersion {
+        id: 1,
+        name: "red",
+        version_group: "red-blue",
+    },
+    RawVersion {
+        id: 2,
+        name: "blue",
+        version_group: "red-blue",
+    },
+    RawVersion {
+        id: 3,
+        name: "yellow",
+        version_group: "yellow",
+    },
+    RawVersion {
+        id: 4,
+        name: "gold",
+        version_group: "gold-silver",
+    },
+    RawVersion {
+        id: 5,
+        name: "silver",
+        version_group: "gold-silver",
+    },
+    RawVersion {
+        id: 6,
+        name: "crystal",
+        version_group: "crystal",
+    },
+    RawVersion {
+        id: 7,
+        name: "ruby",
+        version_group: "ruby-sapphire",
+    },
+    RawVersion {
+        id: 8,
+        name: "sapphire",
+        version_group: "ruby-sapphire",
+    },
+    RawVersion {
+        id: 9,
+        name: "emerald",
+        version_group: "emerald",
+    },
+    RawVersion {
+        id: 10,
+        name: "firered",
+        version_group: "firered-leafgreen",
+    },
+    RawVersion {
+        id: 11,
+        name: "leafgreen",
+        version_group: "firered-leafgreen",
+    },
+    RawVersion {
+        id: 12,
+        name: "diamond",
+        version_group: "diamond-pearl",
+    },
+    RawVersion {
+        id: 13,
+        name: "pearl",
+        version_group: "diamond-pearl",
+    },
+    RawVersion {
+        id: 14,
+        name: "platinum",
+        version_group: "platinum",
+    },
+    RawVersion {
+        id: 15,
+        name: "heartgold",
+        version_group: "heartgold-soulsilver",
+    },
+    RawVersion {
+        id: 16,
+        name: "soulsilver",
+        version_group: "heartgold-soulsilver",
+    },
+    RawVersion {
+        id: 17,
+        name: "black",
+        version_group: "black-white",
+    },
+    RawVersion {
+        id: 18,
+        name: "white",
+        version_group: "black-white",
+    },
+    RawVersion {
+        id: 19,
+        name: "colosseum",
+        version_group: "colosseum",
+    },
+    RawVersion {
+        id: 20,
+        name: "xd",
+        version_group: "xd",
+    },
+    RawVersion {
+        id: 21,
+        name: "black-2",
+        version_group: "black-2-white-2",
+    },
+    RawVersion {
+        id: 22,
+        name: "white-2",
+        version_group: "black-2-white-2",
+    },
+    RawVersion {
+        id: 23,
+        name: "x",
+        version_group: "x-y",
+    },
+    RawVersion {
+        id: 24,
+        name: "y",
+        version_group: "x-y",
+    },
+    RawVersion {
+        id: 25,
+        name: "omega-ruby",
+        version_group: "omega-ruby-alpha-sapphire",
+    },
+    RawVersion {
+        id: 26,
+        name: "alpha-sapphire",
+        version_group: "omega-ruby-alpha-sapphire",
+    },
+    RawVersion {
+        id: 27,
+        name: "sun",
+        version_group: "sun-moon",
+    },
+    RawVersion {
+        id: 28,
+        name: "moon",
+        version_group: "sun-moon",
+    },
+    RawVersion {
+        id: 29,
+        name: "ultra-sun",
+        version_group: "ultra-sun-ultra-moon",
+    },
+    RawVersion {
+        id: 30,
+        name: "ultra-moon",
+        version_group: "ultra-sun-ultra-moon",
+    },
+    RawVersion {
+        id: 31,
+        name: "lets-go-pikachu",
+        version_group: "lets-go-pikachu-lets-go-eevee",
+    },
+    RawVersion {
+        id: 32,
+        name: "lets-go-eevee",
+        version_group: "lets-go-pikachu-lets-go-eevee",
+    },
+    RawVersion {
+        id: 33,
+        name: "sword",
+        version_group: "sword-shield",
+    },
+    RawVersion {
+        id: 34,
+        name: "shield",
+        version_group: "sword-shield",
+    },
+    RawVersion {
+        id: 35,
+        name: "the-isle-of-armor",
+        version_group: "the-isle-of-armor",
+    },
+    RawVersion {
+        id: 36,
+        name: "the-crown-tundra",
+        version_group: "the-crown-tundra",
+    },
+    RawVersion {
+        id: 37,
+        name: "brilliant-diamond",
+        version_group: "brilliant-diamond-shining-pearl",
+    },
+    RawVersion {
+        id: 38,
+        name: "shining-pearl",
+        version_group: "brilliant-diamond-shining-pearl",
+    },
+    RawVersion {
+        id: 39,
+        name: "legends-arceus",
+        version_group: "legends-arceus",
+    },
+    RawVersion {
+        id: 40,
+        name: "scarlet",
+        version_group: "scarlet-violet",
+    },
+    RawVersion {
+        id: 41,
+        name: "violet",
+        version_group: "scarlet-violet",
+    },
+    RawVersion {
+        id: 42,
+        name: "the-teal-mask",
+        version_group: "the-teal-mask",
+    },
+    RawVersion {
+        id: 43,
+        name: "the-indigo-disk",
+        version_group: "the-indigo-disk",
+    },
+    RawVersion {
+        id: 44,
+        name: "red-japan",
+        version_group: "red-green-japan",
+    },
+    RawVersion {
+        id: 45,
+        name: "green-japan",
+        version_group: "red-green-japan",
+    },
+    RawVersion {
+        id: 46,
+        name: "blue-japan",
+        version_group: "blue-japan",
+    },
+];
+
+pub(super) static REGIONS: &[RawRegion] = &[
+    RawRegion {
+        id: 1,
+        name: "kanto",
+        main_generation: Some(1),
+    },
+    RawRegion {
+        id: 2,
+        name: "johto",
+        main_generation: Some(2),
+    },
+    RawRegion {
+        id: 3,
+        name: "hoenn",
+        main_generation: Some(3),
+    },
+    RawRegion {
+        id: 4,
+        name: "sinnoh",
+        main_generation: Some(4),
+    },
+    RawRegion {
+        id: 5,
+        name: "unova",
+        main_generation: Some(5),
+    },
+    RawRegion {
+        id: 6,
+        name: "kalos",
+        main_generation: Some(6),
+    },
+    RawRegion {
+        id: 7,
+        name: "alola",
+        main_generation: Some(7),
+    },
+    RawRegion {
+        id: 8,
+        name: "galar",
+        main_generation: Some(8),
+    },
+    RawRegion {
+        id: 9,
+        name: "hisui",
+        main_generation: None,
+    },
+    RawRegion {
+        id: 10,
+        name: "paldea",
+        main_generation: Some(9),
+    },
+];
@@ -0,0 +1,453 @@
+//! Registry of the generations, version groups, versions and regions of the games, available without network.
+//!
+//! [`Registry::builtin`] returns the games known when the crate was released, generated by `rustemon-static-test`,
+//! while [`Registry::fetch`] pulls them from `PokeApi`, e.g. when new games are added.
+//!
+//! ```rust
+//! use std::cmp::Ordering;
+//!
+//! use rustemon::registry::Registry;
+//!
+//! let registry = Registry::builtin();
+//! let generation = registry.generation_of("omega-ruby-alpha-sapphire").unwrap();
+//! assert_eq!(generation.name, "generation-vi");
+//! assert_eq!(
+//!     registry.compare_version_groups("colosseum", "black-white"),
+//!     Some(Ordering::Less)
+//! );
+//! ```
+
+mod data;
+
+use std::{cmp::Ordering, sync::OnceLock};
+
+use crate::{
+    client::RustemonClient,
+    error::Error,
+    model::{
+        games::{Generation, Version, VersionGroup},
+        generational::Chronology,
+        locations::Region,
+        versioned::{VersionScope, VersionTag},
+    },
+};
+
+/// Generation as stored in the built-in registry.
+struct RawGeneration {
+    id: i64,
+    name: &'static str,
+    main_region: &'static str,
+}
+
+/// Version group as stored in the built-in registry.
+struct RawVersionGroup {
+    id: i64,
+    name: &'static str,
+    order: i64,
+    generation: i64,
+    versions: &'static [&'static str],
+    regions: &'static [&'static str],
+}
+
+/// Version as stored in the built-in registry.
+struct RawVersion {
+    id: i64,
+    name: &'static str,
+    version_group: &'static str,
+}
+
+/// Region as stored in the built-in registry.
+struct RawRegion {
+    id: i64,
+    name: &'static str,
+    main_generation: Option<i64>,
+}
+
+/// Generation of the games, e.g. `generation-vi`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct GenerationInfo {
+    /// The identifier of the generation, which is also its number.
+    pub id: i64,
+    /// The name of the generation.
+    pub name: String,
+    /// The name of the main region introduced in the generation.
+    pub main_region: String,
+}
+
+/// Version group of the games, e.g. `omega-ruby-alpha-sapphire`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct VersionGroupInfo {
+    /// The identifier of the version group.
+    pub id: i64,
+    /// The name of the version group.
+    pub name: String,
+    /// Order for sorting, almost by date of release, except similar versions are grouped together.
+    pub order: i64,
+    /// The number of the generation the version group belongs to.
+    pub generation: i64,
+    /// The names of the versions of the version group.
+    pub versions: Vec<String>,
+    /// The names of the regions that can be visited in the version group.
+    pub regions: Vec<String>,
+}
+
+/// Version of the games, e.g. `omega-ruby`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct VersionInfo {
+    /// The identifier of the version.
+    pub id: i64,
+    /// The name of the version.
+    pub name: String,
+    /// The name of the version group the version belongs to.
+    pub version_group: String,
+}
+
+/// Region of the games, e.g. `hoenn`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct RegionInfo {
+    /// The identifier of the region.
+    pub id: i64,
+    /// The name of the region.
+    pub name: String,
+    /// The number of the generation the region was introduced in, if any.
+    pub main_generation: Option<i64>,
+}
+
+/// Generations, version groups, versions and regions of the games, looked up synchronously.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct Registry {
+    generations: Vec<GenerationInfo>,
+    version_groups: Vec<VersionGroupInfo>,
+    versions: Vec<VersionInfo>,
+    regions: Vec<RegionInfo>,
+}
+
+impl Registry {
+    /// The games known when the crate was released.
+    pub fn builtin() -> &'static Registry {
+        static BUILTIN: OnceLock<Registry> = OnceLock::new();
+
+        BUILTIN.get_or_init(|| {
+            Self::new(
+                data::GENERATIONS.iter().map(|generation| GenerationInfo {
+                    id: generation.id,
+                    name: generation.name.to_owned(),
+                    main_region: generation.main_region.to_owned(),
+                }),
+                data::VERSION_GROUPS
+                    .iter()
+                    .map(|version_group| VersionGroupInfo {
+                        id: version_group.id,
+                        name: version_group.name.to_owned(),
+                        order: version_group.order,
+                        generation: version_group.generation,
+                        versions: to_owned(version_group.versions),
+                        regions: to_owned(version_group.regions),
+                    }),
+                data::VERSIONS.iter().map(|version| VersionInfo {
+                    id: version.id,
+                    name: version.name.to_owned(),
+                    version_group: version.version_group.to_owned(),
+                }),
+                data::REGIONS.iter().map(|region| RegionInfo {
+                    id: region.id,
+                    name: region.name.to_owned(),
+                    main_generation: region.main_generation,
+                }),
+            )
+        })
+    }
+
+    /// Creates a registry of the given games, sorting the version groups by order and the versions by id.
+    pub fn new(
+        generations: impl IntoIterator<Item = GenerationInfo>,
+        version_groups: impl IntoIterator<Item = VersionGroupInfo>,
+        versions: impl IntoIterator<Item = VersionInfo>,
+        regions: impl IntoIterator<Item = RegionInfo>,
+    ) -> Self {
+        let mut registry = Self {
+            generations: generations.into_iter().collect(),
+            version_groups: version_groups.into_iter().collect(),
+            versions: versions.into_iter().collect(),
+            regions: regions.into_iter().collect(),
+        };
+        registry.generations.sort_by_key(|generation| generation.id);
+        registry
+            .version_groups
+            .sort_by_key(|version_group| version_group.order);
+        registry.versions.sort_by_key(|version| version.id);
+        registry.regions.sort_by_key(|region| region.id);
+
+        registry
+    }
+
+    /// Creates a registry of the given resources of `PokeApi`.
+    pub fn from_resources(
+        generations: &[Generation],
+        version_groups: &[VersionGroup],
+        versions: &[Version],
+        regions: &[Region],
+    ) -> Self {
+        let generation_of = |generation: &str| {
+            generations
+                .iter()
+                .find(|candidate| candidate.name == generation)
+                .map(|generation| generation.id)
+        };
+
+        Self::new(
+            generations.iter().map(|generation| GenerationInfo {
+                id: generation.id,
+                name: generation.name.clone(),
+                main_region: generation.main_region.name.to_string(),
+            }),
+            version_groups.iter().map(|version_group| VersionGroupInfo {
+                id: version_group.id,
+                name: version_group.name.clone(),
+                order: version_group.order,
+                generation: generation_of(&version_group.generation.name)
+                    .or_else(|| version_group.generation.id())
+                    .unwrap_or_default(),
+                versions: names(&version_group.versions),
+                regions: names(&version_group.regions),
+            }),
+            versions.iter().map(|version| VersionInfo {
+                id: version.id,
+                name: version.name.clone(),
+                version_group: version.version_group.name.to_string(),
+            }),
+            regions.iter().map(|region| RegionInfo {
+                id: region.id,
+                name: region.name.clone(),
+                main_generation: region.main_generation.as_ref().and_then(|generation| {
+                    generation_of(&generation.name).or_else(|| generation.id())
+                }),
+            }),
+        )
+    }
+
+    /// Pulls every generation, version group, version and region from `PokeApi`, e.g. to know about games released
+    /// after the crate.
+    pub async fn fetch(rustemon_client: &RustemonClient) -> Result<Self, Error> {
+        let mut generations = Vec::new();
+        for entry in crate::games::generation::get_all_entries(rustemon_client).await? {
+            generations
+                .push(crate::games::generation::get_by_name(&entry.name, rustemon_client).await?);
+        }
+        let mut version_groups = Vec::new();
+        for entry in crate::games::version_group::get_all_entries(rustemon_client).await? {
+            version_groups.push(
+                crate::games::version_group::get_by_name(&entry.name, rustemon_client).await?,
+            );
+        }
+        let mut versions = Vec::new();
+        for entry in crate::games::version::get_all_entries(rustemon_client).await? {
+            versions.push(crate::games::version::get_by_name(&entry.name, rustemon_client).await?);
+        }
+        let mut regions = Vec::new();
+        for entry in crate::locations::region::get_all_entries(rustemon_client).await? {
+            regions
+                .push(crate::locations::region::get_by_name(&entry.name, rustemon_client).await?);
+        }
+
+        Ok(Self::from_resources(
+            &generations,
+            &version_groups,
+            &versions,
+            &regions,
+        ))
+    }
+
+    /// Every generation, by number.
+    pub fn generations(&self) -> &[GenerationInfo] {
+        &self.generations
+    }
+
+    /// Every version group, by order.
+    pub fn version_groups(&self) -> &[VersionGroupInfo] {
+        &self.version_groups
+    }
+
+    /// Every version, by id.
+    pub fn versions(&self) -> &[VersionInfo] {
+        &self.versions
+    }
+
+    /// Every region, by id.
+    pub fn regions(&self) -> &[RegionInfo] {
+        &self.regions
+    }
+
+    /// The generation with the given name, e.g. `generation-vi`.
+    pub fn generation(&self, name: &str) -> Option<&GenerationInfo> {
+        self.generations
+            .iter()
+            .find(|generation| generation.name == name)
+    }
+
+    /// The generation with the given number, e.g. `6`.
+    pub fn generation_by_id(&self, id: i64) -> Option<&GenerationInfo> {
+        self.generations
+            .iter()
+            .find(|generation| generation.id == id)
+    }
+
+    /// The version group with the given name, e.g. `omega-ruby-alpha-sapphire`.
+    pub fn version_group(&self, name: &str) -> Option<&VersionGroupInfo> {
+        self.version_groups
+            .iter()
+            .find(|version_group| version_group.name == name)
+    }
+
+    /// The version group with the given id.
+    pub fn version_group_by_id(&self, id: i64) -> Option<&VersionGroupInfo> {
+        self.version_groups
+            .iter()
+            .find(|version_group| version_group.id == id)
+    }
+
+    /// The version with the given name, e.g. `omega-ruby`.
+    pub fn version(&self, name: &str) -> Option<&VersionInfo> {
+        self.versions.iter().find(|version| version.name == name)
+    }
+
+    /// The version with the given id.
+    pub fn version_by_id(&self, id: i64) -> Option<&VersionInfo> {
+        self.versions.iter().find(|version| version.id == id)
+    }
+
+    /// The region with the given name, e.g. `hoenn`.
+    pub fn region(&self, name: &str) -> Option<&RegionInfo> {
+        self.regions.iter().find(|region| region.name == name)
+    }
+
+    /// The region with the given id.
+    pub fn region_by_id(&self, id: i64) -> Option<&RegionInfo> {
+        self.regions.iter().find(|region| region.id == id)
+    }
+
+    /// The version group of the given version, e.g. `omega-ruby-alpha-sapphire` for `omega-ruby`.
+    pub fn version_group_of(&self, version: &str) -> Option<&VersionGroupInfo> {
+        self.version_group(&self.version(version)?.version_group)
+    }
+
+    /// The generation of the given version group or version.
+    pub fn generation_of(&self, version_or_group: &str) -> Option<&GenerationInfo> {
+        let version_group = self
+            .version_group(version_or_group)
+            .or_else(|| self.version_group_of(version_or_group))?;
+
+        self.generation_by_id(version_group.generation)
+    }
+
+    /// The version groups of the given generation, by order.
+    pub fn version_groups_of_generation(
+        &self,
+        generation: i64,
+    ) -> impl Iterator<Item = &VersionGroupInfo> {
+        self.version_groups
+            .iter()
+            .filter(move |version_group| version_group.generation == generation)
+    }
+
+    /// The version groups in which the given region can be visited, by order.
+    pub fn version_groups_of_region<'a>(
+        &'a self,
+        region: &'a str,
+    ) -> impl Iterator<Item = &'a VersionGroupInfo> {
+        self.version_groups
+            .iter()
+            .filter(move |version_group| version_group.regions.iter().any(|r| r == region))
+    }
+
+    /// Compares the release of two version groups, by order, if both are known.
+    pub fn compare_version_groups(&self, a: &str, b: &str) -> Option<Ordering> {
+        Some(
+            self.version_group(a)?
+                .order
+                .cmp(&self.version_group(b)?.order),
+        )
+    }
+
+    /// Compares the release of two versions, by the order of their version group, if both are known.
+    pub fn compare_versions(&self, a: &str, b: &str) -> Option<Ordering> {
+        Some(
+            self.version_group_of(a)?
+                .order
+                .cmp(&self.version_group_of(b)?.order),
+        )
+    }
+
+    /// Compares the release of the version groups of two tags, if both are known,
+    /// e.g. to pick the latest entries with [`Versioned::latest`](crate::model::versioned::Versioned::latest).
+    ///
+    /// ```rust
+    /// use rustemon::{model::versioned::Versioned, registry::Registry};
+    /// # let texts: Vec<rustemon::model::resource::VersionGroupFlavorText> = Vec::new();
+    ///
+    /// let registry = Registry::builtin();
+    /// let latest = texts.latest(|a, b| registry.compare_version_tags(a, b));
+    /// ```
+    pub fn compare_version_tags(&self, a: &VersionTag<'_>, b: &VersionTag<'_>) -> Option<Ordering> {
+        Some(
+            self.version_group_of_tag(a)?
+                .order
+                .cmp(&self.version_group_of_tag(b)?.order),
+        )
+    }
+
+    /// The version group of the given tag, or of its version.
+    fn version_group_of_tag(&self, tag: &VersionTag<'_>) -> Option<&VersionGroupInfo> {
+        match tag {
+            VersionTag::Version(version) => self.version_group_of(&version.name),
+            VersionTag::VersionGroup(version_group) => self.version_group(&version_group.name),
+        }
+    }
+
+    /// The chronology of the version groups, to project the models into a generation or version group.
+    pub fn chronology(&self) -> Chronology {
+        self.version_groups
+            .iter()
+            .fold(Chronology::default(), |chronology, version_group| {
+                chronology.with_version_group(
+                    &version_group.name,
+                    version_group.order,
+                    version_group.generation,
+                )
+            })
+    }
+
+    /// The scope of the given version group and its versions, to filter the entries tagged with them.
+    pub fn scope_of_version_group(&self, version_group: &str) -> Option<VersionScope> {
+        let version_group = self.version_group(version_group)?;
+
+        Some(
+            VersionScope::version_group(&version_group.name).with_versions(&version_group.versions),
+        )
+    }
+
+    /// The scope of the given version and its version group, to filter the entries tagged with them.
+    pub fn scope_of_version(&self, version: &str) -> Option<VersionScope> {
+        let version = self.version(version)?;
+
+        Some(VersionScope::version(&version.name).with_version_group(&version.version_group))
+    }
+}
+
+/// Copies the names of the built-in registry.
+fn to_owned(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| (*name).to_owned()).collect()
+}
+
+/// Collects the names of the given resources.
+fn names<T>(resources: &[crate::model::resource::NamedApiResource<T>]) -> Vec<String> {
+    resources
+        .iter()
+        .map(|resource| resource.name.to_string())
+        .collect()
+}
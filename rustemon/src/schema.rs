@@ -0,0 +1,101 @@
+//! JSON Schemas of the models, as serialized by rustemon.
+//!
+//! Every model derives [`schemars::JsonSchema`], and the references to other resources describe the endpoint they point
+//! to. This module gathers the schemas of the resources served by every endpoint, to validate payloads or generate code
+//! in other languages.
+
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+use schemars::{Schema, generate::SchemaSettings};
+
+use crate::model::{
+    berries, contests, encounters, evolution, games, items, locations, machines, moves, pokemon,
+    resource::Endpoint, utility,
+};
+
+/// Returns the schema of `T`, as a standalone JSON Schema document. The optional fields are required, as they are always
+/// serialized, `null` when absent.
+fn schema<T: Endpoint + schemars::JsonSchema>() -> (&'static str, Schema) {
+    let generator = SchemaSettings::default().for_serialize().into_generator();
+
+    (T::NAME, generator.into_root_schema_for::<T>())
+}
+
+/// Returns the schema of the resources of every endpoint, indexed by the name of the endpoint, e.g. `"pokemon-species"`.
+pub fn resource_schemas() -> BTreeMap<&'static str, Schema> {
+    BTreeMap::from([
+        // Berries endpoints
+        schema::<berries::Berry>(),
+        schema::<berries::BerryFirmness>(),
+        schema::<berries::BerryFlavor>(),
+        // Contests endpoints
+        schema::<contests::ContestType>(),
+        schema::<contests::ContestEffect>(),
+        schema::<contests::SuperContestEffect>(),
+        // Encounters endpoints
+        schema::<encounters::EncounterMethod>(),
+        schema::<encounters::EncounterCondition>(),
+        schema::<encounters::EncounterConditionValue>(),
+        // Evolution endpoints
+        schema::<evolution::EvolutionChain>(),
+        schema::<evolution::EvolutionTrigger>(),
+        // Games endpoints
+        schema::<games::Generation>(),
+        schema::<games::Pokedex>(),
+        schema::<games::Version>(),
+        schema::<games::VersionGroup>(),
+        // Items endpoints
+        schema::<items::Item>(),
+        schema::<items::ItemAttribute>(),
+        schema::<items::ItemCategory>(),
+        schema::<items::ItemFlingEffect>(),
+        schema::<items::ItemPocket>(),
+        // Locations endpoints
+        schema::<locations::Location>(),
+        schema::<locations::LocationArea>(),
+        schema::<locations::PalParkArea>(),
+        schema::<locations::Region>(),
+        // Machines endpoints
+        schema::<machines::Machine>(),
+        // Moves endpoints
+        schema::<moves::Move>(),
+        schema::<moves::MoveAilment>(),
+        schema::<moves::MoveBattleStyle>(),
+        schema::<moves::MoveCategory>(),
+        schema::<moves::MoveDamageClass>(),
+        schema::<moves::MoveLearnMethod>(),
+        schema::<moves::MoveTarget>(),
+        // Pokemon endpoints
+        schema::<pokemon::Ability>(),
+        schema::<pokemon::Characteristic>(),
+        schema::<pokemon::EggGroup>(),
+        schema::<pokemon::Gender>(),
+        schema::<pokemon::GrowthRate>(),
+        schema::<pokemon::Nature>(),
+        schema::<pokemon::PokeathlonStat>(),
+        schema::<pokemon::Pokemon>(),
+        schema::<pokemon::PokemonColor>(),
+        schema::<pokemon::PokemonForm>(),
+        schema::<pokemon::PokemonHabitat>(),
+        schema::<pokemon::PokemonShape>(),
+        schema::<pokemon::PokemonSpecies>(),
+        schema::<pokemon::Stat>(),
+        schema::<pokemon::Type>(),
+        // Utility endpoints
+        schema::<utility::Language>(),
+    ])
+}
+
+/// Writes the schema of the resources of every endpoint into `directory`, one `<endpoint>.json` file per endpoint,
+/// creating the directory if needed.
+pub fn export(directory: impl AsRef<Path>) -> io::Result<()> {
+    let directory = directory.as_ref();
+    fs::create_dir_all(directory)?;
+
+    for (endpoint, schema) in resource_schemas() {
+        let json = serde_json::to_string_pretty(&schema)?;
+        fs::write(directory.join(format!("{endpoint}.json")), json)?;
+    }
+
+    Ok(())
+}
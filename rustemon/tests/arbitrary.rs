@@ -0,0 +1,272 @@
+//! Checks that `Serialize` and `Deserialize` agree for every model, on arbitrary values.
+
+use arbitrary::{Arbitrary, Unstructured};
+use rustemon::{
+    client::Environment,
+    model::{
+        berries, contests, encounters, evolution, games, items, locations, machines, moves,
+        pokemon, resource, utility,
+    },
+};
+
+/// Number of values generated for every model.
+const ITERATIONS: u64 = 256;
+
+/// Fills `bytes` with noise derived from `seed`.
+fn fill(bytes: &mut [u8], mut seed: u64) {
+    for chunk in bytes.chunks_mut(8) {
+        seed = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = seed;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        chunk.copy_from_slice(&z.to_le_bytes()[..chunk.len()]);
+    }
+}
+
+/// Generates values of `T` from noise derived from every seed, skipping the ones the noise isn't enough for.
+fn generate<T: for<'a> Arbitrary<'a>>() -> Vec<T> {
+    let mut bytes = vec![0; 4096];
+
+    (0..ITERATIONS)
+        .filter_map(|seed| {
+            fill(&mut bytes, seed);
+            T::arbitrary(&mut Unstructured::new(&bytes)).ok()
+        })
+        .collect()
+}
+
+/// Generates values of `T`, checking that each one is unchanged by a JSON round-trip.
+fn roundtrip<T>()
+where
+    T: for<'a> Arbitrary<'a>
+        + serde::Serialize
+        + serde::de::DeserializeOwned
+        + PartialEq
+        + std::fmt::Debug,
+{
+    let name = std::any::type_name::<T>();
+    let values = generate::<T>();
+    assert!(!values.is_empty(), "no {name} was generated");
+
+    for value in values {
+        let json = serde_json::to_string(&value).unwrap();
+        let deserialized: T = serde_json::from_str(&json)
+            .unwrap_or_else(|e| panic!("{name} doesn't deserialize from {json}: {e}"));
+        assert_eq!(value, deserialized, "{name} changed during the round-trip");
+    }
+}
+
+macro_rules! roundtrip {
+    ($($test:ident: $model:ty),+ $(,)?) => {
+        $(
+            #[test]
+            fn $test() {
+                roundtrip::<$model>();
+            }
+        )+
+    };
+}
+
+#[test]
+fn named_resources_point_at_their_name() {
+    for resource in generate::<resource::NamedApiResource<pokemon::Pokemon>>() {
+        let expected = resource::NamedApiResource::<pokemon::Pokemon>::from_name(
+            &Environment::Production,
+            &resource.name,
+        )
+        .unwrap();
+        assert_eq!(resource.url, expected.url);
+    }
+}
+
+#[test]
+fn resources_point_at_their_endpoint() {
+    for resource in generate::<resource::ApiResource<evolution::EvolutionChain>>() {
+        assert!(
+            resource
+                .url
+                .starts_with("https://pokeapi.co/api/v2/evolution-chain/"),
+            "{resource:?}"
+        );
+    }
+}
+
+#[test]
+fn pages_link_their_neighbours_on_their_endpoint() {
+    let pages = generate::<resource::NamedApiResourceList<pokemon::Pokemon>>();
+    assert!(pages.iter().any(|page| page.next.is_some()));
+    assert!(pages.iter().any(|page| page.previous.is_some()));
+
+    for page in pages {
+        assert!(page.results.len() as i64 <= page.count, "{page:?}");
+        for url in page.next.iter().chain(&page.previous) {
+            assert!(
+                url.starts_with("https://pokeapi.co/api/v2/pokemon?offset="),
+                "{url}"
+            );
+        }
+    }
+}
+
+roundtrip!(
+    // Berries models
+    berry: berries::Berry,
+    berry_flavor_map: berries::BerryFlavorMap,
+    berry_firmness: berries::BerryFirmness,
+    berry_flavor: berries::BerryFlavor,
+    flavor_berry_map: berries::FlavorBerryMap,
+    // Contests models
+    contest_type: contests::ContestType,
+    contest_name: contests::ContestName,
+    contest_effect: contests::ContestEffect,
+    super_contest_effect: contests::SuperContestEffect,
+    // Encounters models
+    encounter_method: encounters::EncounterMethod,
+    encounter_condition: encounters::EncounterCondition,
+    encounter_condition_value: encounters::EncounterConditionValue,
+    // Evolution models
+    evolution_chain: evolution::EvolutionChain,
+    chain_link: evolution::ChainLink,
+    evolution_detail: evolution::EvolutionDetail,
+    evolution_trigger: evolution::EvolutionTrigger,
+    // Games models
+    generation: games::Generation,
+    pokedex: games::Pokedex,
+    pokemon_entry: games::PokemonEntry,
+    version: games::Version,
+    version_group: games::VersionGroup,
+    // Items models
+    item: items::Item,
+    item_sprites: items::ItemSprites,
+    item_holder_pokemon: items::ItemHolderPokemon,
+    item_holder_pokemon_version_detail: items::ItemHolderPokemonVersionDetail,
+    item_attribute: items::ItemAttribute,
+    item_category: items::ItemCategory,
+    item_fling_effect: items::ItemFlingEffect,
+    item_pocket: items::ItemPocket,
+    // Locations models
+    location: locations::Location,
+    location_area: locations::LocationArea,
+    encounter_method_rate: locations::EncounterMethodRate,
+    encounter_version_details: locations::EncounterVersionDetails,
+    pokemon_encounter: locations::PokemonEncounter,
+    pal_park_area: locations::PalParkArea,
+    pal_park_encounter_species: locations::PalParkEncounterSpecies,
+    region: locations::Region,
+    // Machines models
+    machine: machines::Machine,
+    // Moves models
+    move_: moves::Move,
+    contest_combo_sets: moves::ContestComboSets,
+    contest_combo_detail: moves::ContestComboDetail,
+    move_flavor_text: moves::MoveFlavorText,
+    move_meta_data: moves::MoveMetaData,
+    move_stat_change: moves::MoveStatChange,
+    past_move_stat_values: moves::PastMoveStatValues,
+    move_ailment: moves::MoveAilment,
+    move_battle_style: moves::MoveBattleStyle,
+    move_category: moves::MoveCategory,
+    move_damage_class: moves::MoveDamageClass,
+    move_learn_method: moves::MoveLearnMethod,
+    move_target: moves::MoveTarget,
+    // Pokemon models
+    ability: pokemon::Ability,
+    ability_effect_change: pokemon::AbilityEffectChange,
+    ability_flavor_text: pokemon::AbilityFlavorText,
+    ability_pokemon: pokemon::AbilityPokemon,
+    characteristic: pokemon::Characteristic,
+    egg_group: pokemon::EggGroup,
+    gender: pokemon::Gender,
+    pokemon_species_gender: pokemon::PokemonSpeciesGender,
+    growth_rate: pokemon::GrowthRate,
+    growth_rate_experience_level: pokemon::GrowthRateExperienceLevel,
+    nature: pokemon::Nature,
+    nature_stat_change: pokemon::NatureStatChange,
+    move_battle_style_preference: pokemon::MoveBattleStylePreference,
+    pokeathlon_stat: pokemon::PokeathlonStat,
+    nature_pokeathlon_stat_affect_sets: pokemon::NaturePokeathlonStatAffectSets,
+    nature_pokeathlon_stat_affect: pokemon::NaturePokeathlonStatAffect,
+    pokemon: pokemon::Pokemon,
+    pokemon_ability: pokemon::PokemonAbility,
+    pokemon_ability_past: pokemon::PokemonAbilityPast,
+    pokemon_type: pokemon::PokemonType,
+    pokemon_type_past: pokemon::PokemonTypePast,
+    pokemon_held_item: pokemon::PokemonHeldItem,
+    pokemon_held_item_version: pokemon::PokemonHeldItemVersion,
+    pokemon_move: pokemon::PokemonMove,
+    pokemon_move_version: pokemon::PokemonMoveVersion,
+    pokemon_stat: pokemon::PokemonStat,
+    pokemon_stat_past: pokemon::PokemonStatPast,
+    pokemon_sprites: pokemon::PokemonSprites,
+    other_sprites: pokemon::OtherSprites,
+    dream_world_sprites: pokemon::DreamWorldSprites,
+    home_sprites: pokemon::HomeSprites,
+    official_artwork_sprites: pokemon::OfficialArtworkSprites,
+    versions_sprites: pokemon::VersionsSprites,
+    generation_i_sprites: pokemon::GenerationISprites,
+    red_blue_sprites: pokemon::RedBlueSprites,
+    yellow_sprites: pokemon::YellowSprites,
+    generation_ii_sprites: pokemon::GenerationIISprites,
+    crystal_sprites: pokemon::CrystalSprites,
+    gold_sprites: pokemon::GoldSprites,
+    silver_sprites: pokemon::SilverSprites,
+    generation_iii_sprites: pokemon::GenerationIIISprites,
+    emerald_sprites: pokemon::EmeraldSprites,
+    firered_leafgreen_sprites: pokemon::FireredLeafgreenSprites,
+    ruby_sapphire_sprites: pokemon::RubySapphireSprites,
+    generation_iv_sprites: pokemon::GenerationIVSprites,
+    diamond_pearl_sprites: pokemon::DiamondPearlSprites,
+    platinum_sprites: pokemon::PlatinumSprites,
+    heartgold_soulsilver_sprites: pokemon::HeartgoldSoulsilverSprites,
+    generation_v_sprites: pokemon::GenerationVSprites,
+    black_white_sprites: pokemon::BlackWhiteSprites,
+    black_white_animated_sprites: pokemon::BlackWhiteAnimatedSprites,
+    generation_vi_sprites: pokemon::GenerationVISprites,
+    omegaruby_alphasapphire_sprites: pokemon::OmegarubyAlphasapphireSprites,
+    xy_sprites: pokemon::XYSprites,
+    generation_vii_sprites: pokemon::GenerationVIISprites,
+    icons_sprites: pokemon::IconsSprites,
+    ultrasun_ultramoon_sprites: pokemon::UltrasunUltramoonSprites,
+    generation_viii_sprites: pokemon::GenerationVIIISprites,
+    pokemon_cries: pokemon::PokemonCries,
+    location_area_encounter: pokemon::LocationAreaEncounter,
+    pokemon_color: pokemon::PokemonColor,
+    pokemon_form: pokemon::PokemonForm,
+    pokemon_form_type: pokemon::PokemonFormType,
+    pokemon_form_sprites: pokemon::PokemonFormSprites,
+    pokemon_habitat: pokemon::PokemonHabitat,
+    pokemon_shape: pokemon::PokemonShape,
+    awesome_name: pokemon::AwesomeName,
+    pokemon_species: pokemon::PokemonSpecies,
+    genus: pokemon::Genus,
+    pokemon_species_dex_entry: pokemon::PokemonSpeciesDexEntry,
+    pal_park_encounter_area: pokemon::PalParkEncounterArea,
+    pokemon_species_variety: pokemon::PokemonSpeciesVariety,
+    stat: pokemon::Stat,
+    move_stat_affect_sets: pokemon::MoveStatAffectSets,
+    move_stat_affect: pokemon::MoveStatAffect,
+    nature_stat_affect_sets: pokemon::NatureStatAffectSets,
+    type_: pokemon::Type,
+    type_pokemon: pokemon::TypePokemon,
+    type_relations: pokemon::TypeRelations,
+    type_relations_past: pokemon::TypeRelationsPast,
+    // Resource models
+    named_api_resource: resource::NamedApiResource<pokemon::Pokemon>,
+    named_api_resource_list: resource::NamedApiResourceList<pokemon::Pokemon>,
+    api_resource: resource::ApiResource<evolution::EvolutionChain>,
+    api_resource_list: resource::ApiResourceList<evolution::EvolutionChain>,
+    description: resource::Description,
+    effect: resource::Effect,
+    encounter: resource::Encounter,
+    flavor_text: resource::FlavorText,
+    generation_game_index: resource::GenerationGameIndex,
+    machine_version_detail: resource::MachineVersionDetail,
+    name: resource::Name,
+    verbose_effect: resource::VerboseEffect,
+    version_encounter_detail: resource::VersionEncounterDetail,
+    version_game_index: resource::VersionGameIndex,
+    version_group_flavor_text: resource::VersionGroupFlavorText,
+    // Utility models
+    language: utility::Language,
+);
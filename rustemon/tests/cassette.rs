@@ -0,0 +1,233 @@
+//! Records interactions with a local stand-in of `PokeApi`, then replays them without it.
+
+mod common;
+
+use common::{TempFile, Upstream, builder, soft};
+use rustemon::{
+    cassette::{Cassette, CassetteError, UrlMatching},
+    client::{CacheMode, Environment},
+    error::Error,
+};
+use serde_json::Value;
+
+fn cassette_error(error: Error) -> CassetteError {
+    match error {
+        Error::ReqwestMiddleware(reqwest_middleware::Error::Middleware(error)) => {
+            error.downcast().unwrap()
+        }
+        error => panic!("expected a cassette error, got {error:?}"),
+    }
+}
+
+fn interactions(file: &TempFile) -> Vec<Value> {
+    let content = std::fs::read(&file.0).unwrap();
+    let tape: Value = serde_json::from_slice(&content).unwrap();
+
+    tape["interactions"].as_array().unwrap().clone()
+}
+
+#[tokio::test]
+async fn replays_recorded_interactions_without_upstream() {
+    let file = TempFile::new("cassette-replay.json");
+    let upstream = Upstream::start([("/api/v2/berry-firmness/soft", soft().to_string())]).await;
+    let environment = upstream.environment();
+
+    let cassette = Cassette::record(&file.0);
+    let client = builder(environment.clone(), CacheMode::NoStore)
+        .with_cassette(cassette.clone())
+        .try_build()
+        .unwrap();
+    let recorded = rustemon::berries::berry_firmness::get_by_name("soft", &client)
+        .await
+        .unwrap();
+    cassette.flush().unwrap();
+    drop(upstream);
+
+    let client = builder(environment, CacheMode::NoStore)
+        .with_cassette(Cassette::replay(&file.0).unwrap())
+        .try_build()
+        .unwrap();
+    let replayed = rustemon::berries::berry_firmness::get_by_name("soft", &client)
+        .await
+        .unwrap();
+
+    assert_eq!(recorded, replayed);
+}
+
+#[tokio::test]
+async fn writes_the_file_once_flushed_or_dropped() {
+    let file = TempFile::new("cassette-flush.json");
+    let upstream = Upstream::start([("/api/v2/berry-firmness/soft", soft().to_string())]).await;
+
+    let cassette = Cassette::record(&file.0);
+    let client = builder(upstream.environment(), CacheMode::NoStore)
+        .with_cassette(cassette.clone())
+        .try_build()
+        .unwrap();
+    rustemon::berries::berry_firmness::get_by_name("soft", &client)
+        .await
+        .unwrap();
+    assert!(!file.0.exists());
+
+    cassette.flush().unwrap();
+    assert_eq!(interactions(&file).len(), 1);
+
+    rustemon::berries::berry_firmness::get_by_id(1, &client)
+        .await
+        .unwrap_err();
+    drop(cassette);
+    assert_eq!(interactions(&file).len(), 1);
+    drop(client);
+    assert_eq!(interactions(&file).len(), 2);
+}
+
+#[tokio::test]
+async fn skips_the_calls_answered_by_the_cache() {
+    let file = TempFile::new("cassette-cache.json");
+    let upstream = Upstream::start([("/api/v2/berry-firmness/soft", soft().to_string())]).await;
+
+    let client = builder(upstream.environment(), CacheMode::Default)
+        .with_cassette(Cassette::record(&file.0))
+        .try_build()
+        .unwrap();
+    for _ in 0..3 {
+        rustemon::berries::berry_firmness::get_by_name("soft", &client)
+            .await
+            .unwrap();
+    }
+    drop(client);
+
+    assert_eq!(upstream.hits(), 1);
+    assert_eq!(interactions(&file).len(), 1);
+}
+
+#[tokio::test]
+async fn redacts_headers() {
+    let file = TempFile::new("cassette-redact.json");
+    let upstream = Upstream::start([("/api/v2/berry-firmness/soft", soft().to_string())]).await;
+
+    let client = builder(upstream.environment(), CacheMode::NoStore)
+        .with_cassette(Cassette::record(&file.0).with_redacted_header("Cache-Control"))
+        .try_build()
+        .unwrap();
+    rustemon::berries::berry_firmness::get_by_name("soft", &client)
+        .await
+        .unwrap();
+    drop(client);
+
+    let headers = interactions(&file)[0]["headers"].clone();
+    let headers: Vec<(String, String)> = serde_json::from_value(headers).unwrap();
+    assert!(headers.contains(&("cache-control".to_owned(), "[REDACTED]".to_owned())));
+    assert!(headers.contains(&("content-type".to_owned(), "application/json".to_owned())));
+}
+
+#[tokio::test]
+async fn matches_urls_of_another_environment_once_normalized() {
+    let file = TempFile::new("cassette-normalized.json");
+    let upstream = Upstream::start([(
+        "/api/v2/berry?limit=1&offset=0",
+        r#"{"count":1,"next":null,"previous":null,"results":[]}"#.to_owned(),
+    )])
+    .await;
+
+    let client = builder(upstream.environment(), CacheMode::NoStore)
+        .with_cassette(Cassette::record(&file.0))
+        .try_build()
+        .unwrap();
+    rustemon::berries::berry::get_page_with_param(0, 1, &client)
+        .await
+        .unwrap();
+    drop(client);
+
+    let other = Environment::Custom("http://127.0.0.1:1/api/v2".to_owned());
+    let exact = builder(other.clone(), CacheMode::NoStore)
+        .with_cassette(Cassette::replay(&file.0).unwrap())
+        .try_build()
+        .unwrap();
+    let error = rustemon::berries::berry::get_page_with_param(0, 1, &exact)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        cassette_error(error),
+        CassetteError::Unrecorded { .. }
+    ));
+
+    let normalized = builder(other, CacheMode::NoStore)
+        .with_cassette(
+            Cassette::replay(&file.0)
+                .unwrap()
+                .with_url_matching(UrlMatching::Normalized),
+        )
+        .try_build()
+        .unwrap();
+    let page = rustemon::berries::berry::get_page_with_param(0, 1, &normalized)
+        .await
+        .unwrap();
+    assert_eq!(page.count, 1);
+}
+
+#[tokio::test]
+async fn fails_on_unrecorded_interactions() {
+    let file = TempFile::new("cassette-unrecorded.json");
+    std::fs::write(&file.0, r#"{"interactions":[]}"#).unwrap();
+
+    let client = builder(Environment::default(), CacheMode::NoStore)
+        .with_cassette(Cassette::replay(&file.0).unwrap())
+        .try_build()
+        .unwrap();
+    let error = rustemon::berries::berry_firmness::get_by_name("soft", &client)
+        .await
+        .unwrap_err();
+
+    match cassette_error(error) {
+        CassetteError::Unrecorded { method, url, path } => {
+            assert_eq!(method, "GET");
+            assert_eq!(url, "https://pokeapi.co/api/v2/berry-firmness/soft");
+            assert_eq!(path, file.0);
+        }
+        error => panic!("expected an unrecorded interaction, got {error:?}"),
+    }
+}
+
+#[tokio::test]
+async fn fails_on_invalid_recorded_statuses() {
+    let file = TempFile::new("cassette-status.json");
+    let tape = serde_json::json!({
+        "interactions": [{
+            "method": "GET",
+            "url": "https://pokeapi.co/api/v2/berry-firmness/soft",
+            "status": 1000,
+            "headers": [],
+            "body": soft().to_string(),
+        }],
+    });
+    std::fs::write(&file.0, tape.to_string()).unwrap();
+
+    let client = builder(Environment::default(), CacheMode::NoStore)
+        .with_cassette(Cassette::replay(&file.0).unwrap())
+        .try_build()
+        .unwrap();
+    let error = rustemon::berries::berry_firmness::get_by_name("soft", &client)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        cassette_error(error),
+        CassetteError::InvalidStatus { status: 1000, .. }
+    ));
+}
+
+#[test]
+fn fails_on_unreadable_cassettes() {
+    let file = TempFile::new("cassette-invalid.json");
+
+    assert!(matches!(
+        Cassette::replay(&file.0),
+        Err(CassetteError::Io(..))
+    ));
+    std::fs::write(&file.0, "not a cassette").unwrap();
+    assert!(matches!(
+        Cassette::replay(&file.0),
+        Err(CassetteError::Parse(..))
+    ));
+}
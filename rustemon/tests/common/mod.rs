@@ -0,0 +1,132 @@
+//! Local stand-in of `PokeApi`, shared by the integration tests.
+
+// Each test crate only uses part of the stand-in.
+#![allow(dead_code)]
+
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+use axum::{
+    Router,
+    extract::State,
+    http::{StatusCode, Uri, header},
+    response::{IntoResponse, Response},
+};
+use rustemon::client::{CacheMode, Environment, MokaManager, RustemonClientBuilder};
+use serde_json::{Value, json};
+use tokio::{net::TcpListener, task::JoinHandle};
+
+/// Payload of the `soft` berry firmness.
+pub fn soft() -> Value {
+    json!({
+        "id": 1,
+        "name": "soft",
+        "berries": [{ "name": "pecha", "url": "https://pokeapi.co/api/v2/berry/3/" }],
+        "names": [{ "name": "Soft", "language": { "name": "en", "url": "https://pokeapi.co/api/v2/language/9/" } }],
+    })
+}
+
+#[derive(Clone, Default)]
+struct Routes {
+    payloads: Arc<HashMap<String, String>>,
+    hits: Arc<AtomicUsize>,
+}
+
+async fn serve(State(routes): State<Routes>, uri: Uri) -> Response {
+    routes.hits.fetch_add(1, Ordering::SeqCst);
+    let path_and_query = uri.path_and_query().map_or(uri.path(), |pq| pq.as_str());
+
+    match routes.payloads.get(path_and_query) {
+        Some(payload) => (
+            [
+                (header::CONTENT_TYPE, "application/json"),
+                (header::CACHE_CONTROL, "public, max-age=86400"),
+            ],
+            payload.clone(),
+        )
+            .into_response(),
+        None => (StatusCode::NOT_FOUND, "Not Found").into_response(),
+    }
+}
+
+/// Serves fixed payloads by path and query, e.g. `/api/v2/berry-firmness/soft`, and counts the requests.
+pub struct Upstream {
+    address: SocketAddr,
+    hits: Arc<AtomicUsize>,
+    task: JoinHandle<()>,
+}
+
+impl Upstream {
+    pub async fn start(payloads: impl IntoIterator<Item = (&'static str, String)>) -> Self {
+        let routes = Routes {
+            payloads: Arc::new(
+                payloads
+                    .into_iter()
+                    .map(|(path, payload)| (path.to_owned(), payload))
+                    .collect(),
+            ),
+            hits: Arc::default(),
+        };
+        let hits = Arc::clone(&routes.hits);
+
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let router = Router::new().fallback(serve).with_state(routes);
+        let task = tokio::spawn(async move {
+            axum::serve(listener, router).await.unwrap();
+        });
+
+        Self {
+            address,
+            hits,
+            task,
+        }
+    }
+
+    pub fn environment(&self) -> Environment {
+        Environment::Custom(format!("http://{}/api/v2/", self.address))
+    }
+
+    /// Number of requests received so far.
+    pub fn hits(&self) -> usize {
+        self.hits.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for Upstream {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Builder of a client targeting `environment`, keeping the cache in memory.
+pub fn builder(environment: Environment, mode: CacheMode) -> RustemonClientBuilder<MokaManager> {
+    RustemonClientBuilder::<MokaManager>::default()
+        .with_mode(mode)
+        .with_environment(environment)
+}
+
+/// Path of a file in the temporary directory, unique to the test and removed when dropped.
+pub struct TempFile(pub PathBuf);
+
+impl TempFile {
+    pub fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("rustemon-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        Self(path)
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
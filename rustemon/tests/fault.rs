@@ -0,0 +1,220 @@
+//! Injects every fault in the calls made to a local stand-in of `PokeApi`, and checks how they surface.
+
+mod common;
+
+use std::time::{Duration, Instant};
+
+use common::{Upstream, builder, soft};
+use http::StatusCode;
+use rustemon::{
+    client::{CacheMode, RustemonClient},
+    decode::Strictness,
+    error::Error,
+    fault::{Fault, FaultError, FaultInjector},
+};
+
+const SOFT: (&str, &str) = ("/api/v2/berry-firmness/soft", "/api/v2/berry-firmness/1");
+
+async fn upstream() -> Upstream {
+    Upstream::start([(SOFT.0, soft().to_string()), (SOFT.1, soft().to_string())]).await
+}
+
+fn client(upstream: &Upstream, strictness: Strictness, injector: FaultInjector) -> RustemonClient {
+    builder(upstream.environment(), CacheMode::NoStore)
+        .with_strictness(strictness)
+        .with_fault_injector(injector)
+        .try_build()
+        .unwrap()
+}
+
+async fn get_soft(client: &RustemonClient) -> Result<(), Error> {
+    rustemon::berries::berry_firmness::get_by_name("soft", client)
+        .await
+        .map(drop)
+}
+
+/// Asserts that a broken payload surfaces as a decoding error of the mode.
+fn assert_decode_error(error: Error, strictness: Strictness) {
+    match strictness {
+        Strictness::Strict | Strictness::Lenient => {
+            assert!(
+                matches!(&error, Error::Decode { model, url, .. }
+                    if model.ends_with("BerryFirmness") && url.ends_with(SOFT.0)),
+                "{error:?}"
+            );
+        }
+        #[cfg(not(feature = "simd-json"))]
+        Strictness::Default => {
+            assert!(
+                matches!(&error, Error::Reqwest(e) if e.is_decode()),
+                "{error:?}"
+            );
+        }
+        #[cfg(feature = "simd-json")]
+        Strictness::Default => {
+            assert!(
+                matches!(&error, Error::SimdDecode { model, url, .. }
+                    if model.ends_with("BerryFirmness") && url.ends_with(SOFT.0)),
+                "{error:?}"
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn timeouts_surface_as_middleware_errors() {
+    let upstream = upstream().await;
+    let delay = Duration::from_millis(50);
+    let injector = FaultInjector::new(0).with_fault(Fault::Timeout(delay), 1.0);
+    let client = client(&upstream, Strictness::Default, injector);
+
+    let start = Instant::now();
+    let error = get_soft(&client).await.unwrap_err();
+
+    assert!(start.elapsed() >= delay);
+    match error {
+        Error::ReqwestMiddleware(reqwest_middleware::Error::Middleware(error)) => {
+            let FaultError::Timeout(elapsed, url) = error.downcast().unwrap();
+            assert_eq!(elapsed, delay);
+            assert!(url.as_str().ends_with(SOFT.0));
+        }
+        error => panic!("expected a timeout, got {error:?}"),
+    }
+    assert_eq!(upstream.hits(), 0);
+}
+
+#[tokio::test]
+async fn statuses_surface_as_reqwest_errors() {
+    let upstream = upstream().await;
+    let injector =
+        FaultInjector::new(0).with_fault(Fault::Status(StatusCode::SERVICE_UNAVAILABLE), 1.0);
+    let client = client(&upstream, Strictness::Default, injector);
+
+    let error = get_soft(&client).await.unwrap_err();
+
+    assert!(
+        matches!(&error, Error::Reqwest(e) if e.status() == Some(StatusCode::SERVICE_UNAVAILABLE)),
+        "{error:?}"
+    );
+    assert_eq!(upstream.hits(), 0);
+}
+
+#[tokio::test]
+async fn truncated_bodies_surface_as_decode_errors() {
+    for strictness in [Strictness::Default, Strictness::Strict, Strictness::Lenient] {
+        let upstream = upstream().await;
+        let injector = FaultInjector::new(0).with_fault(Fault::TruncatedBody, 1.0);
+        let client = client(&upstream, strictness, injector);
+
+        let error = get_soft(&client).await.unwrap_err();
+
+        assert_decode_error(error, strictness);
+        assert_eq!(upstream.hits(), 1);
+    }
+}
+
+#[tokio::test]
+async fn malformed_json_surfaces_as_decode_errors() {
+    for strictness in [Strictness::Default, Strictness::Strict, Strictness::Lenient] {
+        let upstream = upstream().await;
+        let injector = FaultInjector::new(0).with_fault(Fault::MalformedJson, 1.0);
+        let client = client(&upstream, strictness, injector);
+
+        let error = get_soft(&client).await.unwrap_err();
+
+        assert_decode_error(error, strictness);
+        assert_eq!(upstream.hits(), 0);
+    }
+}
+
+#[tokio::test]
+async fn slow_responses_eventually_succeed() {
+    let upstream = upstream().await;
+    let delay = Duration::from_millis(50);
+    let injector = FaultInjector::new(0).with_fault(Fault::Slow(delay), 1.0);
+    let client = client(&upstream, Strictness::Default, injector);
+
+    let start = Instant::now();
+    get_soft(&client).await.unwrap();
+
+    assert!(start.elapsed() >= delay);
+    assert_eq!(upstream.hits(), 1);
+}
+
+#[tokio::test]
+async fn scripts_run_in_turn_and_take_precedence() {
+    let upstream = upstream().await;
+    let injector = FaultInjector::new(0)
+        .with_fault(Fault::MalformedJson, 1.0)
+        .with_script([None, Some(Fault::Status(StatusCode::NOT_FOUND))])
+        .with_endpoint_script("berry-firmness", [None]);
+    let client = client(&upstream, Strictness::Default, injector);
+
+    // The script of the endpoint comes first, then the unscoped one, then the rules.
+    get_soft(&client).await.unwrap();
+    get_soft(&client).await.unwrap();
+    assert!(matches!(
+        get_soft(&client).await.unwrap_err(),
+        Error::Reqwest(e) if e.status() == Some(StatusCode::NOT_FOUND)
+    ));
+    assert_decode_error(get_soft(&client).await.unwrap_err(), Strictness::Default);
+    assert_eq!(upstream.hits(), 2);
+}
+
+#[tokio::test]
+async fn endpoint_faults_only_target_their_endpoint() {
+    let upstream = upstream().await;
+    let injector = FaultInjector::new(0).with_endpoint_fault(
+        "pokemon",
+        Fault::Status(StatusCode::INTERNAL_SERVER_ERROR),
+        1.0,
+    );
+    let client = client(&upstream, Strictness::Default, injector);
+
+    get_soft(&client).await.unwrap();
+    let error = rustemon::pokemon::pokemon::get_by_id(1, &client)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        Error::Reqwest(e) if e.status() == Some(StatusCode::INTERNAL_SERVER_ERROR)
+    ));
+}
+
+#[tokio::test]
+async fn same_seeds_inject_the_same_faults() {
+    async fn outcomes(seed: u64) -> Vec<bool> {
+        let upstream = upstream().await;
+        let injector = FaultInjector::new(seed)
+            .with_fault(Fault::Status(StatusCode::SERVICE_UNAVAILABLE), 0.5);
+        let client = client(&upstream, Strictness::Default, injector);
+
+        let mut outcomes = Vec::new();
+        for _ in 0..32 {
+            outcomes.push(get_soft(&client).await.is_ok());
+        }
+        outcomes
+    }
+
+    let outcomes_of_42 = outcomes(42).await;
+
+    assert_eq!(outcomes_of_42, outcomes(42).await);
+    assert!(outcomes_of_42.contains(&true) && outcomes_of_42.contains(&false));
+}
+
+#[tokio::test]
+async fn broken_responses_are_never_cached() {
+    let upstream = upstream().await;
+    let injector = FaultInjector::new(0).with_script([Some(Fault::MalformedJson), None]);
+    let client = builder(upstream.environment(), CacheMode::Default)
+        .with_fault_injector(injector)
+        .try_build()
+        .unwrap();
+
+    get_soft(&client).await.unwrap_err();
+    get_soft(&client).await.unwrap();
+    get_soft(&client).await.unwrap();
+
+    assert_eq!(upstream.hits(), 1);
+}
@@ -0,0 +1,307 @@
+//! Projects the models into past generations and version groups, from the static resources and their history.
+
+use rustemon::{
+    model::{
+        generational::Chronology,
+        moves::{Move, PastMoveStatValues},
+        pokemon::{
+            Ability, Pokemon, PokemonStatPast, PokemonType, PokemonTypePast, Type, TypeRelations,
+            TypeRelationsPast,
+        },
+    },
+    static_resources,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+/// The version groups of the first six generations, with their order in `PokeApi`.
+fn chronology() -> Chronology {
+    [
+        ("red-blue", 1, 1),
+        ("yellow", 2, 1),
+        ("gold-silver", 3, 2),
+        ("crystal", 4, 2),
+        ("ruby-sapphire", 5, 3),
+        ("emerald", 6, 3),
+        ("firered-leafgreen", 7, 3),
+        ("diamond-pearl", 8, 4),
+        ("platinum", 9, 4),
+        ("heartgold-soulsilver", 10, 4),
+        ("black-white", 11, 5),
+        ("colosseum", 12, 3),
+        ("xd", 13, 3),
+        ("black-2-white-2", 14, 5),
+        ("x-y", 15, 6),
+    ]
+    .into_iter()
+    .fold(
+        Chronology::default(),
+        |chronology, (name, order, generation)| {
+            chronology.with_version_group(name, order, generation)
+        },
+    )
+}
+
+fn from<T: DeserializeOwned>(value: Value) -> T {
+    serde_json::from_value(value).unwrap()
+}
+
+fn reference(endpoint: &str, name: &str) -> Value {
+    json!({ "name": name, "url": format!("https://pokeapi.co/api/v2/{endpoint}/{name}/") })
+}
+
+fn generation(number: i64) -> Value {
+    json!({ "name": format!("generation-{number}"), "url": format!("https://pokeapi.co/api/v2/generation/{number}/") })
+}
+
+fn type_names(pokemon: &Pokemon) -> Vec<&str> {
+    pokemon
+        .types
+        .iter()
+        .map(|t| t.type_.name.as_str())
+        .collect()
+}
+
+/// Clefairy, Fairy type since generation 6 and Normal type before.
+fn clefairy() -> Pokemon {
+    let mut clefairy = static_resources::get_pokemon();
+    clefairy.name = "clefairy".to_owned();
+    clefairy.types = vec![from::<PokemonType>(
+        json!({ "slot": 1, "type": reference("type", "fairy") }),
+    )];
+    clefairy.past_types = vec![from::<PokemonTypePast>(json!({
+        "generation": generation(5),
+        "types": [{ "slot": 1, "type": reference("type", "normal") }]
+    }))];
+
+    clefairy
+}
+
+/// Ghost, dealing double damage to Psychic since generation 2 and no damage in generation 1.
+fn ghost() -> Type {
+    let relations = |no: &[&str], double: &[&str]| {
+        let references = |names: &[&str]| {
+            names
+                .iter()
+                .map(|name| reference("type", name))
+                .collect::<Vec<_>>()
+        };
+        json!({
+            "no_damage_to": references(no),
+            "half_damage_to": [],
+            "double_damage_to": references(double),
+            "no_damage_from": [],
+            "half_damage_from": [],
+            "double_damage_from": [],
+        })
+    };
+
+    let mut ghost = static_resources::get_type();
+    ghost.name = "ghost".to_owned();
+    ghost.damage_relations = from::<TypeRelations>(relations(&["normal"], &["psychic", "ghost"]));
+    ghost.past_damage_relations = vec![from::<TypeRelationsPast>(json!({
+        "generation": generation(1),
+        "damage_relations": relations(&["normal", "psychic"], &["ghost"])
+    }))];
+
+    ghost
+}
+
+/// Fire Blast, its power lowered in X and Y and its chance to burn raised in Gold and Silver.
+fn fire_blast() -> Move {
+    let past = |version_group: &str, power: Option<i64>, effect_chance: Option<i64>| {
+        from::<PastMoveStatValues>(json!({
+            "accuracy": null,
+            "effect_chance": effect_chance,
+            "power": power,
+            "pp": null,
+            "effect_entries": [],
+            "type": null,
+            "version_group": reference("version-group", version_group)
+        }))
+    };
+
+    let mut fire_blast = static_resources::get_move();
+    fire_blast.power = Some(110);
+    fire_blast.effect_chance = Some(10);
+    fire_blast.past_values = vec![
+        past("gold-silver", None, Some(30)),
+        past("x-y", Some(120), None),
+    ];
+
+    fire_blast
+}
+
+#[test]
+fn past_types_apply_up_to_and_including_their_generation() {
+    let chronology = chronology();
+
+    assert_eq!(
+        type_names(&clefairy().as_of(chronology.at_generation(5))),
+        ["normal"]
+    );
+    assert_eq!(
+        type_names(&clefairy().as_of(chronology.at_generation(6))),
+        ["fairy"]
+    );
+    assert!(
+        clefairy()
+            .as_of(chronology.at_generation(1))
+            .past_types
+            .is_empty()
+    );
+}
+
+#[test]
+fn past_abilities_apply_up_to_and_including_their_generation() {
+    let charizard = static_resources::get_pokemon();
+    let chronology = chronology();
+    let abilities = |generation| {
+        charizard
+            .as_of(chronology.at_generation(generation))
+            .abilities
+            .into_iter()
+            .filter_map(|ability| Some(ability.ability?.name))
+            .collect::<Vec<_>>()
+    };
+
+    // Charizard had no ability before generation 3, and no hidden ability up to generation 4.
+    assert!(abilities(2).is_empty());
+    assert_eq!(abilities(4), ["blaze"]);
+    assert_eq!(abilities(5), ["blaze", "solar-power"]);
+}
+
+#[test]
+fn past_stats_apply_up_to_and_including_their_generation() {
+    let mut charizard = static_resources::get_pokemon();
+    charizard.past_stats = vec![from::<PokemonStatPast>(json!({
+        "generation": generation(1),
+        "stats": [{ "stat": reference("stat", "special-attack"), "effort": 0, "base_stat": 85 }]
+    }))];
+    let chronology = chronology();
+    let special_attack = |generation| {
+        charizard
+            .as_of(chronology.at_generation(generation))
+            .stats
+            .into_iter()
+            .find(|stat| stat.stat.name == "special-attack")
+            .unwrap()
+            .base_stat
+    };
+
+    assert_eq!(special_attack(1), 85);
+    assert_eq!(special_attack(2), 109);
+}
+
+#[test]
+fn past_damage_relations_apply_up_to_and_including_their_generation() {
+    let chronology = chronology();
+    let no_damage_to = |generation| {
+        ghost()
+            .as_of(chronology.at_generation(generation))
+            .unwrap()
+            .damage_relations
+            .no_damage_to
+            .into_iter()
+            .map(|t| t.name)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(no_damage_to(1), ["normal", "psychic"]);
+    assert_eq!(no_damage_to(2), ["normal"]);
+}
+
+#[test]
+fn past_values_apply_strictly_before_their_version_group() {
+    let fire_blast = fire_blast();
+    let chronology = chronology();
+    let as_of = |version_group| {
+        fire_blast
+            .as_of(chronology.at_version_group(version_group).unwrap())
+            .unwrap()
+    };
+
+    let red_blue = as_of("red-blue");
+    assert_eq!(red_blue.power, Some(120));
+    assert_eq!(red_blue.effect_chance, Some(30));
+    assert!(red_blue.past_values.is_empty());
+
+    let gold_silver = as_of("gold-silver");
+    assert_eq!(gold_silver.power, Some(120));
+    assert_eq!(gold_silver.effect_chance, Some(10));
+
+    assert_eq!(as_of("black-2-white-2").power, Some(120));
+    assert_eq!(as_of("x-y").power, Some(110));
+}
+
+#[test]
+fn effect_changes_apply_strictly_before_their_version_group() {
+    let static_: Ability = static_resources::get_ability();
+    let chronology = chronology();
+    let short_effect = |version_group| {
+        let static_ = static_
+            .as_of(chronology.at_version_group(version_group).unwrap())
+            .unwrap();
+        assert!(static_.effect_changes.is_empty());
+
+        static_
+            .effect_entries
+            .into_iter()
+            .find(|effect| effect.language.name == "en")
+            .unwrap()
+            .short_effect
+    };
+
+    assert_eq!(short_effect("ruby-sapphire"), "Has no overworld effect.");
+    assert_eq!(
+        short_effect("emerald"),
+        "Has a 30% chance of paralyzing attacking Pokémon on contact."
+    );
+}
+
+#[test]
+fn generations_later_than_every_entry_give_the_current_data() {
+    let chronology = chronology();
+    let era = chronology.at_generation(9);
+
+    assert_eq!(type_names(&clefairy().as_of(era)), ["fairy"]);
+    assert_eq!(
+        ghost().as_of(era).unwrap().damage_relations,
+        ghost().damage_relations
+    );
+    assert_eq!(fire_blast().as_of(era).unwrap().power, Some(110));
+    assert_eq!(
+        static_resources::get_ability()
+            .as_of(era)
+            .unwrap()
+            .effect_entries,
+        static_resources::get_ability().effect_entries
+    );
+}
+
+#[test]
+fn resources_introduced_later_are_missing() {
+    let chronology = chronology();
+    let mut fire_blast = fire_blast();
+    fire_blast.generation = from(generation(4));
+
+    assert!(fire_blast.as_of(chronology.at_generation(3)).is_none());
+    assert!(fire_blast.as_of(chronology.at_generation(4)).is_some());
+}
+
+#[test]
+fn chronology_knows_the_order_and_generation_of_the_version_groups() {
+    let chronology = chronology();
+
+    assert_eq!(chronology.order_of("colosseum"), Some(12));
+    assert_eq!(chronology.generation_of("colosseum"), Some(3));
+    assert_eq!(chronology.order_of("scarlet-violet"), None);
+    assert!(chronology.at_version_group("scarlet-violet").is_none());
+
+    // The era of a generation is the one of its latest version group.
+    assert_eq!(
+        chronology.at_generation(3),
+        chronology.at_version_group("xd").unwrap()
+    );
+    assert_eq!(chronology.at_generation(9).generation(), 9);
+}
@@ -0,0 +1,56 @@
+//! Round-trips payloads holding fields unknown to the models.
+
+use rustemon::model::{berries::BerryFirmness, extra::unknown_keys};
+use serde::{Serialize, Serializer};
+use serde_json::{Value, json};
+
+/// Payload of the `soft` berry firmness, with fields unknown to its models.
+fn drifted() -> Value {
+    json!({
+        "id": 1,
+        "name": "soft",
+        "smoothness": 10,
+        "berries": [{ "name": "pecha", "url": "https://pokeapi.co/api/v2/berry/3/" }],
+        "names": [{
+            "name": "Soft",
+            "plural": "Softs",
+            "language": { "name": "en", "url": "https://pokeapi.co/api/v2/language/9/" }
+        }],
+    })
+}
+
+#[test]
+fn unknown_fields_survive_a_round_trip() {
+    let firmness: BerryFirmness = serde_json::from_value(drifted()).unwrap();
+
+    assert_eq!(serde_json::to_value(&firmness).unwrap(), drifted());
+}
+
+#[test]
+fn unknown_fields_are_listed_by_path() {
+    let firmness: BerryFirmness = serde_json::from_value(drifted()).unwrap();
+
+    assert_eq!(unknown_keys(&firmness), ["names[0].plural", "smoothness"]);
+    // Listing them leaves them serialized.
+    assert_eq!(serde_json::to_value(&firmness).unwrap(), drifted());
+}
+
+#[test]
+fn unknown_fields_can_be_listed_while_listing_them() {
+    /// Model listing the unknown fields of the one it wraps while serializing.
+    struct Listing(BerryFirmness);
+
+    impl Serialize for Listing {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            unknown_keys(&self.0);
+            self.0.serialize(serializer)
+        }
+    }
+
+    let firmness: BerryFirmness = serde_json::from_value(drifted()).unwrap();
+
+    assert_eq!(
+        unknown_keys(&Listing(firmness)),
+        ["names[0].plural", "smoothness"]
+    );
+}
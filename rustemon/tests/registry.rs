@@ -0,0 +1,102 @@
+//! Looks up the games in the built-in registry, and orders them by release.
+
+use std::cmp::Ordering;
+
+use rustemon::{
+    model::{resource::FlavorText, versioned::Versioned},
+    registry::Registry,
+};
+use serde_json::json;
+
+fn language() -> serde_json::Value {
+    json!({ "name": "en", "url": "https://pokeapi.co/api/v2/language/9/" })
+}
+
+#[test]
+fn generations_are_looked_up_by_id_and_name() {
+    let registry = Registry::builtin();
+
+    let generation = registry.generation("generation-iii").unwrap();
+    assert_eq!(generation.id, 3);
+    assert_eq!(generation.main_region, "hoenn");
+    assert_eq!(registry.generation_by_id(3), Some(generation));
+    assert_eq!(registry.generations().first().unwrap().name, "generation-i");
+}
+
+#[test]
+fn version_groups_and_versions_are_looked_up_by_id_and_name() {
+    let registry = Registry::builtin();
+
+    let version_group = registry.version_group("black-white").unwrap();
+    assert_eq!(version_group.id, 11);
+    assert_eq!(version_group.generation, 5);
+    assert_eq!(version_group.versions, ["black", "white"]);
+    assert_eq!(registry.version_group_by_id(11), Some(version_group));
+
+    let version = registry.version("white").unwrap();
+    assert_eq!(version.version_group, "black-white");
+    assert_eq!(registry.version_by_id(version.id), Some(version));
+    assert_eq!(registry.version_group_of("white"), Some(version_group));
+    assert_eq!(registry.generation_of("black-white").unwrap().id, 5);
+}
+
+#[test]
+fn unknown_names_and_ids_are_not_found() {
+    let registry = Registry::builtin();
+
+    assert_eq!(registry.generation("generation-0"), None);
+    assert_eq!(registry.generation_by_id(0), None);
+    assert_eq!(registry.version_group("unreleased"), None);
+    assert_eq!(registry.version_group_by_id(-1), None);
+    assert_eq!(registry.version("unreleased"), None);
+    assert_eq!(registry.version_group_of("unreleased"), None);
+    assert_eq!(registry.generation_of("unreleased"), None);
+    assert_eq!(registry.scope_of_version("unreleased"), None);
+    assert_eq!(registry.compare_version_groups("unreleased", "xd"), None);
+    assert_eq!(registry.compare_versions("black", "unreleased"), None);
+}
+
+#[test]
+fn version_groups_are_ordered_by_release_rather_than_id() {
+    let registry = Registry::builtin();
+
+    // Colosseum and XD have higher ids than Black and White, but were released before them.
+    assert!(
+        registry.version_group("colosseum").unwrap().id
+            > registry.version_group("black-white").unwrap().id
+    );
+    assert_eq!(
+        registry.compare_version_groups("colosseum", "black-white"),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        registry.compare_version_groups("xd", "black-white"),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        registry.compare_version_groups("xd", "colosseum"),
+        Some(Ordering::Greater)
+    );
+    assert_eq!(
+        registry.compare_versions("colosseum", "white"),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        registry.compare_versions("black", "white"),
+        Some(Ordering::Equal)
+    );
+}
+
+#[test]
+fn latest_entries_are_picked_by_release() {
+    let registry = Registry::builtin();
+    let texts: Vec<FlavorText> = serde_json::from_value(json!([
+        { "flavor_text": "white", "language": language(), "version": { "name": "white", "url": "https://pokeapi.co/api/v2/version/18/" } },
+        { "flavor_text": "xd", "language": language(), "version": { "name": "xd", "url": "https://pokeapi.co/api/v2/version/20/" } },
+        { "flavor_text": "colosseum", "language": language(), "version": { "name": "colosseum", "url": "https://pokeapi.co/api/v2/version/19/" } },
+    ]))
+    .unwrap();
+
+    let latest = texts.latest(|a, b| registry.compare_version_tags(a, b));
+    assert_eq!(latest.unwrap().flavor_text, "white");
+}
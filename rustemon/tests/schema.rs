@@ -0,0 +1,62 @@
+//! Checks the key properties of the exported JSON Schemas.
+
+use rustemon::schema::resource_schemas;
+use serde_json::{Value, json};
+
+/// Returns the schema of the resources of `endpoint`, as JSON.
+fn schema(endpoint: &str) -> Value {
+    let schemas = resource_schemas();
+
+    serde_json::to_value(&schemas[endpoint]).unwrap()
+}
+
+#[test]
+fn every_endpoint_has_a_schema() {
+    let schemas = resource_schemas();
+
+    assert_eq!(schemas.len(), 48);
+    assert!(schemas.contains_key("pokemon"));
+    assert!(schemas.contains_key("pokemon-species"));
+    assert!(schemas.contains_key("language"));
+}
+
+#[test]
+fn pokemon_requires_all_its_fields() {
+    let pokemon = schema("pokemon");
+
+    assert_eq!(pokemon["title"], "Pokemon");
+    assert_eq!(pokemon["type"], "object");
+
+    let required = pokemon["required"].as_array().unwrap();
+    for field in ["id", "name", "base_experience", "species", "stats", "types"] {
+        assert!(required.contains(&json!(field)), "{field} isn't required");
+    }
+}
+
+#[test]
+fn optional_fields_are_nullable() {
+    let pokemon = schema("pokemon");
+
+    assert_eq!(
+        pokemon["properties"]["base_experience"]["type"],
+        json!(["integer", "null"])
+    );
+}
+
+#[test]
+fn references_describe_the_endpoint_they_point_to() {
+    let pokemon = schema("pokemon");
+
+    assert_eq!(
+        pokemon["properties"]["species"]["$ref"],
+        "#/$defs/NamedApiResource_for_PokemonSpecies"
+    );
+
+    let species = &pokemon["$defs"]["NamedApiResource_for_PokemonSpecies"];
+    assert_eq!(species["x-endpoint"], "pokemon-species");
+    assert_eq!(species["required"], json!(["name", "url"]));
+    assert_eq!(
+        species["properties"]["url"]["pattern"],
+        "/pokemon-species/[0-9]+/$"
+    );
+}
@@ -0,0 +1,91 @@
+//! Interns the strings of the views decoded with the `shared-strings` feature.
+
+use std::thread;
+
+use rustemon::model::{
+    resource::{FlavorText, Name, NamedApiResource},
+    shared::{SharedFlavorText, SharedName, SharedNamedApiResource, SharedStr},
+    utility::Language,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+/// Berry firmness only keeping its names.
+#[derive(Deserialize)]
+struct Names {
+    names: Vec<SharedName>,
+}
+
+#[test]
+fn repeated_strings_share_a_single_allocation() {
+    let firmness: Names = serde_json::from_value(json!({
+        "id": 1,
+        "name": "soft",
+        "berries": [],
+        "names": [
+            { "name": "Soft", "language": { "name": "en", "url": "https://pokeapi.co/api/v2/language/9/" } },
+            { "name": "Soft", "language": { "name": "en", "url": "https://pokeapi.co/api/v2/language/9/" } },
+        ],
+    }))
+    .unwrap();
+    let [first, second] = &firmness.names[..] else {
+        panic!("expected two names");
+    };
+
+    assert_eq!(first.name.as_ptr(), second.name.as_ptr());
+    assert_eq!(first.language.url.as_ptr(), second.language.url.as_ptr());
+}
+
+#[test]
+fn strings_interned_concurrently_are_shared() {
+    let shared: Vec<_> = thread::scope(|scope| {
+        let threads: Vec<_> = (0..8)
+            .map(|_| scope.spawn(|| SharedStr::new("interned-concurrently")))
+            .collect();
+
+        threads.into_iter().map(|t| t.join().unwrap()).collect()
+    });
+
+    assert!(shared.iter().all(|s| s.as_ptr() == shared[0].as_ptr()));
+}
+
+#[test]
+fn views_convert_from_and_into_the_models() {
+    let english = NamedApiResource::<Language>::new("en", "https://pokeapi.co/api/v2/language/9/");
+    let name: Name = serde_json::from_value(json!({
+        "name": "Soft",
+        "language": { "name": "en", "url": "https://pokeapi.co/api/v2/language/9/" }
+    }))
+    .unwrap();
+    let flavor_text: FlavorText = serde_json::from_value(json!({
+        "flavor_text": "Soft.",
+        "language": { "name": "en", "url": "https://pokeapi.co/api/v2/language/9/" },
+        "version": null
+    }))
+    .unwrap();
+
+    let shared = SharedNamedApiResource::from(&english);
+    assert_eq!(shared.name, "en");
+    assert_eq!(NamedApiResource::from(shared), english);
+
+    let shared = SharedName::from(&name);
+    assert_eq!(shared.name, "Soft");
+    assert_eq!(Name::from(shared), name);
+
+    let shared = SharedFlavorText::from(&flavor_text);
+    assert_eq!(shared.language.url, english.url);
+    assert_eq!(FlavorText::from(shared), flavor_text);
+}
+
+#[test]
+fn unused_strings_are_released() {
+    let shared = SharedStr::new("released-once-dropped");
+    drop(shared);
+
+    assert!(rustemon::model::shared::release_unused() >= 1);
+    // Released strings are interned again when needed.
+    assert_eq!(
+        SharedStr::new("released-once-dropped"),
+        "released-once-dropped"
+    );
+}
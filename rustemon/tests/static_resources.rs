@@ -0,0 +1,122 @@
+//! Checks that the static resources pulled by `rustemon-static-test` cover every endpoint.
+
+use rustemon::{
+    model::{
+        berries, contests, encounters, evolution, games, items, locations, machines, moves,
+        pokemon, utility,
+    },
+    static_resources,
+};
+
+/// Number of resources expected at least for every endpoint.
+const MIN_RESOURCES: usize = 2;
+
+/// Pokémon whose encounters are pulled.
+const ENCOUNTERS: [i64; 3] = [1, 6, 133];
+
+/// Lists the endpoints lacking resources or a first page.
+macro_rules! coverage_problems {
+    ($($model:ty),+ $(,)?) => {{
+        let mut problems = Vec::new();
+        $(
+            let name = <$model as rustemon::model::resource::Endpoint>::NAME;
+            let resources = static_resources::get_all::<$model>().len();
+            if resources < MIN_RESOURCES {
+                problems.push(format!("{name} has {resources} resources"));
+            }
+            let page = static_resources::get_page::<$model>().map_or(0, |page| page.results.len());
+            if page == 0 {
+                problems.push(format!("{name} has an empty first page"));
+            }
+        )+
+        problems
+    }};
+}
+
+#[test]
+#[ignore = "the committed fixtures predate this check, and must be pulled again from PokeApi with rustemon-static-test"]
+fn every_endpoint_has_several_resources_and_a_page() {
+    let problems = coverage_problems!(
+        // Berries endpoints
+        berries::Berry,
+        berries::BerryFirmness,
+        berries::BerryFlavor,
+        // Contests endpoints
+        contests::ContestType,
+        contests::ContestEffect,
+        contests::SuperContestEffect,
+        // Encounters endpoints
+        encounters::EncounterMethod,
+        encounters::EncounterCondition,
+        encounters::EncounterConditionValue,
+        // Evolution endpoints
+        evolution::EvolutionChain,
+        evolution::EvolutionTrigger,
+        // Games endpoints
+        games::Generation,
+        games::Pokedex,
+        games::Version,
+        games::VersionGroup,
+        // Items endpoints
+        items::Item,
+        items::ItemAttribute,
+        items::ItemCategory,
+        items::ItemFlingEffect,
+        items::ItemPocket,
+        // Locations endpoints
+        locations::Location,
+        locations::LocationArea,
+        locations::PalParkArea,
+        locations::Region,
+        // Machines endpoints
+        machines::Machine,
+        // Moves endpoints
+        moves::Move,
+        moves::MoveAilment,
+        moves::MoveBattleStyle,
+        moves::MoveCategory,
+        moves::MoveDamageClass,
+        moves::MoveLearnMethod,
+        moves::MoveTarget,
+        // Pokemon endpoints
+        pokemon::Ability,
+        pokemon::Characteristic,
+        pokemon::EggGroup,
+        pokemon::Gender,
+        pokemon::GrowthRate,
+        pokemon::Nature,
+        pokemon::PokeathlonStat,
+        pokemon::Pokemon,
+        pokemon::PokemonColor,
+        pokemon::PokemonForm,
+        pokemon::PokemonHabitat,
+        pokemon::PokemonShape,
+        pokemon::PokemonSpecies,
+        pokemon::Stat,
+        pokemon::Type,
+        // Utility endpoints
+        utility::Language,
+    );
+
+    assert!(problems.is_empty(), "{problems:#?}");
+}
+
+#[test]
+#[ignore = "the committed fixtures predate this check, and must be pulled again from PokeApi with rustemon-static-test"]
+fn encounters_are_pulled() {
+    for id in ENCOUNTERS {
+        assert!(
+            static_resources::get_encounters_by_id(id).is_some(),
+            "no encounters for the Pokémon {id}"
+        );
+    }
+
+    let encountered = ENCOUNTERS
+        .iter()
+        .filter_map(|id| static_resources::get_encounters_by_id(*id))
+        .any(|encounters| !encounters.is_empty());
+    assert!(
+        encountered,
+        "none of the Pokémon {ENCOUNTERS:?} can be encountered"
+    );
+}
@@ -0,0 +1,211 @@
+//! Decodes drifted payloads of a local stand-in of `PokeApi` with every [`Strictness`].
+
+mod common;
+
+use common::{Upstream, builder, soft};
+use rustemon::{
+    client::{CacheMode, RustemonClient},
+    decode::{DecodeWarning, DriftKind, Strictness},
+    error::Error,
+    model::berries::BerryFirmness,
+};
+use serde_json::{Value, json};
+
+const PATH: &str = "/api/v2/berry-firmness/soft";
+
+/// Serves `payload` as the `soft` berry firmness, to a client with the given strictness.
+async fn serve(payload: Value, strictness: Strictness) -> (Upstream, RustemonClient) {
+    let upstream = Upstream::start([(PATH, payload.to_string())]).await;
+    let client = builder(upstream.environment(), CacheMode::NoStore)
+        .with_strictness(strictness)
+        .try_build()
+        .unwrap();
+
+    (upstream, client)
+}
+
+async fn get(
+    payload: Value,
+    strictness: Strictness,
+) -> (Result<BerryFirmness, Error>, Vec<DecodeWarning>) {
+    let (_upstream, client) = serve(payload, strictness).await;
+    let result = rustemon::berries::berry_firmness::get_by_name("soft", &client).await;
+
+    (result, client.take_warnings())
+}
+
+/// The drifts of the warnings, as `(path, kind)` sorted by path.
+fn drifts(warnings: &[DecodeWarning]) -> Vec<(&str, DriftKind)> {
+    let mut drifts: Vec<_> = warnings
+        .iter()
+        .map(|warning| (warning.path.as_str(), warning.kind))
+        .collect();
+    drifts.sort_by_key(|(path, _)| *path);
+
+    drifts
+}
+
+/// Asserts that `error` is the failure of the given mode to decode the field at `path`.
+fn assert_decode_error(error: Error, strictness: Strictness, path: &str) {
+    match (strictness, error) {
+        (
+            Strictness::Strict | Strictness::Lenient,
+            Error::Decode {
+                model,
+                url,
+                path: p,
+                ..
+            },
+        ) => {
+            assert!(model.ends_with("BerryFirmness"));
+            assert!(url.ends_with(PATH));
+            assert_eq!(p, path);
+        }
+        #[cfg(not(feature = "simd-json"))]
+        (Strictness::Default, Error::Reqwest(error)) => assert!(error.is_decode(), "{error:?}"),
+        #[cfg(feature = "simd-json")]
+        (Strictness::Default, Error::SimdDecode { model, .. }) => {
+            assert!(model.ends_with("BerryFirmness"));
+        }
+        (strictness, error) => panic!("unexpected error in {strictness:?} mode: {error:?}"),
+    }
+}
+
+fn with(mut payload: Value, path: &[&str], value: Option<Value>) -> Value {
+    let (field, parents) = path.split_last().unwrap();
+    let mut target = &mut payload;
+    for parent in parents {
+        target = match parent.parse::<usize>() {
+            Ok(index) => &mut target[index],
+            Err(_) => &mut target[*parent],
+        };
+    }
+    let object = target.as_object_mut().unwrap();
+    match value {
+        Some(value) => object.insert((*field).to_owned(), value),
+        None => object.remove(*field),
+    };
+
+    payload
+}
+
+#[tokio::test]
+async fn matching_payloads_decode_in_every_mode() {
+    for strictness in [Strictness::Default, Strictness::Strict, Strictness::Lenient] {
+        let (result, warnings) = get(soft(), strictness).await;
+
+        assert_eq!(result.unwrap().name, "soft");
+        assert!(warnings.is_empty());
+    }
+}
+
+// With `preserve-unknown`, the unknown fields are kept by the models instead.
+#[cfg(not(feature = "preserve-unknown"))]
+#[tokio::test]
+async fn unknown_fields_are_ignored_rejected_or_reported() {
+    let drifted = with(soft(), &["smoothness"], Some(json!(10)));
+    let drifted = with(drifted, &["names", "0", "plural"], Some(json!("Softs")));
+
+    let (result, warnings) = get(drifted.clone(), Strictness::Default).await;
+    let firmness = result.unwrap();
+    assert_eq!(firmness.name, "soft");
+    assert!(firmness.extra.is_empty());
+    assert!(firmness.names[0].extra.is_empty());
+    assert!(warnings.is_empty());
+
+    let (result, _) = get(drifted.clone(), Strictness::Strict).await;
+    match result.unwrap_err() {
+        Error::UnknownFields { model, url, fields } => {
+            assert!(model.ends_with("BerryFirmness"));
+            assert!(url.ends_with(PATH));
+            assert_eq!(fields, ["names[0].plural", "smoothness"]);
+        }
+        error => panic!("expected unknown fields, got {error:?}"),
+    }
+
+    let (result, warnings) = get(drifted, Strictness::Lenient).await;
+    assert_eq!(result.unwrap().name, "soft");
+    assert_eq!(
+        drifts(&warnings),
+        [
+            ("names[0].plural", DriftKind::Unknown),
+            ("smoothness", DriftKind::Unknown)
+        ]
+    );
+    assert!(warnings[0].url.ends_with(PATH));
+}
+
+#[tokio::test]
+async fn wrong_types_fail_in_every_mode() {
+    let drifted = with(soft(), &["names", "0", "name"], Some(json!(42)));
+
+    for strictness in [Strictness::Default, Strictness::Strict, Strictness::Lenient] {
+        let (result, warnings) = get(drifted.clone(), strictness).await;
+
+        assert_decode_error(result.unwrap_err(), strictness, "names[0].name");
+        assert!(warnings.is_empty());
+    }
+}
+
+#[tokio::test]
+async fn missing_fields_fail_unless_lenient() {
+    let drifted = with(soft(), &["names"], None);
+    let drifted = with(drifted, &["berries", "0", "url"], None);
+
+    for strictness in [Strictness::Default, Strictness::Strict] {
+        let (result, _) = get(drifted.clone(), strictness).await;
+
+        assert_decode_error(result.unwrap_err(), strictness, "berries[0]");
+    }
+
+    let (result, warnings) = get(drifted, Strictness::Lenient).await;
+    let firmness = result.unwrap();
+    assert!(firmness.names.is_empty());
+    assert_eq!(firmness.berries[0].name, "pecha");
+    assert_eq!(firmness.berries[0].url, "");
+    assert_eq!(
+        drifts(&warnings),
+        [
+            ("berries[0].url", DriftKind::Missing),
+            ("names", DriftKind::Missing)
+        ]
+    );
+}
+
+#[tokio::test]
+async fn null_fields_fall_back_to_their_default_when_lenient() {
+    let drifted = with(soft(), &["name"], Some(Value::Null));
+    let drifted = with(drifted, &["names", "0", "language"], Some(Value::Null));
+
+    let (result, _) = get(drifted.clone(), Strictness::Strict).await;
+    assert_decode_error(result.unwrap_err(), Strictness::Strict, "name");
+
+    let (result, warnings) = get(drifted, Strictness::Lenient).await;
+    let firmness = result.unwrap();
+    assert_eq!(firmness.name, "");
+    assert_eq!(firmness.names[0].language.name, "");
+    // The fields of the defaulted language aren't reported on their own.
+    assert_eq!(
+        drifts(&warnings),
+        [
+            ("name", DriftKind::Null),
+            ("names[0].language", DriftKind::Null)
+        ]
+    );
+}
+
+// Without `preserve-unknown`, the missing fields are defaulted in a single pass, regardless of their number.
+#[cfg(not(feature = "preserve-unknown"))]
+#[tokio::test]
+async fn many_missing_fields_are_defaulted_in_a_single_pass() {
+    let berries: Vec<Value> = (0..5_000)
+        .map(|i| json!({ "name": format!("berry-{i}") }))
+        .collect();
+    let drifted = with(soft(), &["berries"], Some(Value::Array(berries)));
+
+    let (result, warnings) = get(drifted, Strictness::Lenient).await;
+
+    assert_eq!(result.unwrap().berries.len(), 5_000);
+    assert_eq!(warnings.len(), 5_000);
+    assert_eq!(warnings[4_999].path, "berries[4999].url");
+}
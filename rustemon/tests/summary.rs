@@ -0,0 +1,163 @@
+//! Decodes the summary models, their lazy fields only being decoded on access.
+
+mod common;
+
+use common::{Upstream, builder};
+use rustemon::{
+    client::CacheMode,
+    model::{
+        lazy::Lazy,
+        moves::MoveSummary,
+        pokemon::{PokemonMove, PokemonSummary},
+    },
+};
+use serde_json::{Value, json};
+
+/// Payload of `bulbasaur`, its sprites not matching the model.
+fn bulbasaur() -> Value {
+    json!({
+        "id": 1,
+        "name": "bulbasaur",
+        "base_experience": 64,
+        "height": 7,
+        "is_default": true,
+        "order": 1,
+        "weight": 69,
+        "abilities": [{
+            "is_hidden": false,
+            "slot": 1,
+            "ability": { "name": "overgrow", "url": "https://pokeapi.co/api/v2/ability/65/" }
+        }],
+        "forms": [{ "name": "bulbasaur", "url": "https://pokeapi.co/api/v2/pokemon-form/1/" }],
+        "species": { "name": "bulbasaur", "url": "https://pokeapi.co/api/v2/pokemon-species/1/" },
+        "stats": [{
+            "stat": { "name": "hp", "url": "https://pokeapi.co/api/v2/stat/1/" },
+            "effort": 0,
+            "base_stat": 45
+        }],
+        "types": [{ "slot": 1, "type": { "name": "grass", "url": "https://pokeapi.co/api/v2/type/12/" } }],
+        "moves": [{
+            "move": { "name": "razor-wind", "url": "https://pokeapi.co/api/v2/move/13/" },
+            "version_group_details": [{
+                "move_learn_method": { "name": "egg", "url": "https://pokeapi.co/api/v2/move-learn-method/2/" },
+                "version_group": { "name": "gold-silver", "url": "https://pokeapi.co/api/v2/version-group/3/" },
+                "level_learned_at": 0
+            }]
+        }],
+        "sprites": { "front_default": 1 }
+    })
+}
+
+/// Payload of `thunderbolt`.
+fn thunderbolt() -> Value {
+    json!({
+        "id": 85,
+        "name": "thunderbolt",
+        "accuracy": 100,
+        "effect_chance": 10,
+        "pp": 15,
+        "priority": 0,
+        "power": 90,
+        "damage_class": { "name": "special", "url": "https://pokeapi.co/api/v2/move-damage-class/3/" },
+        "generation": { "name": "generation-i", "url": "https://pokeapi.co/api/v2/generation/1/" },
+        "meta": null,
+        "stat_changes": [],
+        "target": { "name": "selected-pokemon", "url": "https://pokeapi.co/api/v2/move-target/10/" },
+        "type": { "name": "electric", "url": "https://pokeapi.co/api/v2/type/13/" },
+        "flavor_text_entries": [],
+        "learned_by_pokemon": [{ "name": "pikachu", "url": "https://pokeapi.co/api/v2/pokemon/25/" }]
+    })
+}
+
+#[test]
+fn pokemon_summaries_decode_the_eager_fields() {
+    let summary: PokemonSummary = serde_json::from_str(&bulbasaur().to_string()).unwrap();
+
+    assert_eq!(summary.id, 1);
+    assert_eq!(summary.name, "bulbasaur");
+    assert_eq!(summary.base_experience, Some(64));
+    assert_eq!(
+        summary.abilities[0].ability.as_ref().unwrap().name,
+        "overgrow"
+    );
+    assert_eq!(summary.species.name, "bulbasaur");
+    assert_eq!(summary.stats[0].base_stat, 45);
+    assert_eq!(summary.types[0].type_.name, "grass");
+    assert_eq!(summary.sprites.raw(), r#"{"front_default":1}"#);
+}
+
+#[test]
+fn move_summaries_leave_out_the_other_fields() {
+    let summary: MoveSummary = serde_json::from_str(&thunderbolt().to_string()).unwrap();
+
+    assert_eq!(summary.name, "thunderbolt");
+    assert_eq!(summary.power, Some(90));
+    assert_eq!(summary.effect_chance, Some(10));
+    assert_eq!(summary.damage_class.name, "special");
+    assert_eq!(summary.type_.name, "electric");
+    assert_eq!(summary.meta, None);
+}
+
+#[test]
+fn lazy_fields_are_decoded_on_access() {
+    let summary: PokemonSummary = serde_json::from_str(&bulbasaur().to_string()).unwrap();
+
+    let moves = summary.moves.get().unwrap();
+    assert_eq!(moves[0].move_.name, "razor-wind");
+    assert_eq!(
+        moves[0].version_group_details[0].version_group.name,
+        "gold-silver"
+    );
+    // The decoded value is kept, and returned by later accesses.
+    assert!(std::ptr::eq(moves, summary.moves.get().unwrap()));
+
+    let moves = summary.moves.clone().into_inner().unwrap();
+    assert_eq!(moves[0].move_.name, "razor-wind");
+}
+
+#[test]
+fn lazy_fields_not_decoded_yet_are_decoded_into_the_inner_value() {
+    let moves: Lazy<Vec<PokemonMove>> =
+        serde_json::from_str(&bulbasaur()["moves"].to_string()).unwrap();
+
+    assert_eq!(moves.into_inner().unwrap()[0].move_.name, "razor-wind");
+}
+
+#[test]
+fn malformed_lazy_fields_fail_on_access() {
+    let summary: PokemonSummary = serde_json::from_str(&bulbasaur().to_string()).unwrap();
+
+    assert!(summary.sprites.get().is_err());
+    // Failing leaves the field undecoded, failing again.
+    assert!(summary.sprites.get().is_err());
+    assert!(summary.sprites.into_inner().is_err());
+}
+
+#[test]
+fn lazy_fields_can_be_read_from_values() {
+    let summary: PokemonSummary = serde_json::from_value(bulbasaur()).unwrap();
+
+    assert_eq!(summary.moves.get().unwrap()[0].move_.name, "razor-wind");
+}
+
+#[tokio::test]
+async fn summaries_are_fetched_through_the_endpoint_of_the_model() {
+    let upstream = Upstream::start([
+        ("/api/v2/pokemon/bulbasaur", bulbasaur().to_string()),
+        ("/api/v2/move/85", thunderbolt().to_string()),
+    ])
+    .await;
+    let client = builder(upstream.environment(), CacheMode::NoStore)
+        .try_build()
+        .unwrap();
+
+    let pokemon = rustemon::pokemon::pokemon::get_summary_by_name("bulbasaur", &client)
+        .await
+        .unwrap();
+    assert_eq!(pokemon.types[0].type_.name, "grass");
+
+    let move_ = rustemon::moves::move_::get_summary_by_id(85, &client)
+        .await
+        .unwrap();
+    assert_eq!(move_.power, Some(90));
+}
@@ -0,0 +1,45 @@
+//! Builds models with the test builders, only setting the fields relevant to the test.
+
+use rustemon::{
+    client::Environment,
+    model::{
+        pokemon::{Pokemon, PokemonType, Type},
+        resource::NamedApiResource,
+    },
+};
+
+#[test]
+fn unset_fields_keep_their_default_value() {
+    let pokemon = Pokemon::builder()
+        .id(6)
+        .name("charizard")
+        .types(vec![PokemonType::builder().slot(1).build()])
+        .build();
+
+    assert_eq!(pokemon.id, 6);
+    assert_eq!(pokemon.name, "charizard");
+    assert_eq!(pokemon.types[0].slot, 1);
+    assert_eq!(pokemon.types[0].type_, NamedApiResource::new("", ""));
+
+    assert_eq!(pokemon.base_experience, None);
+    assert_eq!(pokemon.height, 0);
+    assert!(!pokemon.is_default);
+    assert!(pokemon.abilities.is_empty());
+    assert!(pokemon.moves.is_empty());
+    assert!(pokemon.stats.is_empty());
+    assert_eq!(pokemon.species.name, "");
+    assert_eq!(pokemon.species.url, "");
+}
+
+#[test]
+fn built_models_equal_their_default_when_nothing_is_set() {
+    assert_eq!(Pokemon::builder().build(), Pokemon::default());
+}
+
+#[test]
+fn references_are_built_from_their_id() {
+    let fire = NamedApiResource::<Type>::from_id(&Environment::Production, 10, "fire").unwrap();
+
+    assert_eq!(fire.name, "fire");
+    assert_eq!(fire.url, "https://pokeapi.co/api/v2/type/10/");
+}
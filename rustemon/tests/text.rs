@@ -0,0 +1,29 @@
+//! Renders the effects of moves, their chance taken from the move or from its past values.
+
+use rustemon::model::{
+    moves::{Move, PastMoveStatValues},
+    resource::VerboseEffect,
+};
+
+fn effect() -> VerboseEffect {
+    VerboseEffect::builder()
+        .effect("Has a $effect_chance% chance to burn the target.")
+        .short_effect("Has a $effect_chance% chance to burn.")
+        .build()
+}
+
+#[test]
+fn past_values_fall_back_to_the_chance_of_the_move() {
+    let move_ = Move::builder().effect_chance(10).build();
+    let unchanged = PastMoveStatValues::builder().power(95).build();
+    let changed = PastMoveStatValues::builder().effect_chance(30).build();
+
+    assert_eq!(
+        effect().render_effect(&(&unchanged, &move_)).to_plain(),
+        "Has a 10% chance to burn the target."
+    );
+    assert_eq!(
+        effect().render_short_effect(&(&changed, &move_)).to_plain(),
+        "Has a 30% chance to burn."
+    );
+}
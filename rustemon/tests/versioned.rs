@@ -0,0 +1,91 @@
+//! Picks the latest entries of collections tagged with versions or version groups.
+
+use std::cmp::Ordering;
+
+use rustemon::model::{
+    resource::{FlavorText, VersionGroupFlavorText},
+    versioned::{VersionScope, VersionTag, Versioned},
+};
+use serde_json::json;
+
+fn language() -> serde_json::Value {
+    json!({ "name": "en", "url": "https://pokeapi.co/api/v2/language/9/" })
+}
+
+/// Compares the tags by the release of their version group, among a few of them.
+fn by_release(a: &VersionTag<'_>, b: &VersionTag<'_>) -> Option<Ordering> {
+    let release = |tag: &VersionTag<'_>| {
+        let version_group = match tag.name() {
+            "black" | "white" => "black-white",
+            name => name,
+        };
+        ["ruby-sapphire", "colosseum", "xd", "black-white"]
+            .iter()
+            .position(|name| *name == version_group)
+    };
+
+    Some(release(a)?.cmp(&release(b)?))
+}
+
+#[test]
+fn latest_version_group_follows_the_given_order() {
+    // Colosseum and XD have higher ids than Black and White, but were released before them.
+    let texts: Vec<VersionGroupFlavorText> = serde_json::from_value(json!([
+        { "text": "bw", "language": language(), "version_group": { "name": "black-white", "url": "https://pokeapi.co/api/v2/version-group/11/" } },
+        { "text": "xd", "language": language(), "version_group": { "name": "xd", "url": "https://pokeapi.co/api/v2/version-group/13/" } },
+        { "text": "colosseum", "language": language(), "version_group": { "name": "colosseum", "url": "https://pokeapi.co/api/v2/version-group/12/" } },
+    ]))
+    .unwrap();
+
+    assert_eq!(texts.latest(by_release).unwrap().text, "bw");
+    // Ordered by id instead, XD comes last.
+    assert_eq!(
+        texts
+            .latest(|a, b| Some(a.id()?.cmp(&b.id()?)))
+            .unwrap()
+            .text,
+        "xd"
+    );
+}
+
+#[test]
+fn latest_version_keeps_the_first_entry_ordered_equally() {
+    let texts: Vec<FlavorText> = serde_json::from_value(json!([
+        { "flavor_text": "unknown", "language": language(), "version": { "name": "unreleased", "url": "https://pokeapi.co/api/v2/version/999/" } },
+        { "flavor_text": "colosseum", "language": language(), "version": { "name": "colosseum", "url": "https://pokeapi.co/api/v2/version/19/" } },
+        { "flavor_text": "white", "language": language(), "version": { "name": "white", "url": "https://pokeapi.co/api/v2/version/18/" } },
+        { "flavor_text": "black", "language": language(), "version": { "name": "black", "url": "https://pokeapi.co/api/v2/version/17/" } },
+    ]))
+    .unwrap();
+
+    assert_eq!(texts.latest(by_release).unwrap().flavor_text, "white");
+}
+
+#[test]
+fn latest_is_none_without_comparable_versions() {
+    let texts: Vec<FlavorText> = serde_json::from_value(json!([
+        { "flavor_text": "untagged", "language": language() },
+        { "flavor_text": "unknown", "language": language(), "version": { "name": "unreleased", "url": "https://pokeapi.co/api/v2/version/999/" } },
+    ]))
+    .unwrap();
+
+    assert!(texts.latest(by_release).is_none());
+}
+
+#[test]
+fn entries_are_filtered_to_the_scope() {
+    let texts: Vec<FlavorText> = serde_json::from_value(json!([
+        { "flavor_text": "black", "language": language(), "version": { "name": "black", "url": "https://pokeapi.co/api/v2/version/17/" } },
+        { "flavor_text": "xd", "language": language(), "version": { "name": "xd", "url": "https://pokeapi.co/api/v2/version/20/" } },
+        { "flavor_text": "white", "language": language(), "version": { "name": "white", "url": "https://pokeapi.co/api/v2/version/18/" } },
+    ]))
+    .unwrap();
+    let black_white = VersionScope::version_group("black-white").with_versions(["black", "white"]);
+
+    let in_scope: Vec<_> = texts
+        .in_scope(&black_white)
+        .map(|text| text.flavor_text.as_str())
+        .collect();
+    assert_eq!(in_scope, ["black", "white"]);
+    assert!(!texts.any_in_scope(&VersionScope::version("sword")));
+}